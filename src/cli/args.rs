@@ -0,0 +1,134 @@
+// src/cli/args.rs
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Argumentos de um comando: posicionais, opções `--nome valor` e flags `--nome`.
+/// Opções curtas (`-o valor`) ficam disponíveis pelo nome da letra.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Args {
+    positionals: Vec<String>,
+    options: Vec<(String, String)>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    /// Interpreta os argumentos. `flags` lista as opções que não recebem valor.
+    /// Também aceita a forma `--nome=valor`.
+    pub fn parse(raw: &[String], flags: &[&str]) -> Result<Args, String> {
+        let mut args = Args::default();
+        let mut iter = raw.iter();
+
+        while let Some(arg) = iter.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                if let Some((name, value)) = name.split_once('=') {
+                    args.options.push((name.to_string(), value.to_string()));
+                } else if flags.contains(&name) {
+                    args.flags.push(name.to_string());
+                } else {
+                    match iter.next() {
+                        Some(value) => args.options.push((name.to_string(), value.clone())),
+                        None => return Err(format!("A opção '--{}' requer um valor", name)),
+                    }
+                }
+            } else if let Some(name) = short_option(arg) {
+                match iter.next() {
+                    Some(value) => args.options.push((name.to_string(), value.clone())),
+                    None => return Err(format!("A opção '-{}' requer um valor", name)),
+                }
+            } else {
+                args.positionals.push(arg.clone());
+            }
+        }
+
+        Ok(args)
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positionals.get(index).map(String::as_str)
+    }
+
+    pub fn positionals(&self) -> &[String] {
+        &self.positionals
+    }
+
+    /// Último valor informado para a opção.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Todos os valores informados para uma opção repetível.
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.options
+            .iter()
+            .filter(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Define o valor da opção caso ela não tenha sido informada.
+    pub fn set_default(&mut self, name: &str, value: &str) {
+        if self.value(name).is_none() {
+            self.options.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+}
+
+/// Nome de uma opção curta: um hífen seguido de uma letra.
+fn short_option(arg: &str) -> Option<&str> {
+    let name = arg.strip_prefix('-')?;
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if letter.is_ascii_alphabetic() => Some(name),
+        _ => None,
+    }
+}
+
+/// Formato de saída dos relatórios (`--format table|json`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_args(args: &Args) -> Result<OutputFormat, String> {
+        match args.value("format") {
+            None => Ok(OutputFormat::Table),
+            Some(format) => format.parse(),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(format!(
+                "Formato '{}' inválido (esperado table ou json)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Table => write!(f, "table"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
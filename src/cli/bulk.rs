@@ -0,0 +1,228 @@
+// src/cli/bulk.rs
+
+use crate::cli::args::Args;
+use crate::cli::commands::{check_transfer_owner, process_list_nfts};
+use crate::cli::config::{Config, ValidationRules};
+use crate::cli::databases::NftFilter;
+use crate::models::category::{CategoryDefinition, CategoryPath};
+use crate::models::nft::NFT;
+use crate::storage::file_storage::FileStorage;
+
+/// Acima desta quantidade de NFTs, operações em lote exigem `--yes`.
+pub const CONFIRMATION_THRESHOLD: usize = 10;
+
+/// Quantidade de Token IDs exibidos na prévia.
+const PREVIEW_LIMIT: usize = 20;
+
+/// Alterações de `--set`. Campos `None` permanecem como estão.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BulkChange {
+    pub owner_id: Option<u64>,
+    pub category: Option<CategoryPath>,
+    /// `Some(None)` remove os NFTs da coleção.
+    pub collection_id: Option<Option<String>>,
+}
+
+impl BulkChange {
+    /// Lê `--set owner=ID`, `--set category=C[/SUB...]` e `--set collection=ID`
+    /// (valor vazio remove da coleção), resolvendo a categoria com `definitions`.
+    pub fn from_args(
+        args: &Args,
+        definitions: &[CategoryDefinition],
+    ) -> Result<BulkChange, String> {
+        let mut change = BulkChange::default();
+        for assignment in args.values("set") {
+            let (key, value) = assignment
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| format!("Formato inválido para --set: '{}'", assignment))?;
+            match key {
+                "owner" => {
+                    let owner_id = value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|id| *id > 0)
+                        .ok_or("owner deve ser um número inteiro maior que 0")?;
+                    change.owner_id = Some(owner_id);
+                }
+                "category" => change.category = Some(CategoryPath::resolve(value, definitions)?),
+                "collection" => {
+                    change.collection_id = Some(Some(value.to_string()).filter(|v| !v.is_empty()))
+                }
+                _ => {
+                    return Err(format!(
+                        "Campo '{}' não pode ser alterado em lote (esperado owner, category ou collection)",
+                        key
+                    ))
+                }
+            }
+        }
+        if change == BulkChange::default() {
+            return Err("Informe ao menos um --set CAMPO=VALOR".to_string());
+        }
+        Ok(change)
+    }
+
+    fn apply(&self, nft: &mut NFT) -> Result<(), String> {
+        if let Some(owner_id) = self.owner_id {
+            nft.transfer_to(owner_id)?;
+        }
+        if let Some(path) = &self.category {
+            nft.set_category_path(path.clone());
+        }
+        if let Some(collection_id) = &self.collection_id {
+            nft.collection_id = collection_id.clone();
+        }
+        Ok(())
+    }
+}
+
+/// Aplica a alteração a todos os NFTs selecionados, validando tudo antes
+/// de gravar com um único `save_all`. Retorna os Token IDs alterados.
+pub fn process_bulk_update(
+    filter: &NftFilter,
+    change: &BulkChange,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<Vec<String>, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    if let Some(owner_id) = change.owner_id {
+        check_transfer_owner(owner_id, rules, storage)?;
+    }
+
+    let mut updated = Vec::new();
+    for nft in nfts.iter_mut().filter(|nft| filter.matches(nft)) {
+        change
+            .apply(nft)
+            .and_then(|_| nft.validate())
+            .map_err(|e| format!("{}: {}", nft.token_id, e))?;
+        updated.push(nft.token_id.clone());
+    }
+
+    if let Some(Some(collection_id)) = &change.collection_id {
+        check_collection_supply(collection_id, &nfts, storage)?;
+    }
+    if !updated.is_empty() {
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    }
+    Ok(updated)
+}
+
+/// Verifica se a coleção existe e comporta os NFTs após a alteração.
+fn check_collection_supply(
+    collection_id: &str,
+    nfts: &[NFT],
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let collections = storage.load_collections().map_err(|e| e.to_string())?;
+    let collection = collections
+        .iter()
+        .find(|c| c.id == collection_id)
+        .ok_or_else(|| format!("Coleção '{}' não encontrada.", collection_id))?;
+    let members = nfts
+        .iter()
+        .filter(|n| n.collection_id.as_deref() == Some(collection_id))
+        .count() as u64;
+    match collection.max_supply {
+        Some(max_supply) if members > max_supply => Err(format!(
+            "Coleção '{}' comporta no máximo {} NFTs; a operação resultaria em {}.",
+            collection_id, max_supply, members
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Remove todos os NFTs selecionados com um único `save_all`.
+/// Retorna os Token IDs removidos.
+pub fn process_bulk_delete(
+    filter: &NftFilter,
+    storage: &mut FileStorage,
+) -> Result<Vec<String>, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let deleted: Vec<String> = nfts
+        .iter()
+        .filter(|nft| filter.matches(nft))
+        .map(|nft| nft.token_id.clone())
+        .collect();
+
+    if !deleted.is_empty() {
+        nfts.retain(|nft| !filter.matches(nft));
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    }
+    Ok(deleted)
+}
+
+/// Exibe a prévia e decide se a operação pode prosseguir: `--dry-run` só
+/// mostra a prévia, e acima do limite é preciso confirmar com `--yes`.
+fn confirm(selected: &[NFT], action: &str, args: &Args) -> Result<bool, String> {
+    println!("{} NFT(s) selecionado(s) para {}:", selected.len(), action);
+    for nft in selected.iter().take(PREVIEW_LIMIT) {
+        println!("  {}", nft.token_id);
+    }
+    if selected.len() > PREVIEW_LIMIT {
+        println!("  ... e mais {}", selected.len() - PREVIEW_LIMIT);
+    }
+
+    if selected.is_empty() || args.has_flag("dry-run") {
+        return Ok(false);
+    }
+    if selected.len() > CONFIRMATION_THRESHOLD && !args.has_flag("yes") {
+        return Err(format!(
+            "Operação afeta mais de {} NFTs; repita com --yes para confirmar.",
+            CONFIRMATION_THRESHOLD
+        ));
+    }
+    Ok(true)
+}
+
+/// Filtro das operações em lote; um filtro vazio selecionaria todos os NFTs.
+fn bulk_filter(args: &Args, storage: &mut FileStorage) -> Result<NftFilter, String> {
+    let filter = NftFilter::load(args, storage)?;
+    if filter.is_empty() {
+        return Err("Informe --where ou outro filtro para selecionar os NFTs".to_string());
+    }
+    Ok(filter)
+}
+
+/// Executa `update --where ... --set CAMPO=VALOR [--yes] [--dry-run]`.
+pub fn bulk_update_command(config: &Config, args: &Args) -> bool {
+    let mut storage = FileStorage::new(&config.database.value);
+    let result = bulk_filter(args, &mut storage).and_then(|filter| {
+        let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+        let change = BulkChange::from_args(args, &definitions)?;
+        let selected = process_list_nfts(&filter, &mut storage)?;
+        if !confirm(&selected, "atualização", args)? {
+            return Ok(());
+        }
+        let updated =
+            process_bulk_update(&filter, &change, &config.validation_rules(), &mut storage)?;
+        println!("{} NFT(s) atualizado(s) com sucesso!", updated.len());
+        Ok(())
+    });
+    report(result)
+}
+
+/// Executa `delete --where ... [--yes] [--dry-run]`.
+pub fn bulk_delete_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+    let result = bulk_filter(args, &mut storage).and_then(|filter| {
+        let selected = process_list_nfts(&filter, &mut storage)?;
+        if !confirm(&selected, "remoção", args)? {
+            return Ok(());
+        }
+        let deleted = process_bulk_delete(&filter, &mut storage)?;
+        println!("{} NFT(s) deletado(s) com sucesso!", deleted.len());
+        Ok(())
+    });
+    report(result)
+}
+
+fn report(result: Result<(), String>) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
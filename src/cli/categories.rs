@@ -0,0 +1,323 @@
+// src/cli/categories.rs
+
+use crate::cli::args::Args;
+use crate::models::category::{category_key, Category, CategoryDefinition};
+use crate::models::nft::NFT;
+use crate::storage::file_storage::FileStorage;
+
+/// Recusa nomes ou apelidos já usados por outra categoria definida.
+fn check_conflicts(
+    definition: &CategoryDefinition,
+    others: &[CategoryDefinition],
+) -> Result<(), String> {
+    for other in others {
+        if let Some(name) = std::iter::once(&definition.name)
+            .chain(&definition.aliases)
+            .find(|name| other.matches(name))
+        {
+            return Err(format!(
+                "'{}' já é usado pela categoria '{}'.",
+                name.trim(),
+                other.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn process_define_category(
+    definition: &CategoryDefinition,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    definition.validate()?;
+
+    let mut definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    check_conflicts(definition, &definitions)?;
+    definitions.push(definition.clone());
+    save_definitions(&definitions, storage)
+}
+
+pub fn process_update_category(
+    definition: &CategoryDefinition,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    definition.validate()?;
+
+    let mut definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let index = position(&definition.name, &definitions)?;
+    let others: Vec<CategoryDefinition> = definitions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, d)| d.clone())
+        .collect();
+    check_conflicts(definition, &others)?;
+    definitions[index] = definition.clone();
+    save_definitions(&definitions, storage)
+}
+
+/// Remove a definição. Os NFTs mantêm a categoria gravada.
+pub fn process_delete_category(name: &str, storage: &mut FileStorage) -> Result<(), String> {
+    let mut definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let index = position(name, &definitions)?;
+    definitions.remove(index);
+    save_definitions(&definitions, storage)
+}
+
+/// Recategoriza os NFTs de todas as `sources` para `target` e retorna
+/// quantos foram alterados. As definições das origens são removidas e os
+/// seus nomes e apelidos passam a ser apelidos do destino.
+pub fn process_merge_categories(
+    sources: &[&str],
+    target: &str,
+    storage: &mut FileStorage,
+) -> Result<usize, String> {
+    let mut definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let updated = merge_categories(sources, target, &mut definitions, &mut nfts)?;
+    storage
+        .save_all_with_categories(&nfts, &definitions)
+        .map_err(|e| e.to_string())?;
+    Ok(updated)
+}
+
+/// Aplica a união de `process_merge_categories` às definições e aos NFTs
+/// carregados, sem gravar.
+fn merge_categories(
+    sources: &[&str],
+    target: &str,
+    definitions: &mut Vec<CategoryDefinition>,
+    nfts: &mut [NFT],
+) -> Result<usize, String> {
+    if target.trim().is_empty() {
+        return Err("Categoria de destino não pode ser vazia".to_string());
+    }
+    // Categorias de origem, incluindo as dos nomes e apelidos das definidas
+    let mut categories: Vec<Category> = Vec::new();
+    let mut folded: Vec<CategoryDefinition> = Vec::new();
+    for source in sources {
+        if source.trim().is_empty() {
+            return Err("Categoria de origem não pode ser vazia".to_string());
+        }
+        categories.push(Category::resolve(source, &[])?);
+        if let Some(index) = definitions.iter().position(|d| d.matches(source)) {
+            if definitions[index].matches(target) {
+                // Origem é um nome da própria categoria de destino
+                continue;
+            }
+            let definition = definitions.remove(index);
+            for name in std::iter::once(&definition.name).chain(&definition.aliases) {
+                categories.push(Category::resolve(name, &[])?);
+            }
+            folded.push(definition);
+        }
+    }
+
+    if !folded.is_empty() {
+        let index = match definitions.iter().position(|d| d.matches(target)) {
+            Some(index) => index,
+            None => {
+                let mut definition = CategoryDefinition::new(target);
+                definition.description = folded.iter().find_map(|d| d.description.clone());
+                definitions.push(definition);
+                definitions.len() - 1
+            }
+        };
+        let destination = &mut definitions[index];
+        for name in folded
+            .iter()
+            .flat_map(|d| std::iter::once(&d.name).chain(&d.aliases))
+        {
+            if !destination.matches(name) {
+                destination.aliases.push(name.clone());
+            }
+        }
+    }
+
+    let category = Category::resolve(target, definitions)?;
+    let mut updated = 0;
+    for nft in nfts.iter_mut().filter(|nft| {
+        nft.category.same_as(&category) || categories.iter().any(|c| nft.category.same_as(c))
+    }) {
+        if nft.category != category {
+            nft.category = category.clone();
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+/// Renomeia uma categoria em todos os NFTs, com uma única gravação. O nome
+/// antigo continua aceito como apelido, mesmo que a categoria não estivesse
+/// definida.
+pub fn process_rename_category(
+    from: &str,
+    to: &str,
+    storage: &mut FileStorage,
+) -> Result<usize, String> {
+    let mut definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let source = definitions.iter().position(|d| d.matches(from));
+    if let Some(target) = definitions.iter().position(|d| d.matches(to)) {
+        if source != Some(target) {
+            return Err(format!(
+                "Categoria '{}' já existe; use 'category merge' para unir as duas.",
+                definitions[target].name
+            ));
+        }
+    }
+    match source {
+        Some(index) => {
+            let definition = &mut definitions[index];
+            let previous = std::mem::replace(&mut definition.name, to.trim().to_string());
+            definition
+                .aliases
+                .retain(|alias| category_key(alias) != category_key(to));
+            if category_key(&previous) != category_key(to) {
+                definition.aliases.push(previous);
+            }
+            definition.validate()?;
+        }
+        None => {
+            // Sem definição, a nova categoria passa a ser definida com o nome
+            // antigo como apelido; nomes predefinidos não podem ser apelidos.
+            if let Category::Other(previous) = Category::resolve(from, &[])? {
+                if category_key(&previous) != category_key(to) {
+                    let mut definition = CategoryDefinition::new(to);
+                    definition.aliases.push(previous);
+                    definition.validate()?;
+                    definitions.push(definition);
+                }
+            }
+        }
+    }
+
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let updated = merge_categories(&[from], to, &mut definitions, &mut nfts)?;
+    storage
+        .save_all_with_categories(&nfts, &definitions)
+        .map_err(|e| e.to_string())?;
+    Ok(updated)
+}
+
+/// Grava as definições.
+fn save_definitions(
+    definitions: &[CategoryDefinition],
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    storage
+        .save_categories(definitions)
+        .map_err(|e| e.to_string())
+}
+
+fn position(name: &str, definitions: &[CategoryDefinition]) -> Result<usize, String> {
+    definitions
+        .iter()
+        .position(|d| d.matches(name))
+        .ok_or_else(|| format!("Categoria '{}' não definida.", name))
+}
+
+/// Aplica `--alias A` (repetível), `--remove-alias A` e `--description D`
+/// (valor vazio remove a descrição).
+pub fn apply_category_args(definition: &mut CategoryDefinition, args: &Args) -> Result<(), String> {
+    for alias in args.values("remove-alias") {
+        let key = category_key(alias);
+        let original_len = definition.aliases.len();
+        definition.aliases.retain(|a| category_key(a) != key);
+        if definition.aliases.len() == original_len {
+            return Err(format!("Apelido '{}' não encontrado", alias));
+        }
+    }
+    for alias in args.values("alias") {
+        if !definition.matches(alias) {
+            definition.aliases.push(alias.trim().to_string());
+        }
+    }
+    if let Some(description) = args.value("description") {
+        definition.description = Some(description.trim().to_string()).filter(|d| !d.is_empty());
+    }
+    Ok(())
+}
+
+fn print_definition(definition: &CategoryDefinition, count: usize) {
+    println!("Categoria: {}", definition.name);
+    if !definition.aliases.is_empty() {
+        println!("Apelidos: {}", definition.aliases.join(", "));
+    }
+    if let Some(description) = &definition.description {
+        println!("Descrição: {}", description);
+    }
+    println!("NFTs: {}", count);
+}
+
+fn count_nfts(definition: &CategoryDefinition, storage: &mut FileStorage) -> Result<usize, String> {
+    let category = definition.category();
+    Ok(storage
+        .load_all()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .filter(|n| n.category == category)
+        .count())
+}
+
+/// Executa `category <define|update|delete|list|rename|merge> ...`.
+pub fn category_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some("define"), Some(name)) => {
+            let mut definition = CategoryDefinition::new(name);
+            apply_category_args(&mut definition, args)
+                .and_then(|_| process_define_category(&definition, &mut storage))
+                .map(|_| println!("Categoria definida com sucesso!"))
+        }
+        (Some("update"), Some(name)) => storage
+            .load_categories()
+            .map_err(|e| e.to_string())
+            .and_then(|definitions| {
+                let mut definition = definitions[position(name, &definitions)?].clone();
+                apply_category_args(&mut definition, args)?;
+                process_update_category(&definition, &mut storage)
+            })
+            .map(|_| println!("Categoria atualizada com sucesso!")),
+        (Some("delete"), Some(name)) => process_delete_category(name, &mut storage)
+            .map(|_| println!("Categoria deletada com sucesso!")),
+        (Some("list"), _) => storage
+            .load_categories()
+            .map_err(|e| e.to_string())
+            .and_then(|definitions| {
+                if definitions.is_empty() {
+                    println!("Nenhuma categoria definida.");
+                }
+                for definition in &definitions {
+                    println!("------------------------------");
+                    print_definition(definition, count_nfts(definition, &mut storage)?);
+                }
+                Ok(())
+            }),
+        (Some("rename"), Some(from)) => match args.positional(2) {
+            Some(to) => process_rename_category(from, to, &mut storage)
+                .map(|updated| println!("Categoria renomeada; {} NFT(s) atualizado(s).", updated)),
+            None => Err("Uso: nft_manager category rename <antiga> <nova>".to_string()),
+        },
+        (Some("merge"), Some(_)) => match args.value("into") {
+            Some(target) => {
+                let sources: Vec<&str> =
+                    args.positionals()[1..].iter().map(String::as_str).collect();
+                process_merge_categories(&sources, target, &mut storage)
+                    .map(|updated| println!("Categorias unidas; {} NFT(s) atualizado(s).", updated))
+            }
+            None => Err("Uso: nft_manager category merge <origem>... --into <destino>".to_string()),
+        },
+        _ => Err(
+            "Uso: nft_manager category <define|update|delete|list|rename|merge> ...".to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// src/cli/chart.rs
+
+use crate::cli::args::Args;
+use crate::cli::stats::{process_stats, top_from_args, MonthCount};
+use crate::storage::file_storage::FileStorage;
+use std::io::{self, IsTerminal};
+
+/// Largura usada quando a saída não é um terminal.
+pub const DEFAULT_WIDTH: usize = 80;
+
+const UNICODE_EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+const UNICODE_SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const ASCII_SPARKS: [char; 8] = ['_', '.', ':', '-', '=', '+', '*', '#'];
+
+/// Conjunto de caracteres usado no desenho dos gráficos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartStyle {
+    Unicode,
+    Ascii,
+}
+
+impl ChartStyle {
+    /// Reticências usadas nos rótulos truncados e nos intervalos.
+    pub fn ellipsis(self) -> &'static str {
+        match self {
+            ChartStyle::Unicode => "…",
+            ChartStyle::Ascii => "...",
+        }
+    }
+}
+
+/// Desenha um gráfico de barras horizontais ocupando até `width` colunas.
+/// Rótulos longos são truncados e terminam com reticências.
+pub fn render_bar_chart(
+    entries: &[(String, usize)],
+    width: usize,
+    style: ChartStyle,
+) -> Vec<String> {
+    let max = entries.iter().map(|(_, value)| *value).max().unwrap_or(0);
+    let label_width = entries
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(width / 3);
+    let count_width = max.to_string().len();
+    // Rótulo, espaço, barra, espaço e contagem
+    let bar_width = width.saturating_sub(label_width + count_width + 2).max(1);
+
+    entries
+        .iter()
+        .map(|(label, value)| {
+            let label = truncate_label(label, label_width, style);
+            format!(
+                "{:<label_width$} {} {:>count_width$}",
+                label,
+                render_bar(*value, max, bar_width, style),
+                value,
+            )
+        })
+        .collect()
+}
+
+fn truncate_label(label: &str, width: usize, style: ChartStyle) -> String {
+    if label.chars().count() <= width {
+        return label.to_string();
+    }
+    let ellipsis = style.ellipsis();
+    let kept = width.saturating_sub(ellipsis.chars().count());
+    let mut truncated: String = label.chars().take(kept).collect();
+    truncated.push_str(ellipsis);
+    truncated.chars().take(width).collect()
+}
+
+fn render_bar(value: usize, max: usize, bar_width: usize, style: ChartStyle) -> String {
+    if max == 0 {
+        return " ".repeat(bar_width);
+    }
+
+    // Comprimento em oitavos de coluna
+    let eighths = value * bar_width * 8 / max;
+    let mut bar = match style {
+        ChartStyle::Unicode => {
+            let mut bar = "█".repeat(eighths / 8);
+            if !eighths.is_multiple_of(8) {
+                bar.push(UNICODE_EIGHTHS[eighths % 8]);
+            }
+            bar
+        }
+        ChartStyle::Ascii => "#".repeat((eighths + 4) / 8),
+    };
+    let used = bar.chars().count();
+    bar.push_str(&" ".repeat(bar_width.saturating_sub(used)));
+    bar
+}
+
+/// Desenha uma linha com um caractere por valor, proporcional ao máximo.
+pub fn render_sparkline(values: &[usize], style: ChartStyle) -> String {
+    let sparks = match style {
+        ChartStyle::Unicode => UNICODE_SPARKS,
+        ChartStyle::Ascii => ASCII_SPARKS,
+    };
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            let level = (value * (sparks.len() - 1)).checked_div(max).unwrap_or(0);
+            sparks[level]
+        })
+        .collect()
+}
+
+/// Completa com zero os meses sem criações entre o primeiro e o último mês.
+pub fn fill_months(months: &[MonthCount]) -> Vec<(String, usize)> {
+    let parse = |month: &str| -> Option<(i32, u32)> {
+        let (year, month) = month.split_once('-')?;
+        Some((year.parse().ok()?, month.parse().ok()?))
+    };
+    let (Some(first), Some(last)) = (
+        months.first().and_then(|m| parse(&m.month)),
+        months.last().and_then(|m| parse(&m.month)),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut filled = Vec::new();
+    let (mut year, mut month) = first;
+    while (year, month) <= last {
+        let label = format!("{:04}-{:02}", year, month);
+        let count = months
+            .iter()
+            .find(|m| m.month == label)
+            .map(|m| m.count)
+            .unwrap_or(0);
+        filled.push((label, count));
+        (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+    }
+    filled
+}
+
+/// Largura e estilo conforme o destino da saída: em terminal usa a largura
+/// dele e caracteres Unicode; redirecionada, usa ASCII e [`DEFAULT_WIDTH`].
+fn output_layout(args: &Args) -> Result<(usize, ChartStyle), String> {
+    let is_terminal = io::stdout().is_terminal();
+    let width = match args.value("width") {
+        Some(width) => width
+            .parse::<usize>()
+            .ok()
+            .filter(|&w| w >= 20)
+            .ok_or("--width deve ser um número inteiro a partir de 20")?,
+        None if is_terminal => terminal_size::terminal_size()
+            .map(|(terminal_size::Width(w), _)| w as usize)
+            .unwrap_or(DEFAULT_WIDTH),
+        None => DEFAULT_WIDTH,
+    };
+    let style = if args.has_flag("ascii") || !is_terminal {
+        ChartStyle::Ascii
+    } else {
+        ChartStyle::Unicode
+    };
+    Ok((width, style))
+}
+
+/// Executa `chart [--top N] [--width N] [--ascii]`.
+pub fn chart_command(db_path: &str, args: &Args) -> bool {
+    let result = output_layout(args).and_then(|(width, style)| {
+        let top = top_from_args(args)?;
+        let mut storage = FileStorage::new(db_path);
+        let stats = process_stats(&mut storage, top)?;
+
+        if stats.total == 0 {
+            println!("Nenhum NFT encontrado.");
+            return Ok(());
+        }
+
+        println!("NFTs por categoria");
+        let categories: Vec<(String, usize)> = stats
+            .categories
+            .iter()
+            .map(|c| (c.category.clone(), c.count))
+            .collect();
+        for line in render_bar_chart(&categories, width, style) {
+            println!("{}", line);
+        }
+
+        let months = fill_months(&stats.minted_per_month);
+        // Mantém apenas os meses mais recentes que cabem na largura
+        let months = &months[months.len().saturating_sub(width)..];
+        let counts: Vec<usize> = months.iter().map(|(_, count)| *count).collect();
+        println!("\nNFTs criados por mês");
+        println!("{}", render_sparkline(&counts, style));
+        println!(
+            "{} {} {} (máximo: {} por mês)",
+            months[0].0,
+            style.ellipsis(),
+            months[months.len() - 1].0,
+            counts.iter().max().unwrap_or(&0)
+        );
+
+        println!("\nMaiores proprietários");
+        let holders: Vec<(String, usize)> = stats
+            .top_holders
+            .iter()
+            .map(|h| {
+                let label = match &h.owner_name {
+                    Some(name) => format!("{} ({})", h.owner_id, name),
+                    None => h.owner_id.to_string(),
+                };
+                (label, h.count)
+            })
+            .collect();
+        for line in render_bar_chart(&holders, width, style) {
+            println!("{}", line);
+        }
+        Ok(())
+    });
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,306 @@
+// src/cli/collections.rs
+
+use crate::cli::args::Args;
+use crate::cli::commands::print_nft;
+use crate::models::collection::{Chain, Collection, Royalty};
+use crate::models::nft::NFT;
+use crate::storage::file_storage::FileStorage;
+use std::str::FromStr;
+
+pub fn process_create_collection(
+    collection: &Collection,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    collection.validate()?;
+
+    let mut collections = storage.load_collections().map_err(|e| e.to_string())?;
+    if collections.iter().any(|c| c.id == collection.id) {
+        return Err(format!("Coleção '{}' já existe.", collection.id));
+    }
+    collections.push(collection.clone());
+    storage
+        .save_collections(&collections)
+        .map_err(|e| e.to_string())
+}
+
+/// Substitui a coleção de mesmo ID. O fornecimento máximo não pode ficar
+/// abaixo da quantidade de NFTs que já pertencem à coleção.
+pub fn process_update_collection(
+    collection: &Collection,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    collection.validate()?;
+
+    let members = count_members(&collection.id, storage)?;
+    if let Some(max_supply) = collection.max_supply {
+        if members as u64 > max_supply {
+            return Err(format!(
+                "Coleção '{}' já possui {} NFTs, acima do fornecimento máximo {}.",
+                collection.id, members, max_supply
+            ));
+        }
+    }
+
+    let mut collections = storage.load_collections().map_err(|e| e.to_string())?;
+    match collections.iter_mut().find(|c| c.id == collection.id) {
+        Some(existing) => *existing = collection.clone(),
+        None => return Err(not_found(&collection.id)),
+    }
+    storage
+        .save_collections(&collections)
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a coleção. Falha se ainda houver NFTs que a referenciam.
+pub fn process_delete_collection(id: &str, storage: &mut FileStorage) -> Result<(), String> {
+    let members = count_members(id, storage)?;
+    if members > 0 {
+        return Err(format!(
+            "Coleção '{}' ainda possui {} NFT(s); remova-os da coleção antes de deletá-la.",
+            id, members
+        ));
+    }
+
+    let mut collections = storage.load_collections().map_err(|e| e.to_string())?;
+    let original_len = collections.len();
+    collections.retain(|c| c.id != id);
+
+    if collections.len() < original_len {
+        storage
+            .save_collections(&collections)
+            .map_err(|e| e.to_string())
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// Verifica se a coleção existe e ainda comporta mais um NFT.
+pub fn process_check_collection_capacity(
+    collection_id: &str,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let collection = find_collection(collection_id, storage)?;
+    let members = count_members(collection_id, storage)?;
+    if let Some(max_supply) = collection.max_supply {
+        if members as u64 >= max_supply {
+            return Err(format!(
+                "Coleção '{}' atingiu o fornecimento máximo de {} NFTs.",
+                collection_id, max_supply
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Associa o NFT a uma coleção existente (ou o desassocia com `None`),
+/// respeitando o fornecimento máximo da coleção. Um NFT que já está na
+/// coleção não conta contra o limite.
+pub fn process_assign_collection(
+    token_id: &str,
+    collection_id: Option<&str>,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let index = nfts
+        .iter()
+        .position(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+    if let Some(collection_id) = collection_id {
+        if nfts[index].collection_id.as_deref() != Some(collection_id) {
+            process_check_collection_capacity(collection_id, storage)?;
+        }
+    }
+
+    nfts[index].collection_id = collection_id.map(str::to_string);
+    storage.save_all(&nfts).map_err(|e| e.to_string())
+}
+
+pub fn process_list_collection_nfts(
+    id: &str,
+    storage: &mut FileStorage,
+) -> Result<(Collection, Vec<NFT>), String> {
+    let collection = find_collection(id, storage)?;
+    let nfts = storage
+        .load_all()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|n| n.collection_id.as_deref() == Some(id))
+        .collect();
+    Ok((collection, nfts))
+}
+
+fn find_collection(id: &str, storage: &mut FileStorage) -> Result<Collection, String> {
+    storage
+        .load_collections()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| not_found(id))
+}
+
+fn count_members(id: &str, storage: &mut FileStorage) -> Result<usize, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    Ok(nfts
+        .iter()
+        .filter(|n| n.collection_id.as_deref() == Some(id))
+        .count())
+}
+
+fn not_found(id: &str) -> String {
+    format!("Coleção '{}' não encontrada.", id)
+}
+
+/// Aplica as opções de coleção. Um valor vazio remove os campos opcionais.
+pub fn apply_collection_args(collection: &mut Collection, args: &Args) -> Result<(), String> {
+    if let Some(name) = args.value("name") {
+        collection.name = name.trim().to_string();
+    }
+    if let Some(symbol) = args.value("symbol") {
+        collection.symbol = symbol.trim().to_string();
+    }
+    if let Some(chain) = args.value("chain") {
+        collection.chain = Chain::from_str(chain)?;
+    }
+    if let Some(address) = args.value("contract") {
+        collection.contract_address = Some(address.trim().to_string()).filter(|a| !a.is_empty());
+    }
+    if let Some(max_supply) = args.value("max-supply") {
+        collection.max_supply = match max_supply.trim() {
+            "" => None,
+            value => Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|_| "Fornecimento máximo inválido".to_string())?,
+            ),
+        };
+    }
+
+    apply_royalty_args(&mut collection.royalty, args)
+}
+
+/// Aplica `--royalty-bps BPS` e `--royalty-recipient ID`. Uma opção omitida
+/// mantém o valor atual e `--royalty-bps ""` remove o royalty.
+pub fn apply_royalty_args(royalty: &mut Option<Royalty>, args: &Args) -> Result<(), String> {
+    let basis_points = args.value("royalty-bps");
+    let recipient = args.value("royalty-recipient");
+    if basis_points == Some("") {
+        *royalty = None;
+    } else if basis_points.is_some() || recipient.is_some() {
+        let current = royalty.clone();
+        let basis_points = match basis_points {
+            Some(value) => value
+                .trim()
+                .parse::<u16>()
+                .map_err(|_| "Royalty inválido: informe os pontos-base (0 a 10000)".to_string())?,
+            None => current.as_ref().map(|r| r.basis_points).unwrap_or_default(),
+        };
+        let recipient_id = match recipient {
+            Some(value) => value
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| "Beneficiário do royalty inválido".to_string())?,
+            None => current
+                .as_ref()
+                .map(|r| r.recipient_id)
+                .ok_or("Informe --royalty-recipient junto com --royalty-bps")?,
+        };
+        *royalty = Some(Royalty {
+            basis_points,
+            recipient_id,
+        });
+    }
+
+    Ok(())
+}
+
+fn print_collection(collection: &Collection) {
+    println!("ID: {}", collection.id);
+    println!("Nome: {}", collection.name);
+    println!("Símbolo: {}", collection.symbol);
+    println!("Rede: {}", collection.chain);
+    if let Some(address) = &collection.contract_address {
+        println!("Contrato: {}", address);
+    }
+    if let Some(max_supply) = collection.max_supply {
+        println!("Fornecimento Máximo: {}", max_supply);
+    }
+    if let Some(royalty) = &collection.royalty {
+        println!("Royalty: {}", royalty);
+    }
+}
+
+/// Executa `collection <ação> ...`. Retorna `false` em caso de erro.
+pub fn collection_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+    let id = args.positional(1);
+
+    let result = match (args.positional(0), id) {
+        (Some("create"), Some(id)) => {
+            let mut collection = Collection::new(
+                id.to_string(),
+                String::new(),
+                String::new(),
+                Chain::Ethereum,
+            );
+            apply_collection_args(&mut collection, args)
+                .and_then(|_| process_create_collection(&collection, &mut storage))
+                .map(|_| println!("Coleção criada com sucesso!"))
+        }
+        (Some("update"), Some(id)) => find_collection(id, &mut storage)
+            .and_then(|mut collection| {
+                apply_collection_args(&mut collection, args)?;
+                process_update_collection(&collection, &mut storage)
+            })
+            .map(|_| println!("Coleção atualizada com sucesso!")),
+        (Some("delete"), Some(id)) => process_delete_collection(id, &mut storage)
+            .map(|_| println!("Coleção deletada com sucesso!")),
+        (Some("show"), Some(id)) => {
+            process_list_collection_nfts(id, &mut storage).map(|(collection, nfts)| {
+                let owners = storage.load_owners().unwrap_or_default();
+                println!("------------------------------");
+                print_collection(&collection);
+                println!("NFTs na coleção: {}", nfts.len());
+                for nft in &nfts {
+                    println!("------------------------------");
+                    print_nft(nft, &owners);
+                }
+                println!("------------------------------");
+            })
+        }
+        (Some("list"), _) => storage
+            .load_collections()
+            .map_err(|e| e.to_string())
+            .map(|collections| {
+                if collections.is_empty() {
+                    println!("Nenhuma coleção encontrada.");
+                }
+                for collection in &collections {
+                    println!("------------------------------");
+                    print_collection(collection);
+                }
+            }),
+        (Some("assign"), Some(token_id)) => match args.positional(2) {
+            Some(collection_id) => {
+                process_assign_collection(token_id, Some(collection_id), &mut storage)
+                    .map(|_| println!("NFT adicionado à coleção com sucesso!"))
+            }
+            None => Err("Uso: nft_manager collection assign <token_id> <coleção>".to_string()),
+        },
+        (Some("unassign"), Some(token_id)) => {
+            process_assign_collection(token_id, None, &mut storage)
+                .map(|_| println!("NFT removido da coleção com sucesso!"))
+        }
+        _ => Err(
+            "Uso: nft_manager collection <create|update|delete|show|list|assign|unassign> ..."
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -1,8 +1,18 @@
+use crate::cli::args::Args;
+use crate::cli::collections::apply_royalty_args;
+use crate::cli::config::{Config, ValidationRules};
+use crate::cli::databases::NftFilter;
+use crate::cli::shell::{prompt, LineSource, PromptError};
+use crate::cli::valuations::{print_price_history, process_price_history};
 use crate::models::nft::NFT;
-use crate::models::category::Category;
-use crate::storage::file_storage::{FileStorage, StorageError};
+use crate::models::owner::Owner;
+use crate::models::wallet::WalletAddress;
+use crate::models::category::{Category, CategoryPath};
+use crate::models::metadata::{Attribute, AttributeValue, NftMetadata};
+use crate::models::token_id::TokenIdStrategy;
+use crate::storage::file_storage::{FileStorage, StorageError, SCHEMA_VERSION};
+use crate::storage::ledger::LedgerReport;
 use chrono::NaiveDate;
-use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 
 pub fn collect_nft_data(
@@ -10,7 +20,10 @@ pub fn collect_nft_data(
     owner_id: u64,
     creation_date: NaiveDate,
     category: Category,
+    rules: &ValidationRules,
 ) -> Result<NFT, String> {
+    rules.check_token_id(&token_id)?;
+
     let nft = NFT::new(token_id, owner_id, creation_date, category);
     nft.validate()?;
     Ok(nft)
@@ -20,22 +33,57 @@ pub fn process_create_nft(nft: &NFT, storage: &mut FileStorage) -> Result<(), St
     storage.save(nft)
 }
 
-pub fn create_nft(reader: &mut impl BufRead, db_path: &str) {
+/// Retorna o Token ID informado, recusando um já existente, ou gera um
+/// novo com a estratégia quando nenhum é informado.
+pub fn process_token_id(
+    token_id: Option<&str>,
+    strategy: &TokenIdStrategy,
+    collection_id: Option<&str>,
+    storage: &mut FileStorage,
+) -> Result<String, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    match token_id {
+        Some(token_id) if nfts.iter().any(|nft| nft.token_id == token_id) => {
+            Err(format!("NFT com Token ID '{}' já existe.", token_id))
+        }
+        Some(token_id) => Ok(token_id.to_string()),
+        None => strategy.generate(&nfts, collection_id),
+    }
+}
+
+/// Coleta os dados de um novo NFT e o grava. Token IDs deixados vazios são
+/// gerados com `strategy`.
+/// Retorna erro se o usuário cancelar ou a entrada terminar antes do fim.
+pub fn create_nft(
+    reader: &mut impl LineSource,
+    db_path: &str,
+    rules: &ValidationRules,
+    strategy: &TokenIdStrategy,
+) -> Result<(), PromptError> {
     println!("\nCriando um novo NFT...");
+    println!("(Digite 'cancel' a qualquer momento para cancelar.)");
 
     // Token ID
+    let mut storage = FileStorage::new(db_path);
     let token_id = loop {
-        let input = get_input("Digite o Token ID (não vazio): ", reader);
-        if !input.trim().is_empty() {
-            break input;
+        let input = prompt(reader, "Digite o Token ID (vazio para gerar automaticamente): ")?;
+        let token_id = Some(input.as_str()).filter(|t| !t.is_empty());
+        match process_token_id(token_id, strategy, None, &mut storage) {
+            Ok(generated) if token_id.is_none() => {
+                println!("Token ID gerado: {}", generated);
+                break generated;
+            }
+            Ok(token_id) => {
+                println!("Token ID recebido: {}", token_id);
+                break token_id;
+            }
+            Err(e) => println!("{}", e),
         }
-        println!("Token ID não pode ser vazio.");
     };
-    println!("Token ID recebido: {}", token_id);
 
     // Owner ID
     let owner_id = loop {
-        let input = get_input("Digite o Owner ID (número inteiro maior que 0): ", reader);
+        let input = prompt(reader, "Digite o Owner ID (número inteiro maior que 0): ")?;
         match input.parse::<u64>() {
             Ok(id) if id > 0 => {
                 println!("Owner ID recebido: {}", id);
@@ -47,7 +95,7 @@ pub fn create_nft(reader: &mut impl BufRead, db_path: &str) {
 
     // Creation Date
     let creation_date = loop {
-        let input = get_input("Digite a Data de Criação (AAAA-MM-DD): ", reader);
+        let input = prompt(reader, "Digite a Data de Criação (AAAA-MM-DD): ")?;
         match NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
             Ok(date) => {
                 let current_date = chrono::Local::now().date_naive();
@@ -64,27 +112,34 @@ pub fn create_nft(reader: &mut impl BufRead, db_path: &str) {
 
     // Category
     println!("\nCategorias disponíveis:");
-    println!("- Art");
-    println!("- Music");
-    println!("- Virtual Real Estate");
-    println!("- Collectible");
-    println!("- Game Item");
+    for category in &Category::NAMED {
+        println!("- {}", category);
+    }
+    let definitions = storage.load_categories().unwrap_or_default();
+    for definition in &definitions {
+        if definition.aliases.is_empty() {
+            println!("- {}", definition.name);
+        } else {
+            println!("- {} ({})", definition.name, definition.aliases.join(", "));
+        }
+    }
     println!("- Outra (digite uma descrição personalizada)");
+    println!("Subcategorias são separadas por '/', como Art/Generative.");
 
-    let category = loop {
-        let input = get_input("Digite a Categoria do NFT: ", reader);
-        match Category::from_str(&input) {
-            Ok(category) => {
-                println!("Categoria recebida: {}", category);
-                break category;
+    let path = loop {
+        let input = prompt(reader, "Digite a Categoria do NFT: ")?;
+        match CategoryPath::resolve(&input, &definitions) {
+            Ok(path) => {
+                println!("Categoria recebida: {}", path);
+                break path;
             }
             Err(e) => println!("{}", e),
         }
     };
 
-    match collect_nft_data(token_id, owner_id, creation_date, category) {
-        Ok(nft) => {
-            let mut storage = FileStorage::new(db_path);
+    match collect_nft_data(token_id, owner_id, creation_date, path.category.clone(), rules) {
+        Ok(mut nft) => {
+            nft.set_category_path(path);
             if let Err(e) = process_create_nft(&nft, &mut storage) {
                 println!("Erro ao salvar NFT: {}", e);
             } else {
@@ -95,6 +150,7 @@ pub fn create_nft(reader: &mut impl BufRead, db_path: &str) {
             println!("Erro ao coletar dados do NFT: {}", e);
         }
     }
+    Ok(())
 }
 
 pub fn read_nft(db_path: &str) -> Result<Vec<NFT>, StorageError> {
@@ -106,12 +162,10 @@ pub fn read_nft(db_path: &str) -> Result<Vec<NFT>, StorageError> {
             if nfts.is_empty() {
                 println!("Nenhum NFT encontrado.");
             } else {
+                let owners = storage.load_owners().unwrap_or_default();
                 for nft in nfts.iter() {
                     println!("------------------------------");
-                    println!("Token ID: {}", nft.token_id);
-                    println!("Owner ID: {}", nft.owner_id);
-                    println!("Data de Criação: {}", nft.creation_date);
-                    println!("Categoria: {}", nft.category);
+                    print_nft(nft, &owners);
                 }
                 println!("------------------------------");
             }
@@ -124,15 +178,352 @@ pub fn read_nft(db_path: &str) -> Result<Vec<NFT>, StorageError> {
     }
 }
 
+/// NFTs que atendem ao filtro, na ordem do banco de dados.
+pub fn process_list_nfts(
+    filter: &NftFilter,
+    storage: &mut FileStorage,
+) -> Result<Vec<NFT>, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    Ok(nfts.into_iter().filter(|nft| filter.matches(nft)).collect())
+}
+
+/// Executa `list` com os filtros de `NftFilter::from_args`.
+pub fn list_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+    let result = NftFilter::load(args, &mut storage)
+        .and_then(|filter| process_list_nfts(&filter, &mut storage));
+
+    match result {
+        Ok(nfts) => {
+            let owners = storage.load_owners().unwrap_or_default();
+            for nft in &nfts {
+                println!("------------------------------");
+                print_nft(nft, &owners);
+            }
+            if !nfts.is_empty() {
+                println!("------------------------------");
+            }
+            println!("{} NFT(s) encontrado(s).", nfts.len());
+            true
+        }
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
+
+/// Exibe os campos de um NFT, incluindo os metadados preenchidos.
+/// O nome do proprietário é resolvido a partir do registro, quando existir.
+pub(crate) fn print_nft(nft: &NFT, owners: &[Owner]) {
+    println!("Token ID: {}", nft.token_id);
+    match owners.iter().find(|o| o.id == nft.owner_id) {
+        Some(owner) => println!("Owner ID: {} ({})", nft.owner_id, owner.display_name),
+        None => println!("Owner ID: {}", nft.owner_id),
+    }
+    println!("Data de Criação: {}", nft.creation_date);
+    println!("Categoria: {}", nft.category_path());
+    if !nft.tags.is_empty() {
+        println!("Marcadores: {}", nft.tags.join(", "));
+    }
+    if let Some(address) = &nft.owner_address {
+        println!("Carteira: {}", address);
+    }
+    if let Some(collection_id) = &nft.collection_id {
+        println!("Coleção: {}", collection_id);
+    }
+    if let Some(royalty) = &nft.royalty {
+        println!("Royalty: {}", royalty);
+    }
+    if let Some(shares) = &nft.shares {
+        println!("Cotas: {}", shares);
+    }
+
+    let metadata = &nft.metadata;
+    let fields = [
+        ("Nome", &metadata.name),
+        ("Descrição", &metadata.description),
+        ("Imagem", &metadata.image),
+        ("Animação", &metadata.animation_url),
+        ("URL Externa", &metadata.external_url),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value {
+            println!("{}: {}", label, value);
+        }
+    }
+    if !metadata.attributes.is_empty() {
+        println!("Atributos:");
+        for attribute in &metadata.attributes {
+            match &attribute.display_type {
+                Some(display_type) => println!(
+                    "  - {}: {} ({})",
+                    attribute.trait_type, attribute.value, display_type
+                ),
+                None => println!("  - {}: {}", attribute.trait_type, attribute.value),
+            }
+        }
+    }
+}
+
+pub fn process_find_nft(token_id: &str, storage: &mut FileStorage) -> Result<NFT, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    nfts.into_iter()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))
+}
+
+pub fn show_nft(db_path: &str, token_id: &str) -> Result<NFT, String> {
+    let mut storage = FileStorage::new(db_path);
+    match process_find_nft(token_id, &mut storage) {
+        Ok(nft) => {
+            let owners = storage.load_owners().unwrap_or_default();
+            println!("------------------------------");
+            print_nft(&nft, &owners);
+            if let Ok(history) = process_price_history(&nft.token_id, &mut storage) {
+                print_price_history(&history);
+            }
+            println!("------------------------------");
+            Ok(nft)
+        }
+        Err(e) => {
+            println!("Erro ao exibir NFT: {}", e);
+            Err(e)
+        }
+    }
+}
+
+pub fn process_update_metadata(
+    token_id: &str,
+    metadata: NftMetadata,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+
+    if let Some(nft) = nfts.iter_mut().find(|n| n.token_id == token_id) {
+        nft.metadata = metadata;
+
+        // Validação dos dados
+        nft.validate()?;
+
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+        Ok(())
+    } else {
+        Err(format!("NFT com Token ID '{}' não encontrado.", token_id))
+    }
+}
+
+/// Aplica as opções de edição de metadados. Um valor vazio remove o campo.
+pub fn apply_metadata_args(metadata: &mut NftMetadata, args: &Args) -> Result<(), String> {
+    let fields = [
+        ("name", &mut metadata.name),
+        ("description", &mut metadata.description),
+        ("image", &mut metadata.image),
+        ("animation-url", &mut metadata.animation_url),
+        ("external-url", &mut metadata.external_url),
+    ];
+    for (option, field) in fields {
+        if let Some(value) = args.value(option) {
+            *field = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        }
+    }
+
+    for trait_type in args.values("remove-attribute") {
+        if !metadata.remove_attribute(trait_type) {
+            return Err(format!("Atributo '{}' não encontrado", trait_type));
+        }
+    }
+    for pair in args.values("attribute") {
+        let (trait_type, value) = split_pair(pair, "--attribute")?;
+        metadata.set_attribute(Attribute::new(trait_type, AttributeValue::parse(value)));
+    }
+    for pair in args.values("display-type") {
+        let (trait_type, display_type) = split_pair(pair, "--display-type")?;
+        let attribute = metadata
+            .attributes
+            .iter_mut()
+            .find(|a| a.trait_type == trait_type)
+            .ok_or_else(|| format!("Atributo '{}' não encontrado", trait_type))?;
+        attribute.display_type = Some(display_type.to_string()).filter(|d| !d.is_empty());
+    }
+
+    Ok(())
+}
+
+fn split_pair<'a>(pair: &'a str, option: &str) -> Result<(&'a str, &'a str), String> {
+    pair.split_once('=')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| !key.is_empty())
+        .ok_or_else(|| format!("Formato inválido para {}: esperado CHAVE=VALOR", option))
+}
+
+/// Aplica todas as opções de `edit` ao NFT e grava com um único `save_all`,
+/// depois de validar o resultado; se alguma opção for inválida, nada muda.
+pub fn process_edit_nft(
+    token_id: &str,
+    args: &Args,
+    storage: &mut FileStorage,
+) -> Result<NFT, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter_mut()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+
+    if let Some(address) = args.value("owner-address") {
+        nft.owner_address = match address.trim() {
+            "" => None,
+            address => Some(WalletAddress::from_str(address)?),
+        };
+    }
+    if let Some(category) = args.value("category") {
+        nft.set_category_path(CategoryPath::resolve(category, &definitions)?);
+    }
+    if args.value("royalty-bps").is_some() || args.value("royalty-recipient").is_some() {
+        apply_royalty_args(&mut nft.royalty, args)?;
+    }
+    apply_metadata_args(&mut nft.metadata, args)?;
+
+    // Validação dos dados
+    nft.validate()?;
+    let edited = nft.clone();
+
+    storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    Ok(edited)
+}
+
+pub fn edit_nft(db_path: &str, token_id: &str, args: &Args) -> Result<(), String> {
+    println!("\nEditando metadados do NFT...");
+
+    let mut storage = FileStorage::new(db_path);
+    let result = process_edit_nft(token_id, args, &mut storage).map(|_| ());
+
+    match &result {
+        Ok(_) => println!("Metadados atualizados com sucesso!"),
+        Err(e) => println!("Erro ao editar metadados: {}", e),
+    }
+    result
+}
+
+/// Cria um NFT a partir das opções de `create`. Sem Token ID, ele é gerado
+/// com `--id-strategy` ou com a estratégia da configuração.
+pub fn process_create_command(
+    token_id: Option<&str>,
+    args: &Args,
+    config: &Config,
+    storage: &mut FileStorage,
+) -> Result<NFT, String> {
+    let owner_id = match args.value("owner").map(str::parse::<u64>) {
+        Some(Ok(id)) if id > 0 => id,
+        _ => return Err("Informe --owner com um número inteiro maior que 0".to_string()),
+    };
+    let creation_date = match args.value("date") {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| "Data inválida. Formato esperado: AAAA-MM-DD.".to_string())?,
+        None => chrono::Local::now().date_naive(),
+    };
+    let path = match args.value("category") {
+        Some(category) => {
+            let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+            CategoryPath::resolve(category, &definitions)?
+        }
+        None => return Err("Informe --category".to_string()),
+    };
+    let strategy = match args.value("id-strategy") {
+        Some(strategy) => strategy.parse()?,
+        None => config.token_id_strategy.value.clone(),
+    };
+    let collection_id = args.value("collection");
+    if let Some(collection_id) = collection_id {
+        crate::cli::collections::process_check_collection_capacity(collection_id, storage)?;
+    }
+
+    let token_id = process_token_id(token_id, &strategy, collection_id, storage)?;
+    let mut nft = collect_nft_data(
+        token_id,
+        owner_id,
+        creation_date,
+        path.category.clone(),
+        &config.validation_rules(),
+    )?;
+    nft.set_category_path(path);
+    for tag in args.values("tag") {
+        nft.add_tag(tag);
+    }
+    nft.collection_id = collection_id.map(str::to_string);
+    nft.validate()?;
+    process_create_nft(&nft, storage).map_err(|e| e.to_string())?;
+    Ok(nft)
+}
+
+/// Executa `create [token_id] --owner ID --category C ...`.
+pub fn create_command(config: &Config, args: &Args) -> bool {
+    let mut storage = FileStorage::new(&config.database.value);
+    match process_create_command(args.positional(0), args, config, &mut storage) {
+        Ok(nft) => {
+            println!("NFT salvo com sucesso! Token ID: {}", nft.token_id);
+            true
+        }
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
+
+pub fn migrate_database(db_path: &str) -> Result<(), StorageError> {
+    let mut storage = FileStorage::new(db_path);
+    match storage.migrate() {
+        Ok(None) => println!("Banco de dados inexistente; nada a migrar."),
+        Ok(Some(version)) if version < SCHEMA_VERSION => println!(
+            "Banco de dados migrado da versão {} para a versão {}.",
+            version, SCHEMA_VERSION
+        ),
+        Ok(Some(_)) => println!(
+            "Banco de dados já está na versão {}.",
+            SCHEMA_VERSION
+        ),
+        Err(e) => {
+            println!("Erro ao migrar banco de dados: {}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Com a regra ativa, só é possível transferir para proprietários registrados.
+/// A regra de `rules` (da configuração), quando definida, prevalece sobre o
+/// banco de dados.
+pub fn check_transfer_owner(
+    new_owner_id: u64,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let settings = storage.load_settings().map_err(|e| e.to_string())?;
+    let require_known_owners = rules
+        .require_known_owners
+        .unwrap_or(settings.require_known_owners);
+    if require_known_owners {
+        let owners = storage.load_owners().map_err(|e| e.to_string())?;
+        if !owners.iter().any(|o| o.id == new_owner_id) {
+            return Err(format!("Proprietário {} não está registrado.", new_owner_id));
+        }
+    }
+    Ok(())
+}
+
 pub fn process_update_nft(
     token_id: &str,
     new_owner_id: u64,
+    rules: &ValidationRules,
     storage: &mut FileStorage,
 ) -> Result<(), String> {
     let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    check_transfer_owner(new_owner_id, rules, storage)?;
 
     if let Some(nft) = nfts.iter_mut().find(|n| n.token_id == token_id) {
-        nft.owner_id = new_owner_id;
+        nft.transfer_to(new_owner_id)?;
         
         // Validação dos dados
         nft.validate()?;
@@ -144,12 +535,16 @@ pub fn process_update_nft(
     }
 }
 
-pub fn update_nft(reader: &mut impl BufRead, db_path: &str) {
+pub fn update_nft(
+    reader: &mut impl LineSource,
+    db_path: &str,
+    rules: &ValidationRules,
+) -> Result<(), PromptError> {
     println!("\nAtualizando um NFT...");
 
-    let token_id = get_input("Digite o Token ID do NFT que deseja atualizar: ", reader);
+    let token_id = prompt(reader, "Digite o Token ID do NFT que deseja atualizar: ")?;
     let new_owner_id = loop {
-        let input = get_input("Digite o novo Owner ID (número inteiro maior que 0): ", reader);
+        let input = prompt(reader, "Digite o novo Owner ID (número inteiro maior que 0): ")?;
         match input.parse::<u64>() {
             Ok(id) if id > 0 => {
                 println!("Novo Owner ID recebido: {}", id);
@@ -160,10 +555,11 @@ pub fn update_nft(reader: &mut impl BufRead, db_path: &str) {
     };
 
     let mut storage = FileStorage::new(db_path);
-    match process_update_nft(&token_id, new_owner_id, &mut storage) {
+    match process_update_nft(&token_id, new_owner_id, rules, &mut storage) {
         Ok(_) => println!("NFT atualizado com sucesso!"),
         Err(e) => println!("Erro ao atualizar NFT: {}", e),
     }
+    Ok(())
 }
 
 pub fn process_delete_nft(token_id: &str, storage: &mut FileStorage) -> Result<(), String> {
@@ -179,10 +575,10 @@ pub fn process_delete_nft(token_id: &str, storage: &mut FileStorage) -> Result<(
     }
 }
 
-pub fn delete_nft(reader: &mut impl BufRead, db_path: &str) {
+pub fn delete_nft(reader: &mut impl LineSource, db_path: &str) -> Result<(), PromptError> {
     println!("\nDeletando um NFT...");
 
-    let token_id = get_input("Digite o Token ID do NFT que deseja deletar: ", reader);
+    let token_id = prompt(reader, "Digite o Token ID do NFT que deseja deletar: ")?;
     println!("Token ID a ser deletado: {}", token_id);
 
     let mut storage = FileStorage::new(db_path);
@@ -190,12 +586,38 @@ pub fn delete_nft(reader: &mut impl BufRead, db_path: &str) {
         Ok(_) => println!("NFT deletado com sucesso!"),
         Err(e) => println!("Erro ao deletar NFT: {}", e),
     }
+    Ok(())
+}
+
+pub fn process_verify_ledger(storage: &mut FileStorage) -> Result<LedgerReport, StorageError> {
+    let nfts = storage.load_all()?;
+    storage.ledger().verify(&nfts)
 }
 
-fn get_input(prompt: &str, reader: &mut impl BufRead) -> String {
-    print!("{}", prompt);
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    reader.read_line(&mut input).unwrap();
-    input.trim().to_string()
-}
\ No newline at end of file
+pub fn verify_ledger(db_path: &str) -> Result<LedgerReport, StorageError> {
+    println!("\nVerificando o histórico do banco de dados...");
+
+    let mut storage = FileStorage::new(db_path);
+    match process_verify_ledger(&mut storage) {
+        Ok(report) => {
+            match &report.issue {
+                None => println!(
+                    "Histórico íntegro: {} entradas verificadas.",
+                    report.entries_checked
+                ),
+                Some(issue) => {
+                    println!(
+                        "Histórico inválido após {} entradas verificadas.",
+                        report.entries_checked
+                    );
+                    println!("{}", issue);
+                }
+            }
+            Ok(report)
+        }
+        Err(e) => {
+            println!("Erro ao verificar histórico: {}", e);
+            Err(e)
+        }
+    }
+}
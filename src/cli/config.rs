@@ -0,0 +1,373 @@
+// src/cli/config.rs
+
+use crate::cli::args::{Args, OutputFormat};
+use crate::models::token_id::TokenIdStrategy;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Banco de dados usado quando nada é configurado.
+pub const DEFAULT_DATABASE: &str = "nfts.db";
+
+/// Regras de validação aplicadas além das gravadas no banco de dados.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ValidationRules {
+    /// Substitui a política `owner policy` do banco de dados.
+    pub require_known_owners: Option<bool>,
+    /// Tamanho máximo do Token ID dos NFTs gravados (criados, importados ou copiados).
+    pub max_token_id_length: Option<usize>,
+}
+
+impl ValidationRules {
+    /// Recusa Token IDs maiores que `max_token_id_length`.
+    pub fn check_token_id(&self, token_id: &str) -> Result<(), String> {
+        match self.max_token_id_length {
+            Some(max) if token_id.chars().count() > max => Err(format!(
+                "Token ID deve ter no máximo {} caracteres: '{}'",
+                max, token_id
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Conjunto de opções; o topo do arquivo e cada perfil usam o mesmo formato.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub database: Option<String>,
+    pub format: Option<String>,
+    /// Estratégia de geração de Token IDs (`uuid4`, `uuid7`, `sequential`, `template:...`).
+    pub token_id_strategy: Option<String>,
+    #[serde(default)]
+    pub validation: ValidationRules,
+    /// Bancos de dados nomeados, selecionáveis com `--db NOME`.
+    #[serde(default)]
+    pub databases: BTreeMap<String, String>,
+}
+
+/// Conteúdo do arquivo de configuração (TOML, ou JSON pela extensão `.json`).
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Perfil usado quando `--profile` não é informado.
+    pub default_profile: Option<String>,
+    #[serde(flatten)]
+    pub base: Profile,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ConfigFile {
+    pub fn parse(path: &Path, contents: &str) -> Result<ConfigFile, String> {
+        let parsed = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(contents).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(contents).map_err(|e| e.to_string())
+        };
+        parsed.map_err(|e| match unknown_key(&e) {
+            Some(key) => format!(
+                "Configuração inválida em {}: opção desconhecida '{}'",
+                path.display(),
+                key
+            ),
+            None => format!("Configuração inválida em {}: {}", path.display(), e),
+        })
+    }
+
+    pub fn read(path: &Path) -> Result<ConfigFile, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Erro ao ler {}: {}", path.display(), e))?;
+        ConfigFile::parse(path, &contents)
+    }
+}
+
+/// Extrai a chave recusada por `deny_unknown_fields` da mensagem do serde.
+fn unknown_key(message: &str) -> Option<&str> {
+    let rest = &message[message.find("unknown field `")? + "unknown field `".len()..];
+    rest.find('`').map(|end| &rest[..end])
+}
+
+/// Origem de um valor da configuração efetiva.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Profile(String),
+    Env(&'static str),
+    Option(&'static str),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "padrão"),
+            Source::File(path) => write!(f, "arquivo {}", path.display()),
+            Source::Profile(name) => write!(f, "perfil '{}'", name),
+            Source::Env(name) => write!(f, "variável de ambiente {}", name),
+            Source::Option(name) => write!(f, "opção {}", name),
+        }
+    }
+}
+
+/// Valor da configuração junto com a sua origem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    fn new(value: T) -> Setting<T> {
+        Setting {
+            value,
+            source: Source::Default,
+        }
+    }
+
+    fn set(&mut self, value: Option<T>, source: &Source) {
+        if let Some(value) = value {
+            self.value = value;
+            self.source = source.clone();
+        }
+    }
+}
+
+/// Configuração efetiva, após combinar padrões, arquivo, perfil e ambiente.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Arquivo lido, se houver.
+    pub file: Option<PathBuf>,
+    /// Perfil selecionado, se houver.
+    pub profile: Option<String>,
+    pub database: Setting<String>,
+    pub format: Setting<OutputFormat>,
+    pub token_id_strategy: Setting<TokenIdStrategy>,
+    pub require_known_owners: Setting<Option<bool>>,
+    pub max_token_id_length: Setting<Option<usize>>,
+    /// Registro de bancos de dados nomeados.
+    pub databases: BTreeMap<String, Setting<String>>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            file: None,
+            profile: None,
+            database: Setting::new(DEFAULT_DATABASE.to_string()),
+            format: Setting::new(OutputFormat::Table),
+            token_id_strategy: Setting::new(TokenIdStrategy::default()),
+            require_known_owners: Setting::new(None),
+            max_token_id_length: Setting::new(None),
+            databases: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Combina, em ordem crescente de prioridade: padrões, topo do arquivo,
+    /// perfil selecionado, a variável `DB_PATH` e o banco nomeado em `--db`.
+    /// O banco de um perfil escolhido explicitamente com `--profile`
+    /// prevalece sobre `DB_PATH`.
+    pub fn resolve(
+        file: Option<(PathBuf, ConfigFile)>,
+        profile: Option<&str>,
+        db_path_env: Option<String>,
+        db_name: Option<&str>,
+    ) -> Result<Config, String> {
+        let mut config = Config::default();
+        let explicit_profile = profile.is_some();
+
+        if let Some((path, file)) = file {
+            let source = Source::File(path.clone());
+            let base = path.parent().unwrap_or(Path::new(""));
+            config.apply(&file.base, &source, base)?;
+
+            let profile = profile.map(str::to_string).or(file.default_profile);
+            if let Some(name) = &profile {
+                let selected = file.profiles.get(name).ok_or_else(|| {
+                    format!("Perfil '{}' não encontrado em {}", name, path.display())
+                })?;
+                config.apply(selected, &Source::Profile(name.clone()), base)?;
+            }
+            config.file = Some(path);
+            config.profile = profile;
+        } else if let Some(name) = profile {
+            return Err(format!(
+                "Perfil '{}' informado, mas nenhum arquivo de configuração foi encontrado",
+                name
+            ));
+        }
+
+        let from_profile = matches!(config.database.source, Source::Profile(_));
+        if !(explicit_profile && from_profile) {
+            config.database.set(db_path_env, &Source::Env("DB_PATH"));
+        }
+        if let Some(name) = db_name {
+            let path = config.registered_database(name)?.to_string();
+            config.database.set(Some(path), &Source::Option("--db"));
+        }
+        Ok(config)
+    }
+
+    /// Caminho de um banco de dados registrado.
+    pub fn registered_database(&self, name: &str) -> Result<&str, String> {
+        self.databases
+            .get(name)
+            .map(|setting| setting.value.as_str())
+            .ok_or_else(|| format!("Banco de dados '{}' não registrado", name))
+    }
+
+    /// Aceita o nome de um banco registrado ou o caminho de um arquivo.
+    pub fn database_path(&self, name_or_path: &str) -> String {
+        self.registered_database(name_or_path)
+            .map(str::to_string)
+            .unwrap_or_else(|_| name_or_path.to_string())
+    }
+
+    /// Aplica os valores do perfil. Caminhos relativos de bancos de dados são
+    /// resolvidos a partir de `base`, o diretório do arquivo de configuração.
+    fn apply(&mut self, profile: &Profile, source: &Source, base: &Path) -> Result<(), String> {
+        let format = match &profile.format {
+            Some(format) => Some(format.parse()?),
+            None => None,
+        };
+        let token_id_strategy = match &profile.token_id_strategy {
+            Some(strategy) => Some(strategy.parse()?),
+            None => None,
+        };
+
+        let database = profile.database.as_deref().map(|path| relative_to(base, path));
+        self.database.set(database, source);
+        self.format.set(format, source);
+        self.token_id_strategy.set(token_id_strategy, source);
+        let rules = &profile.validation;
+        self.require_known_owners
+            .set(rules.require_known_owners.map(Some), source);
+        self.max_token_id_length
+            .set(rules.max_token_id_length.map(Some), source);
+        for (name, path) in &profile.databases {
+            let mut setting = Setting::new(relative_to(base, path));
+            setting.source = source.clone();
+            self.databases.insert(name.clone(), setting);
+        }
+        Ok(())
+    }
+
+    pub fn validation_rules(&self) -> ValidationRules {
+        ValidationRules {
+            require_known_owners: self.require_known_owners.value,
+            max_token_id_length: self.max_token_id_length.value,
+        }
+    }
+}
+
+/// Caminho relativo ao diretório `base`; caminhos absolutos ficam como estão.
+fn relative_to(base: &Path, path: &str) -> String {
+    if Path::new(path).is_absolute() {
+        return path.to_string();
+    }
+    base.join(path).to_string_lossy().into_owned()
+}
+
+/// Arquivo padrão: `$XDG_CONFIG_HOME/nft_manager/config.toml`
+/// (ou `~/.config/...`), com `config.json` como alternativa.
+pub fn default_config_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    let dir = base.join("nft_manager");
+    ["config.toml", "config.json"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Carrega a configuração a partir de `--config`, `--profile` e `--db`.
+pub fn load(args: &Args) -> Result<Config, String> {
+    let file = match args.value("config") {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            Some((path.clone(), ConfigFile::read(&path)?))
+        }
+        None => match default_config_path() {
+            Some(path) => Some((path.clone(), ConfigFile::read(&path)?)),
+            None => None,
+        },
+    };
+    Config::resolve(
+        file,
+        args.value("profile"),
+        env::var("DB_PATH").ok(),
+        args.value("db"),
+    )
+}
+
+/// Exibe a configuração efetiva e a origem de cada valor.
+pub fn print_config(config: &Config) {
+    match &config.file {
+        Some(path) => println!("Arquivo de configuração: {}", path.display()),
+        None => println!("Arquivo de configuração: nenhum"),
+    }
+    println!("Perfil: {}", config.profile.as_deref().unwrap_or("nenhum"));
+    println!("------------------------------");
+
+    let optional = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    let rows = [
+        (
+            "database",
+            config.database.value.clone(),
+            &config.database.source,
+        ),
+        (
+            "format",
+            config.format.value.to_string(),
+            &config.format.source,
+        ),
+        (
+            "token_id_strategy",
+            config.token_id_strategy.value.to_string(),
+            &config.token_id_strategy.source,
+        ),
+        (
+            "validation.require_known_owners",
+            optional(config.require_known_owners.value.map(|v| v.to_string())),
+            &config.require_known_owners.source,
+        ),
+        (
+            "validation.max_token_id_length",
+            optional(config.max_token_id_length.value.map(|v| v.to_string())),
+            &config.max_token_id_length.source,
+        ),
+    ];
+    for (key, value, source) in rows {
+        println!("{:<32} = {:<24} ({})", key, value, source);
+    }
+    for (name, path) in &config.databases {
+        println!(
+            "{:<32} = {:<24} ({})",
+            format!("databases.{}", name),
+            path.value,
+            path.source
+        );
+    }
+}
+
+/// Executa `config show`.
+pub fn config_command(config: &Config, args: &Args) -> bool {
+    match args.positional(0) {
+        Some("show") => {
+            print_config(config);
+            true
+        }
+        _ => {
+            println!("Uso: nft_manager config show [--config ARQUIVO] [--profile NOME]");
+            false
+        }
+    }
+}
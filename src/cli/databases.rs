@@ -0,0 +1,435 @@
+// src/cli/databases.rs
+
+use crate::cli::args::Args;
+use crate::cli::config::{Config, ValidationRules};
+use crate::models::category::{CategoryDefinition, CategoryPath};
+use crate::models::nft::NFT;
+use crate::models::query::Query;
+use crate::models::sale::Sale;
+use crate::models::valuation::Valuation;
+use crate::storage::file_storage::FileStorage;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// O que fazer quando o Token ID já existe no banco de destino.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ConflictStrategy {
+    /// Mantém o NFT do destino e ignora o da origem.
+    #[default]
+    Skip,
+    /// Substitui o NFT do destino.
+    Overwrite,
+    /// Grava com um novo Token ID (`<token_id>_2`, `<token_id>_3`, ...).
+    Rename,
+}
+
+impl FromStr for ConflictStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(ConflictStrategy::Skip),
+            "overwrite" => Ok(ConflictStrategy::Overwrite),
+            "rename" => Ok(ConflictStrategy::Rename),
+            other => Err(format!(
+                "Estratégia '{}' inválida (esperado skip, overwrite ou rename)",
+                other
+            )),
+        }
+    }
+}
+
+/// Seleção de NFTs. Critérios vazios aceitam qualquer NFT.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NftFilter {
+    pub token_ids: Vec<String>,
+    /// Categoria ou subcategoria; aceita também as subcategorias abaixo dela.
+    pub category: Option<CategoryPath>,
+    pub owner_id: Option<u64>,
+    pub collection_id: Option<String>,
+    /// Marcadores que o NFT precisa ter, todos eles.
+    pub all_tags: Vec<String>,
+    /// Marcadores dos quais o NFT precisa ter pelo menos um.
+    pub any_tags: Vec<String>,
+    /// Consulta de `--where`.
+    pub query: Option<Query>,
+}
+
+impl NftFilter {
+    /// Lê `--token ID` (repetível), `--category C[/SUB...]`, `--owner ID`,
+    /// `--collection ID`, `--tag T` (repetível, todos), `--any-tag T` (repetível,
+    /// algum) e a consulta de `--where` (veja [`Query`]). As categorias são
+    /// resolvidas com `definitions`, as definidas no banco que será filtrado.
+    pub fn from_args(args: &Args, definitions: &[CategoryDefinition]) -> Result<NftFilter, String> {
+        let owner_id = match args.value("owner") {
+            Some(owner) => Some(
+                owner
+                    .parse::<u64>()
+                    .map_err(|_| "--owner deve ser um número inteiro".to_string())?,
+            ),
+            None => None,
+        };
+        let tags = |option: &str| args.values(option).iter().map(|t| t.to_string()).collect();
+        let query = args
+            .value("where")
+            .map(|expression| {
+                Query::parse(expression, definitions).map_err(|e| e.render(expression))
+            })
+            .transpose()?;
+        Ok(NftFilter {
+            token_ids: args.values("token").iter().map(|t| t.to_string()).collect(),
+            category: args
+                .value("category")
+                .map(|category| CategoryPath::resolve(category, definitions))
+                .transpose()?,
+            owner_id,
+            collection_id: args.value("collection").map(str::to_string),
+            all_tags: tags("tag"),
+            any_tags: tags("any-tag"),
+            query,
+        })
+    }
+
+    /// Como [`NftFilter::from_args`], com as categorias definidas no banco de
+    /// `storage`.
+    pub fn load(args: &Args, storage: &mut FileStorage) -> Result<NftFilter, String> {
+        let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+        NftFilter::from_args(args, &definitions)
+    }
+
+    /// Verdadeiro se nenhum critério foi informado.
+    pub fn is_empty(&self) -> bool {
+        *self == NftFilter::default()
+    }
+
+    pub fn matches(&self, nft: &NFT) -> bool {
+        (self.token_ids.is_empty() || self.token_ids.contains(&nft.token_id))
+            && self
+                .category
+                .as_ref()
+                .is_none_or(|c| c.contains(&nft.category_path()))
+            && self.owner_id.is_none_or(|id| id == nft.owner_id)
+            && self
+                .collection_id
+                .as_ref()
+                .is_none_or(|id| nft.collection_id.as_ref() == Some(id))
+            && self.all_tags.iter().all(|tag| nft.has_tag(tag))
+            && (self.any_tags.is_empty() || self.any_tags.iter().any(|tag| nft.has_tag(tag)))
+            && self.query.as_ref().is_none_or(|query| query.matches(nft))
+    }
+}
+
+/// Resultado de uma cópia ou movimentação entre bancos.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransferReport {
+    /// Token IDs gravados no destino sem conflito.
+    pub copied: Vec<String>,
+    /// Token IDs que substituíram NFTs do destino.
+    pub overwritten: Vec<String>,
+    /// Pares (Token ID de origem, novo Token ID no destino).
+    pub renamed: Vec<(String, String)>,
+    /// Token IDs ignorados por já existirem no destino.
+    pub skipped: Vec<String>,
+    /// Token IDs cuja coleção não existe no destino e foi removida.
+    pub detached: Vec<String>,
+}
+
+impl TransferReport {
+    pub fn transferred(&self) -> usize {
+        self.copied.len() + self.overwritten.len() + self.renamed.len()
+    }
+}
+
+/// Primeiro Token ID livre na forma `<token_id>_N`.
+fn free_token_id(token_id: &str, taken: &[NFT]) -> String {
+    (2..)
+        .map(|n| format!("{}_{}", token_id, n))
+        .find(|candidate| !taken.iter().any(|nft| &nft.token_id == candidate))
+        .expect("sequência infinita")
+}
+
+/// Copia os NFTs selecionados da origem para o destino. Com `remove_from_source`,
+/// os NFTs transferidos são removidos da origem; os ignorados permanecem nela.
+///
+/// As vendas e avaliações de cada NFT o acompanham, com o Token ID do
+/// destino; as de um NFT substituído no destino são descartadas. Todo Token
+/// ID gravado no destino precisa respeitar `rules`; se algum não respeitar,
+/// nada é gravado.
+///
+/// Origem e destino são arquivos distintos, gravados um após o outro; veja
+/// [`restore_destination`] para o que acontece se a gravação da origem falhar.
+pub fn process_copy(
+    source: &mut FileStorage,
+    destination: &mut FileStorage,
+    filter: &NftFilter,
+    strategy: ConflictStrategy,
+    remove_from_source: bool,
+    rules: &ValidationRules,
+) -> Result<TransferReport, String> {
+    let mut source_nfts = source.load_all().map_err(|e| e.to_string())?;
+    let source_sales = source.load_sales().map_err(|e| e.to_string())?;
+    let source_valuations = source.load_valuations().map_err(|e| e.to_string())?;
+    let mut destination_nfts = destination.load_all().map_err(|e| e.to_string())?;
+    let mut sales = destination.load_sales().map_err(|e| e.to_string())?;
+    let mut valuations = destination.load_valuations().map_err(|e| e.to_string())?;
+    let original = Snapshot {
+        nfts: destination_nfts.clone(),
+        sales: sales.clone(),
+        valuations: valuations.clone(),
+    };
+    let collections = destination.load_collections().map_err(|e| e.to_string())?;
+
+    let mut report = TransferReport::default();
+    let mut transferred: Vec<String> = Vec::new();
+
+    for nft in source_nfts.iter().filter(|nft| filter.matches(nft)) {
+        let mut copy = nft.clone();
+        let detached = copy
+            .collection_id
+            .take_if(|id| !collections.iter().any(|c| &c.id == id))
+            .is_some();
+
+        let overwritten = match destination_nfts
+            .iter()
+            .position(|existing| existing.token_id == nft.token_id)
+        {
+            None => {
+                rules.check_token_id(&copy.token_id)?;
+                report.copied.push(nft.token_id.clone());
+                None
+            }
+            Some(_) if strategy == ConflictStrategy::Skip => {
+                report.skipped.push(nft.token_id.clone());
+                continue;
+            }
+            Some(index) if strategy == ConflictStrategy::Overwrite => {
+                rules.check_token_id(&copy.token_id)?;
+                report.overwritten.push(nft.token_id.clone());
+                Some(index)
+            }
+            Some(_) => {
+                copy.token_id = free_token_id(&nft.token_id, &destination_nfts);
+                rules.check_token_id(&copy.token_id)?;
+                report
+                    .renamed
+                    .push((nft.token_id.clone(), copy.token_id.clone()));
+                None
+            }
+        };
+
+        // Vendas e avaliações acompanham o NFT, com o Token ID do destino
+        sales.retain(|sale| sale.token_id != copy.token_id);
+        valuations.retain(|valuation| valuation.token_id != copy.token_id);
+        for sale in source_sales.iter().filter(|s| s.token_id == nft.token_id) {
+            sales.push(Sale {
+                token_id: copy.token_id.clone(),
+                ..sale.clone()
+            });
+        }
+        for valuation in source_valuations
+            .iter()
+            .filter(|v| v.token_id == nft.token_id)
+        {
+            valuations.push(Valuation {
+                token_id: copy.token_id.clone(),
+                ..valuation.clone()
+            });
+        }
+        match overwritten {
+            Some(index) => destination_nfts[index] = copy,
+            None => destination_nfts.push(copy),
+        }
+        if detached {
+            report.detached.push(nft.token_id.clone());
+        }
+        transferred.push(nft.token_id.clone());
+    }
+
+    if transferred.is_empty() {
+        return Ok(report);
+    }
+
+    destination
+        .save_all_with_prices(&destination_nfts, &sales, &valuations)
+        .map_err(|e| e.to_string())?;
+    if remove_from_source {
+        source_nfts.retain(|nft| !transferred.contains(&nft.token_id));
+        if let Err(e) = source.save_all(&source_nfts) {
+            return Err(restore_destination(
+                e.to_string(),
+                source,
+                destination,
+                &original,
+                &transferred,
+            ));
+        }
+    }
+    Ok(report)
+}
+
+/// Conteúdo do destino antes da cópia, para restaurá-lo em caso de falha.
+struct Snapshot {
+    nfts: Vec<NFT>,
+    sales: Vec<Sale>,
+    valuations: Vec<Valuation>,
+}
+
+/// Trata a falha ao remover os NFTs movidos da origem, depois de o destino já
+/// ter sido gravado. Se a origem ainda tiver os NFTs, o destino volta ao
+/// conteúdo anterior para que eles não fiquem duplicados; se a origem foi
+/// gravada (a falha foi no histórico), o destino é mantido para não perdê-los.
+/// A mensagem de erro informa o estado em que os dois bancos ficaram.
+fn restore_destination(
+    error: String,
+    source: &mut FileStorage,
+    destination: &mut FileStorage,
+    original: &Snapshot,
+    transferred: &[String],
+) -> String {
+    let still_in_source = source.load_all().is_ok_and(|nfts| {
+        nfts.iter().any(|nft| transferred.contains(&nft.token_id))
+    });
+    if !still_in_source {
+        return format!(
+            "Erro ao atualizar a origem: {}. Os NFTs já foram removidos dela e estão no destino.",
+            error
+        );
+    }
+    let restored =
+        destination.save_all_with_prices(&original.nfts, &original.sales, &original.valuations);
+    match restored {
+        Ok(_) => format!(
+            "Erro ao remover os NFTs da origem: {}. O destino foi restaurado.",
+            error
+        ),
+        Err(rollback) => format!(
+            "Erro ao remover os NFTs da origem: {}. Não foi possível restaurar o destino ({}); \
+             NFTs presentes nos dois bancos: {}",
+            error,
+            rollback,
+            transferred.join(", ")
+        ),
+    }
+}
+
+fn print_report(report: &TransferReport, verb: &str) {
+    for token_id in &report.copied {
+        println!("{}: {}", verb, token_id);
+    }
+    for token_id in &report.overwritten {
+        println!("Substituído no destino: {}", token_id);
+    }
+    for (from, to) in &report.renamed {
+        println!("Renomeado: {} -> {}", from, to);
+    }
+    for token_id in &report.skipped {
+        println!("Ignorado (já existe no destino): {}", token_id);
+    }
+    for token_id in &report.detached {
+        println!("Coleção inexistente no destino removida de: {}", token_id);
+    }
+    println!(
+        "{} NFT(s) transferido(s), {} ignorado(s).",
+        report.transferred(),
+        report.skipped.len()
+    );
+}
+
+/// Verdadeiro se os dois caminhos levam ao mesmo arquivo, mesmo escritos de
+/// formas diferentes (`./x.db` e `x.db`, links simbólicos). Um destino que
+/// ainda não existe é comparado pelo diretório, já resolvido, e pelo nome.
+pub fn same_database(a: &str, b: &str) -> bool {
+    let resolve = |path: &str| {
+        let path = Path::new(path);
+        fs::canonicalize(path).ok().or_else(|| {
+            let parent = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            Some(fs::canonicalize(parent).ok()?.join(path.file_name()?))
+        })
+    };
+    match (resolve(a), resolve(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => Path::new(a) == Path::new(b),
+    }
+}
+
+/// Executa `copy <destino>` ou `move <destino>` a partir do banco selecionado.
+pub fn copy_command(config: &Config, args: &Args, remove_from_source: bool) -> bool {
+    let command = if remove_from_source { "move" } else { "copy" };
+    let result = match args.positional(0) {
+        Some(target) => {
+            let mut source = FileStorage::new(&config.database.value);
+            NftFilter::load(args, &mut source).and_then(|filter| {
+                let strategy = match args.value("on-conflict") {
+                    Some(strategy) => strategy.parse()?,
+                    None => ConflictStrategy::default(),
+                };
+                let destination_path = config.database_path(target);
+                if same_database(&config.database.value, &destination_path) {
+                    return Err("Origem e destino são o mesmo banco de dados".to_string());
+                }
+
+                let mut destination = FileStorage::new(&destination_path);
+                let report = process_copy(
+                    &mut source,
+                    &mut destination,
+                    &filter,
+                    strategy,
+                    remove_from_source,
+                    &config.validation_rules(),
+                )?;
+                let verb = if remove_from_source {
+                    "Movido"
+                } else {
+                    "Copiado"
+                };
+                print_report(&report, verb);
+                Ok(())
+            })
+        }
+        None => Err(format!(
+            "Uso: nft_manager {} <banco> [--token ID] [--category C] [--owner ID] \
+             [--collection ID] [--on-conflict skip|overwrite|rename]",
+            command
+        )),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
+
+/// Executa `db list`: bancos registrados e quantidade de NFTs em cada um.
+pub fn db_command(config: &Config, args: &Args) -> bool {
+    match args.positional(0) {
+        Some("list") => {
+            if config.databases.is_empty() {
+                println!("Nenhum banco de dados registrado.");
+            }
+            for (name, path) in &config.databases {
+                let marker = if path.value == config.database.value {
+                    "*"
+                } else {
+                    " "
+                };
+                let count = match FileStorage::new(&path.value).load_all() {
+                    Ok(nfts) => format!("{} NFT(s)", nfts.len()),
+                    Err(e) => format!("erro: {}", e),
+                };
+                println!("{} {:<16} {:<32} {}", marker, name, path.value, count);
+            }
+            true
+        }
+        _ => {
+            println!("Uso: nft_manager db list");
+            false
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// src/cli/erc721.rs
+
+use crate::cli::args::Args;
+use crate::cli::config::{Config, ValidationRules};
+use crate::cli::databases::NftFilter;
+use crate::models::category::CategoryPath;
+use crate::storage::erc721::{
+    document_path, list_documents, read_document, write_document, Erc721Metadata, ImportOptions,
+    DEFAULT_CATEGORY_ATTRIBUTE,
+};
+use crate::storage::file_storage::FileStorage;
+use chrono::NaiveDate;
+use std::fs;
+use std::path::Path;
+
+/// Resultado de uma importação de documentos de metadados.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportReport {
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Resultado de uma exportação de documentos de metadados.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExportReport {
+    pub exported: Vec<String>,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Importa os arquivos `<token_id>.json` do diretório. Tokens já existentes são
+/// ignorados; os NFTs válidos são gravados de uma só vez.
+pub fn process_import_metadata(
+    dir: &Path,
+    options: &ImportOptions,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<ImportReport, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let mut report = ImportReport::default();
+
+    for path in list_documents(dir).map_err(|e| e.to_string())? {
+        let file_name = path.display().to_string();
+        let token_id = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(stem) => stem.to_string(),
+            None => {
+                report
+                    .failed
+                    .push((file_name, "Nome de arquivo inválido".to_string()));
+                continue;
+            }
+        };
+
+        if nfts.iter().any(|n| n.token_id == token_id) {
+            report.skipped.push(token_id);
+            continue;
+        }
+
+        let nft = rules.check_token_id(&token_id).and_then(|_| {
+            read_document(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|document| document.into_nft(&token_id, options, &definitions))
+        });
+        match nft {
+            Ok(nft) => {
+                nfts.push(nft);
+                report.imported.push(token_id);
+            }
+            Err(e) => report.failed.push((file_name, e)),
+        }
+    }
+
+    if !report.imported.is_empty() {
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    }
+    Ok(report)
+}
+
+/// Exporta cada NFT selecionado pelo filtro para `<dir>/<token_id>.json`,
+/// criando o diretório se necessário.
+pub fn process_export_metadata(
+    dir: &Path,
+    category_attribute: &str,
+    filter: &NftFilter,
+    storage: &mut FileStorage,
+) -> Result<ExportReport, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let mut report = ExportReport::default();
+
+    for nft in nfts.iter().filter(|nft| filter.matches(nft)) {
+        let document = Erc721Metadata::from_nft(nft, category_attribute);
+        let result = document_path(dir, &nft.token_id)
+            .and_then(|path| write_document(&path, &document).map_err(|e| e.to_string()));
+        match result {
+            Ok(_) => report.exported.push(nft.token_id.clone()),
+            Err(e) => report.failed.push((nft.token_id.clone(), e)),
+        }
+    }
+
+    Ok(report)
+}
+
+pub fn import_metadata(config: &Config, dir: &str, args: &Args) -> Result<ImportReport, String> {
+    println!("\nImportando metadados de '{}'...", dir);
+
+    let mut storage = FileStorage::new(&config.database.value);
+    let result = import_options(args, &mut storage).and_then(|options| {
+        process_import_metadata(
+            Path::new(dir),
+            &options,
+            &config.validation_rules(),
+            &mut storage,
+        )
+    });
+
+    match &result {
+        Ok(report) => {
+            println!("NFTs importados: {}", report.imported.len());
+            if !report.skipped.is_empty() {
+                println!(
+                    "Ignorados (Token ID já existente): {}",
+                    report.skipped.join(", ")
+                );
+            }
+            for (file, reason) in &report.failed {
+                println!("Falha em {}: {}", file, reason);
+            }
+        }
+        Err(e) => println!("Erro ao importar metadados: {}", e),
+    }
+    result
+}
+
+pub fn export_metadata(db_path: &str, dir: &str, args: &Args) -> Result<ExportReport, String> {
+    println!("\nExportando metadados para '{}'...", dir);
+
+    let category_attribute = args
+        .value("category-attribute")
+        .unwrap_or(DEFAULT_CATEGORY_ATTRIBUTE);
+    let mut storage = FileStorage::new(db_path);
+    let result = NftFilter::load(args, &mut storage).and_then(|filter| {
+        process_export_metadata(Path::new(dir), category_attribute, &filter, &mut storage)
+    });
+
+    match &result {
+        Ok(report) => {
+            println!("Documentos exportados: {}", report.exported.len());
+            for (token_id, reason) in &report.failed {
+                println!("Falha em {}: {}", token_id, reason);
+            }
+        }
+        Err(e) => println!("Erro ao exportar metadados: {}", e),
+    }
+    result
+}
+
+fn import_options(args: &Args, storage: &mut FileStorage) -> Result<ImportOptions, String> {
+    let owner_id = match args.value("owner").map(str::parse::<u64>) {
+        Some(Ok(id)) if id > 0 => id,
+        _ => return Err("Informe --owner com um número inteiro maior que 0".to_string()),
+    };
+    let creation_date = match args.value("date") {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| "Data inválida. Formato esperado: AAAA-MM-DD.".to_string())?,
+        None => chrono::Local::now().date_naive(),
+    };
+    let definitions = storage.load_categories().map_err(|e| e.to_string())?;
+    let default_category = args
+        .value("default-category")
+        .map(|category| CategoryPath::resolve(category, &definitions))
+        .transpose()?;
+
+    Ok(ImportOptions {
+        owner_id,
+        creation_date,
+        category_attribute: args
+            .value("category-attribute")
+            .unwrap_or(DEFAULT_CATEGORY_ATTRIBUTE)
+            .to_string(),
+        default_category,
+    })
+}
@@ -0,0 +1,495 @@
+// src/cli/merge.rs
+
+use crate::cli::args::{Args, OutputFormat};
+use crate::cli::config::Config;
+use crate::models::category::{category_key, Category};
+use crate::models::collection::Royalty;
+use crate::models::nft::NFT;
+use crate::models::shares::Shares;
+use crate::models::wallet::WalletAddress;
+use crate::storage::file_storage::{Database, FileStorage};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+/// Campo alterado entre duas versões de um NFT. Valores ausentes são `None`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ChangedNft {
+    pub token_id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Diferenças do banco `a` para o banco `b`.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct NftDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<ChangedNft>,
+}
+
+impl NftDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Campos de um NFT em forma plana; os metadados viram `metadata.<campo>`.
+/// Campos ausentes não aparecem no mapa.
+fn fields(nft: &NFT) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    if let Ok(Value::Object(object)) = serde_json::to_value(nft) {
+        for (key, value) in object {
+            match value {
+                Value::Object(metadata) if key == "metadata" => {
+                    for (field, value) in metadata {
+                        fields.insert(format!("metadata.{}", field), value);
+                    }
+                }
+                value => {
+                    fields.insert(key, value);
+                }
+            }
+        }
+    }
+    fields
+}
+
+/// Reconstrói o NFT a partir dos campos planos.
+fn from_fields(fields: BTreeMap<String, Value>) -> Result<NFT, String> {
+    let mut object = Map::new();
+    let mut metadata = Map::new();
+    for (key, value) in fields {
+        match key.strip_prefix("metadata.") {
+            Some(field) => metadata.insert(field.to_string(), value),
+            None => object.insert(key, value),
+        };
+    }
+    if !metadata.is_empty() {
+        object.insert("metadata".to_string(), Value::Object(metadata));
+    }
+    serde_json::from_value(Value::Object(object)).map_err(|e| e.to_string())
+}
+
+/// Valor legível de um campo, usando o `Display` dos tipos conhecidos.
+fn display(field: &str, value: &Value) -> String {
+    let shown = match field {
+        "category" => serde_json::from_value::<Category>(value.clone())
+            .ok()
+            .map(|c| c.to_string()),
+        "owner_address" => serde_json::from_value::<WalletAddress>(value.clone())
+            .ok()
+            .map(|w| w.to_string()),
+        "royalty" => serde_json::from_value::<Royalty>(value.clone())
+            .ok()
+            .map(|r| r.to_string()),
+        "shares" => serde_json::from_value::<Shares>(value.clone())
+            .ok()
+            .map(|s| s.to_string()),
+        _ => None,
+    };
+    shown.unwrap_or_else(|| match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    })
+}
+
+/// Campos que diferem entre duas versões do mesmo NFT.
+pub fn field_changes(before: &NFT, after: &NFT) -> Vec<FieldChange> {
+    let before = fields(before);
+    let after = fields(after);
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| FieldChange {
+            field: key.clone(),
+            before: before.get(key).map(|v| display(key, v)),
+            after: after.get(key).map(|v| display(key, v)),
+        })
+        .collect()
+}
+
+pub fn diff_nfts(a: &[NFT], b: &[NFT]) -> NftDiff {
+    let a: BTreeMap<&str, &NFT> = a.iter().map(|n| (n.token_id.as_str(), n)).collect();
+    let b: BTreeMap<&str, &NFT> = b.iter().map(|n| (n.token_id.as_str(), n)).collect();
+
+    let mut diff = NftDiff::default();
+    for (token_id, before) in &a {
+        match b.get(token_id) {
+            None => diff.removed.push(token_id.to_string()),
+            Some(after) => {
+                let changes = field_changes(before, after);
+                if !changes.is_empty() {
+                    diff.changed.push(ChangedNft {
+                        token_id: token_id.to_string(),
+                        changes,
+                    });
+                }
+            }
+        }
+    }
+    diff.added = b
+        .keys()
+        .filter(|token_id| !a.contains_key(*token_id))
+        .map(|token_id| token_id.to_string())
+        .collect();
+    diff
+}
+
+/// Como resolver conflitos na mesclagem.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ConflictPolicy {
+    /// Não grava o resultado enquanto houver conflitos.
+    #[default]
+    Fail,
+    Ours,
+    Theirs,
+}
+
+impl FromStr for ConflictPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fail" => Ok(ConflictPolicy::Fail),
+            "ours" => Ok(ConflictPolicy::Ours),
+            "theirs" => Ok(ConflictPolicy::Theirs),
+            other => Err(format!(
+                "Política '{}' inválida (esperado fail, ours ou theirs)",
+                other
+            )),
+        }
+    }
+}
+
+/// Alteração incompatível feita pelos dois lados. `field` é `None` quando um
+/// lado removeu o NFT e o outro o alterou.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MergeConflict {
+    pub token_id: String,
+    pub field: Option<String>,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeResult {
+    pub nfts: Vec<NFT>,
+    /// Conflitos encontrados; com `Ours` ou `Theirs`, já resolvidos.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Mesclagem de três vias por Token ID. Quando os dois lados alteram o mesmo
+/// NFT, a mesclagem é feita campo a campo.
+pub fn merge_nfts(
+    base: &[NFT],
+    ours: &[NFT],
+    theirs: &[NFT],
+    policy: ConflictPolicy,
+) -> Result<MergeResult, String> {
+    // Mantém a ordem do nosso lado e acrescenta os novos do outro lado
+    let mut order: Vec<String> = ours.iter().map(|n| n.token_id.clone()).collect();
+    for nft in theirs {
+        if !order.contains(&nft.token_id) {
+            order.push(nft.token_id.clone());
+        }
+    }
+
+    let index = |nfts: &[NFT]| -> BTreeMap<String, NFT> {
+        nfts.iter()
+            .map(|n| (n.token_id.clone(), n.clone()))
+            .collect()
+    };
+    let (base, ours, theirs) = (index(base), index(ours), index(theirs));
+
+    let mut result = MergeResult::default();
+    for token_id in order {
+        let (b, o, t) = (
+            base.get(&token_id),
+            ours.get(&token_id),
+            theirs.get(&token_id),
+        );
+        let merged = if o == t || t == b {
+            o.cloned()
+        } else if o == b {
+            t.cloned()
+        } else {
+            match (b, o, t) {
+                (_, Some(o), Some(t)) => {
+                    Some(merge_fields(&token_id, b, o, t, policy, &mut result)?)
+                }
+                // Um lado removeu e o outro alterou
+                _ => {
+                    let state = |nft: Option<&NFT>| match nft {
+                        Some(_) => "alterado".to_string(),
+                        None => "removido".to_string(),
+                    };
+                    result.conflicts.push(MergeConflict {
+                        token_id: token_id.clone(),
+                        field: None,
+                        base: b.map(|_| "presente".to_string()),
+                        ours: Some(state(o)),
+                        theirs: Some(state(t)),
+                    });
+                    match policy {
+                        ConflictPolicy::Theirs => t.cloned(),
+                        _ => o.cloned(),
+                    }
+                }
+            }
+        };
+        result.nfts.extend(merged);
+    }
+    Ok(result)
+}
+
+fn merge_fields(
+    token_id: &str,
+    base: Option<&NFT>,
+    ours: &NFT,
+    theirs: &NFT,
+    policy: ConflictPolicy,
+    result: &mut MergeResult,
+) -> Result<NFT, String> {
+    let base = base.map(fields).unwrap_or_default();
+    let ours = fields(ours);
+    let theirs = fields(theirs);
+    let keys: BTreeSet<&String> = base
+        .keys()
+        .chain(ours.keys())
+        .chain(theirs.keys())
+        .collect();
+
+    let mut merged = BTreeMap::new();
+    for key in keys {
+        let (b, o, t) = (base.get(key), ours.get(key), theirs.get(key));
+        let value = if o == t || t == b {
+            o
+        } else if o == b {
+            t
+        } else {
+            result.conflicts.push(MergeConflict {
+                token_id: token_id.to_string(),
+                field: Some(key.clone()),
+                base: b.map(|v| display(key, v)),
+                ours: o.map(|v| display(key, v)),
+                theirs: t.map(|v| display(key, v)),
+            });
+            match policy {
+                ConflictPolicy::Theirs => t,
+                _ => o,
+            }
+        };
+        if let Some(value) = value {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    from_fields(merged).map_err(|e| format!("NFT '{}': {}", token_id, e))
+}
+
+/// Une dois conjuntos identificados por `id`; em caso de repetição, vale o nosso.
+fn union_by_id<T: Clone>(ours: Vec<T>, theirs: Vec<T>, id: impl Fn(&T) -> String) -> Vec<T> {
+    let mut merged = ours;
+    for item in theirs {
+        if !merged.iter().any(|existing| id(existing) == id(&item)) {
+            merged.push(item);
+        }
+    }
+    merged
+}
+
+/// Mescla os bancos e grava o resultado em `output`, de uma só vez, exceto
+/// quando há conflitos com a política `Fail`. Um NFT mesclado inválido
+/// recusa a mesclagem. Coleções, proprietários e categorias definidas são
+/// unidos por ID ou nome (prevalece o nosso lado), as vendas e avaliações
+/// dos dois lados são unidas e as configurações vêm do nosso lado.
+pub fn process_merge(
+    base: &mut FileStorage,
+    ours: &mut FileStorage,
+    theirs: &mut FileStorage,
+    output: &mut FileStorage,
+    policy: ConflictPolicy,
+) -> Result<MergeResult, String> {
+    let result = merge_nfts(
+        &base.load_all().map_err(|e| e.to_string())?,
+        &ours.load_all().map_err(|e| e.to_string())?,
+        &theirs.load_all().map_err(|e| e.to_string())?,
+        policy,
+    )?;
+    if policy == ConflictPolicy::Fail && !result.conflicts.is_empty() {
+        return Ok(result);
+    }
+    for nft in &result.nfts {
+        nft.validate()
+            .map_err(|e| format!("NFT '{}' mesclado é inválido: {}", nft.token_id, e))?;
+    }
+
+    let collections = union_by_id(
+        ours.load_collections().map_err(|e| e.to_string())?,
+        theirs.load_collections().map_err(|e| e.to_string())?,
+        |c| c.id.clone(),
+    );
+    let owners = union_by_id(
+        ours.load_owners().map_err(|e| e.to_string())?,
+        theirs.load_owners().map_err(|e| e.to_string())?,
+        |o| o.id.to_string(),
+    );
+    let categories = union_by_id(
+        ours.load_categories().map_err(|e| e.to_string())?,
+        theirs.load_categories().map_err(|e| e.to_string())?,
+        |c| category_key(&c.name),
+    );
+    let mut sales = ours.load_sales().map_err(|e| e.to_string())?;
+    for sale in theirs.load_sales().map_err(|e| e.to_string())? {
+        if !sales.contains(&sale) {
+            sales.push(sale);
+        }
+    }
+    let mut valuations = ours.load_valuations().map_err(|e| e.to_string())?;
+    for valuation in theirs.load_valuations().map_err(|e| e.to_string())? {
+        if !valuations.contains(&valuation) {
+            valuations.push(valuation);
+        }
+    }
+    let settings = ours.load_settings().map_err(|e| e.to_string())?;
+
+    let mut database = Database::default();
+    database.nfts = result.nfts.clone();
+    database.collections = collections;
+    database.owners = owners;
+    database.settings = settings;
+    database.categories = categories;
+    database.sales = sales;
+    database.valuations = valuations;
+    output.save_database(database).map_err(|e| e.to_string())?;
+    Ok(result)
+}
+
+fn value_or_dash(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("-")
+}
+
+fn print_diff(diff: &NftDiff) {
+    for token_id in &diff.added {
+        println!("+ {}", token_id);
+    }
+    for token_id in &diff.removed {
+        println!("- {}", token_id);
+    }
+    for changed in &diff.changed {
+        println!("~ {}", changed.token_id);
+        for change in &changed.changes {
+            println!(
+                "    {}: {} -> {}",
+                change.field,
+                value_or_dash(&change.before),
+                value_or_dash(&change.after)
+            );
+        }
+    }
+    println!(
+        "{} adicionado(s), {} removido(s), {} alterado(s).",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+}
+
+fn print_conflicts(conflicts: &[MergeConflict]) {
+    for conflict in conflicts {
+        match &conflict.field {
+            Some(field) => println!("Conflito em {} ({}):", conflict.token_id, field),
+            None => println!("Conflito em {} (remoção e alteração):", conflict.token_id),
+        }
+        println!("    base:  {}", value_or_dash(&conflict.base));
+        println!("    nosso: {}", value_or_dash(&conflict.ours));
+        println!("    deles: {}", value_or_dash(&conflict.theirs));
+    }
+}
+
+/// Executa `diff <a> <b> [--format table|json]`.
+pub fn diff_command(config: &Config, args: &Args) -> bool {
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some(a), Some(b)) => OutputFormat::from_args(args).and_then(|format| {
+            let a = FileStorage::new(&config.database_path(a))
+                .load_all()
+                .map_err(|e| e.to_string())?;
+            let b = FileStorage::new(&config.database_path(b))
+                .load_all()
+                .map_err(|e| e.to_string())?;
+            let diff = diff_nfts(&a, &b);
+            match format {
+                OutputFormat::Table => print_diff(&diff),
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&diff).map_err(|e| e.to_string())?
+                ),
+            }
+            Ok(())
+        }),
+        _ => Err("Uso: nft_manager diff <banco_a> <banco_b> [--format table|json]".to_string()),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
+
+/// Executa `merge <base> <nosso> <deles> -o <saída> [--on-conflict fail|ours|theirs]`.
+pub fn merge_command(config: &Config, args: &Args) -> bool {
+    let output = args.value("o").or(args.value("output"));
+    let result = match (args.positionals(), output) {
+        ([base, ours, theirs], Some(output)) => args
+            .value("on-conflict")
+            .map(ConflictPolicy::from_str)
+            .unwrap_or(Ok(ConflictPolicy::default()))
+            .and_then(|policy| {
+                let open = |name: &str| FileStorage::new(&config.database_path(name));
+                let result = process_merge(
+                    &mut open(base),
+                    &mut open(ours),
+                    &mut open(theirs),
+                    &mut open(output),
+                    policy,
+                )?;
+                print_conflicts(&result.conflicts);
+                if policy == ConflictPolicy::Fail && !result.conflicts.is_empty() {
+                    return Err(format!(
+                        "{} conflito(s); nada foi gravado. Use --on-conflict ours|theirs para resolvê-los.",
+                        result.conflicts.len()
+                    ));
+                }
+                println!(
+                    "Mesclagem gravada em {}: {} NFT(s), {} conflito(s) resolvido(s).",
+                    output,
+                    result.nfts.len(),
+                    result.conflicts.len()
+                );
+                Ok(())
+            }),
+        _ => Err(
+            "Uso: nft_manager merge <base> <nosso> <deles> -o <saída> [--on-conflict fail|ours|theirs]"
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
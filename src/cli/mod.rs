@@ -1,17 +1,60 @@
 // src/cli/mod.rs
 
+pub mod args;
+pub mod bulk;
+pub mod categories;
+pub mod chart;
+pub mod collections;
 pub mod commands;
-use commands::{create_nft, delete_nft, read_nft, update_nft};
-use std::env;
-use std::io::{self, BufRead, BufReader, Write};
+pub mod config;
+pub mod databases;
+pub mod erc721;
+pub mod merge;
+pub mod owners;
+pub mod portfolio;
+pub mod sales;
+pub mod search;
+pub mod shares;
+pub mod shell;
+pub mod stats;
+pub mod tags;
+pub mod tui;
+pub mod valuations;
+use args::Args;
+use commands::{
+    create_command, create_nft, delete_nft, edit_nft, list_command, migrate_database, read_nft,
+    show_nft, update_nft, verify_ledger,
+};
+use shell::{LineSource, PromptError, Shell};
+use std::io::{self, BufReader, IsTerminal};
 
 /// Função principal para executar a interface de linha de comando (CLI).
-pub fn run_cli() {
-    // Obtém o caminho do banco de dados da variável de ambiente ou usa o padrão
-    let db_path = env::var("DB_PATH").unwrap_or_else(|_| "nfts.db".to_string());
+/// Em um terminal, usa edição de linha com histórico e completação.
+/// Aceita `--config ARQUIVO`, `--profile NOME` e `--db NOME`.
+pub fn run_cli(raw_args: &[String]) {
+    let config = match Args::parse(raw_args, FLAGS).and_then(|args| config::load(&args)) {
+        Ok(config) => config,
+        Err(e) => {
+            println!("Erro: {}", e);
+            return;
+        }
+    };
+    if io::stdin().is_terminal() {
+        match Shell::new(&config.database.value) {
+            Ok(mut shell) => return run_menu(&mut shell, &config),
+            Err(e) => println!("Erro ao iniciar o terminal interativo: {}", e),
+        }
+    }
+
     let stdin = io::stdin();
     let mut reader = BufReader::new(stdin.lock());
+    run_menu(&mut reader, &config);
+}
 
+/// Menu principal. Termina ao escolher "Sair" ou no fim da entrada.
+fn run_menu(reader: &mut impl LineSource, config: &config::Config) {
+    let db_path = config.database.value.as_str();
+    let rules = config.validation_rules();
     loop {
         println!("\n=== Gerenciador de NFTs ===");
         println!("1. Criar NFT");
@@ -20,31 +63,132 @@ pub fn run_cli() {
         println!("4. Deletar NFT");
         println!("5. Sair");
 
-        let choice = get_input("Selecione uma opção: ", &mut reader);
+        let choice = match reader.read_line("Selecione uma opção: ") {
+            Ok(Some(choice)) => choice,
+            Ok(None) => {
+                println!("\nSaindo...");
+                break;
+            }
+            Err(e) => {
+                println!("Erro ao ler entrada: {}", e);
+                break;
+            }
+        };
 
-        match choice.trim() {
-            "1" => create_nft(&mut reader, &db_path),
+        let result = match choice.trim() {
+            "1" => create_nft(reader, db_path, &rules, &config.token_id_strategy.value),
             "2" => {
-                if let Err(e) = read_nft(&db_path) {
+                if let Err(e) = read_nft(db_path) {
                     println!("Erro ao listar NFTs: {}", e);
                 }
+                Ok(())
             }
-            "3" => update_nft(&mut reader, &db_path),
-            "4" => delete_nft(&mut reader, &db_path),
+            "3" => update_nft(reader, db_path, &rules),
+            "4" => delete_nft(reader, db_path),
             "5" => {
                 println!("Saindo...");
                 break;
             }
-            _ => println!("Opção inválida. Por favor, tente novamente."),
+            _ => {
+                println!("Opção inválida. Por favor, tente novamente.");
+                Ok(())
+            }
+        };
+
+        // O fim da entrada cancela a operação e encerra a sessão
+        match result {
+            Ok(_) => {}
+            Err(PromptError::Cancelled) => println!("\n{}", PromptError::Cancelled),
+            Err(e) => {
+                println!("\n{}", e);
+                println!("Saindo...");
+                break;
+            }
+        }
+    }
+}
+
+/// Opções que não recebem valor.
+const FLAGS: &[&str] = &["ascii", "yes", "dry-run"];
+
+/// Executa um comando não interativo e retorna `Some` com o sucesso dele;
+/// opções inválidas ou argumentos ausentes contam como falha. Para um
+/// comando desconhecido retorna `None` sem exibir nada, e cabe a quem chama
+/// informar o erro.
+pub fn run_command(command: &str, raw_args: &[String]) -> Option<bool> {
+    let mut args = match Args::parse(raw_args, FLAGS) {
+        Ok(args) => args,
+        Err(e) => {
+            println!("{}", e);
+            return Some(false);
         }
+    };
+    let config = match config::load(&args) {
+        Ok(config) => config,
+        Err(e) => {
+            println!("Erro: {}", e);
+            return Some(false);
+        }
+    };
+    // O formato configurado vale quando `--format` não é informado
+    if config.format.source != config::Source::Default {
+        args.set_default("format", &config.format.value.to_string());
     }
+    let db_path = config.database.value.clone();
+
+    let success = match command {
+        "create" => create_command(&config, &args),
+        "verify" => matches!(verify_ledger(&db_path), Ok(report) if report.is_valid()),
+        "migrate" => migrate_database(&db_path).is_ok(),
+        "show" => match args.positional(0) {
+            Some(token_id) => show_nft(&db_path, token_id).is_ok(),
+            None => usage("show <token_id>"),
+        },
+        "edit" => match args.positional(0) {
+            Some(token_id) => edit_nft(&db_path, token_id, &args).is_ok(),
+            None => usage("edit <token_id> [--name N] [--description D] [--image URI] ..."),
+        },
+        "import" => match args.positional(0) {
+            Some(dir) => matches!(
+                erc721::import_metadata(&config, dir, &args),
+                Ok(report) if report.failed.is_empty()
+            ),
+            None => usage("import <diretório> --owner ID [--date AAAA-MM-DD] ..."),
+        },
+        "export" => match args.positional(0) {
+            Some(dir) => matches!(
+                erc721::export_metadata(&db_path, dir, &args),
+                Ok(report) if report.failed.is_empty()
+            ),
+            None => usage("export <diretório> [--category-attribute TRAIT]"),
+        },
+        "collection" => collections::collection_command(&db_path, &args),
+        "owner" => owners::owner_command(&db_path, &args),
+        "category" => categories::category_command(&db_path, &args),
+        "tag" => tags::tag_command(&db_path, &args),
+        "search" => search::search_command(&db_path, &args),
+        "sale" => sales::sale_command(&config, &args),
+        "share" => shares::share_command(&config, &args),
+        "valuation" => valuations::valuation_command(&db_path, &args),
+        "list" => list_command(&db_path, &args),
+        "update" => bulk::bulk_update_command(&config, &args),
+        "delete" => bulk::bulk_delete_command(&db_path, &args),
+        "portfolio" => portfolio::portfolio_command(&db_path, &args),
+        "stats" => stats::stats_command(&db_path, &args),
+        "chart" => chart::chart_command(&db_path, &args),
+        "tui" => tui::tui_command(&config),
+        "config" => config::config_command(&config, &args),
+        "db" => databases::db_command(&config, &args),
+        "copy" => databases::copy_command(&config, &args, false),
+        "move" => databases::copy_command(&config, &args, true),
+        "diff" => merge::diff_command(&config, &args),
+        "merge" => merge::merge_command(&config, &args),
+        _ => return None,
+    };
+    Some(success)
 }
 
-/// Função auxiliar para obter entrada do usuário.
-fn get_input(prompt: &str, reader: &mut impl BufRead) -> String {
-    print!("{}", prompt);
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    reader.read_line(&mut input).unwrap();
-    input.trim().to_string()
+fn usage(synopsis: &str) -> bool {
+    println!("Uso: nft_manager {}", synopsis);
+    false
 }
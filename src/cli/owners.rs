@@ -0,0 +1,194 @@
+// src/cli/owners.rs
+
+use crate::cli::args::Args;
+use crate::models::owner::Owner;
+use crate::models::wallet::WalletAddress;
+use crate::storage::file_storage::FileStorage;
+use std::str::FromStr;
+
+pub fn process_create_owner(owner: &Owner, storage: &mut FileStorage) -> Result<(), String> {
+    owner.validate()?;
+
+    let mut owners = storage.load_owners().map_err(|e| e.to_string())?;
+    if owners.iter().any(|o| o.id == owner.id) {
+        return Err(format!("Proprietário {} já existe.", owner.id));
+    }
+    owners.push(owner.clone());
+    storage.save_owners(&owners).map_err(|e| e.to_string())
+}
+
+pub fn process_update_owner(owner: &Owner, storage: &mut FileStorage) -> Result<(), String> {
+    owner.validate()?;
+
+    let mut owners = storage.load_owners().map_err(|e| e.to_string())?;
+    match owners.iter_mut().find(|o| o.id == owner.id) {
+        Some(existing) => *existing = owner.clone(),
+        None => return Err(not_found(owner.id)),
+    }
+    storage.save_owners(&owners).map_err(|e| e.to_string())
+}
+
+/// Remove o proprietário. Falha se ele ainda possuir NFTs ou cotas de NFTs.
+pub fn process_delete_owner(id: u64, storage: &mut FileStorage) -> Result<(), String> {
+    let held = storage
+        .load_all()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .filter(|n| n.share_of(id).is_some())
+        .count();
+    if held > 0 {
+        return Err(format!(
+            "Proprietário {} ainda possui {} NFT(s) ou cotas de NFT(s); transfira-os antes de deletá-lo.",
+            id, held
+        ));
+    }
+
+    let mut owners = storage.load_owners().map_err(|e| e.to_string())?;
+    let original_len = owners.len();
+    owners.retain(|o| o.id != id);
+
+    if owners.len() < original_len {
+        storage.save_owners(&owners).map_err(|e| e.to_string())
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// Ativa ou desativa a exigência de proprietários registrados nas transferências.
+pub fn process_set_owner_policy(
+    require_known_owners: bool,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let mut settings = storage.load_settings().map_err(|e| e.to_string())?;
+    settings.require_known_owners = require_known_owners;
+    storage.save_settings(&settings).map_err(|e| e.to_string())
+}
+
+fn find_owner(id: u64, storage: &mut FileStorage) -> Result<Owner, String> {
+    storage
+        .load_owners()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|o| o.id == id)
+        .ok_or_else(|| not_found(id))
+}
+
+fn not_found(id: u64) -> String {
+    format!("Proprietário {} não encontrado.", id)
+}
+
+/// Aplica as opções de proprietário. `--contact ""` remove o contato.
+/// As carteiras são validadas e gravadas na forma canônica `rede:endereço`.
+pub fn apply_owner_args(owner: &mut Owner, args: &Args) -> Result<(), String> {
+    if let Some(name) = args.value("name") {
+        owner.display_name = name.trim().to_string();
+    }
+    if let Some(contact) = args.value("contact") {
+        owner.contact = Some(contact.trim().to_string()).filter(|c| !c.is_empty());
+    }
+    for wallet in args.values("remove-wallet") {
+        let normalized = WalletAddress::from_str(wallet)
+            .map(|w| w.to_string())
+            .unwrap_or_else(|_| wallet.trim().to_string());
+        let original_len = owner.wallets.len();
+        owner.wallets.retain(|w| *w != normalized);
+        if owner.wallets.len() == original_len {
+            return Err(format!("Carteira '{}' não encontrada", wallet));
+        }
+    }
+    for wallet in args.values("wallet") {
+        let wallet = WalletAddress::from_str(wallet)?.to_string();
+        if !owner.wallets.contains(&wallet) {
+            owner.wallets.push(wallet);
+        }
+    }
+    Ok(())
+}
+
+fn print_owner(owner: &Owner) {
+    println!("Owner ID: {}", owner.id);
+    println!("Nome: {}", owner.display_name);
+    for wallet in &owner.wallets {
+        println!("Carteira: {}", wallet);
+    }
+    if let Some(contact) = &owner.contact {
+        println!("Contato: {}", contact);
+    }
+    println!("Cadastrado em: {}", owner.created_at.format("%Y-%m-%d %H:%M:%S"));
+}
+
+fn parse_id(id: &str) -> Result<u64, String> {
+    match id.parse::<u64>() {
+        Ok(id) if id > 0 => Ok(id),
+        _ => Err("Owner ID inválido. Por favor, insira um número inteiro maior que 0.".to_string()),
+    }
+}
+
+/// Executa `owner <ação> ...`. Retorna `false` em caso de erro.
+pub fn owner_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some("create"), Some(id)) => parse_id(id)
+            .and_then(|id| {
+                let mut owner = Owner::new(id, String::new());
+                apply_owner_args(&mut owner, args)?;
+                process_create_owner(&owner, &mut storage)
+            })
+            .map(|_| println!("Proprietário criado com sucesso!")),
+        (Some("update"), Some(id)) => parse_id(id)
+            .and_then(|id| find_owner(id, &mut storage))
+            .and_then(|mut owner| {
+                apply_owner_args(&mut owner, args)?;
+                process_update_owner(&owner, &mut storage)
+            })
+            .map(|_| println!("Proprietário atualizado com sucesso!")),
+        (Some("delete"), Some(id)) => parse_id(id)
+            .and_then(|id| process_delete_owner(id, &mut storage))
+            .map(|_| println!("Proprietário deletado com sucesso!")),
+        (Some("show"), Some(id)) => parse_id(id)
+            .and_then(|id| find_owner(id, &mut storage))
+            .and_then(|owner| {
+                let held = storage
+                    .load_all()
+                    .map_err(|e| e.to_string())?
+                    .iter()
+                    .filter(|n| n.owner_id == owner.id)
+                    .count();
+                println!("------------------------------");
+                print_owner(&owner);
+                println!("NFTs: {}", held);
+                println!("------------------------------");
+                Ok(())
+            }),
+        (Some("list"), _) => storage
+            .load_owners()
+            .map_err(|e| e.to_string())
+            .map(|owners| {
+                if owners.is_empty() {
+                    println!("Nenhum proprietário encontrado.");
+                }
+                for owner in &owners {
+                    println!("------------------------------");
+                    print_owner(owner);
+                }
+            }),
+        (Some("policy"), Some(policy)) => match policy {
+            "strict" => process_set_owner_policy(true, &mut storage).map(|_| {
+                println!("Transferências restritas a proprietários registrados.")
+            }),
+            "open" => process_set_owner_policy(false, &mut storage)
+                .map(|_| println!("Transferências liberadas para qualquer Owner ID.")),
+            _ => Err("Uso: nft_manager owner policy <strict|open>".to_string()),
+        },
+        _ => Err("Uso: nft_manager owner <create|update|delete|show|list|policy> ...".to_string()),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,214 @@
+// src/cli/portfolio.rs
+
+use crate::cli::args::{Args, OutputFormat};
+use crate::models::nft::NFT;
+use crate::models::owner::Owner;
+use crate::storage::file_storage::FileStorage;
+use crate::storage::ledger::LedgerEntry;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// NFT na carteira de um proprietário.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Holding {
+    pub token_id: String,
+    pub creation_date: NaiveDate,
+    /// Data em que o proprietário recebeu o NFT, segundo o histórico.
+    pub acquired_on: Option<NaiveDate>,
+    /// Fração do NFT na carteira: 1, ou a das cotas se for compartilhado.
+    pub fraction: Decimal,
+}
+
+/// NFTs de uma categoria na carteira.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CategoryHoldings {
+    pub category: String,
+    pub count: usize,
+    /// Soma das frações: quantos NFTs inteiros as cotas representam.
+    pub equivalent: Decimal,
+    pub oldest: NaiveDate,
+    pub newest: NaiveDate,
+    pub nfts: Vec<Holding>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Portfolio {
+    pub owner_id: u64,
+    pub owner_name: Option<String>,
+    pub total: usize,
+    pub equivalent: Decimal,
+    pub categories: Vec<CategoryHoldings>,
+}
+
+/// Monta a carteira do proprietário agrupada por categoria, incluindo os
+/// NFTs compartilhados em que ele tem cotas. As datas de aquisição vêm do
+/// histórico de mutações, quando disponível.
+pub fn compute_portfolio(
+    owner_id: u64,
+    nfts: &[NFT],
+    owners: &[Owner],
+    history: &[LedgerEntry],
+) -> Portfolio {
+    let acquisitions = acquisition_dates(owner_id, history);
+    let mut groups: BTreeMap<String, Vec<(&NFT, Decimal)>> = BTreeMap::new();
+    for nft in nfts {
+        if let Some(fraction) = nft.share_of(owner_id) {
+            groups
+                .entry(nft.category.to_string())
+                .or_default()
+                .push((nft, fraction));
+        }
+    }
+
+    let categories: Vec<CategoryHoldings> = groups
+        .into_iter()
+        .map(|(category, mut group)| {
+            group.sort_by(|(a, _), (b, _)| {
+                (a.creation_date, &a.token_id).cmp(&(b.creation_date, &b.token_id))
+            });
+            CategoryHoldings {
+                category,
+                count: group.len(),
+                equivalent: group.iter().map(|(_, fraction)| fraction).sum(),
+                oldest: group[0].0.creation_date,
+                newest: group[group.len() - 1].0.creation_date,
+                nfts: group
+                    .iter()
+                    .map(|(nft, fraction)| Holding {
+                        token_id: nft.token_id.clone(),
+                        creation_date: nft.creation_date,
+                        acquired_on: acquisitions.get(&nft.token_id).copied(),
+                        fraction: *fraction,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Portfolio {
+        owner_id,
+        owner_name: owners
+            .iter()
+            .find(|o| o.id == owner_id)
+            .map(|o| o.display_name.clone()),
+        total: categories.iter().map(|c| c.count).sum(),
+        equivalent: categories.iter().map(|c| c.equivalent).sum(),
+        categories,
+    }
+}
+
+/// Data da última vez em que o proprietário passou a ter cada token (ou
+/// cotas dele).
+fn acquisition_dates(owner_id: u64, history: &[LedgerEntry]) -> BTreeMap<String, NaiveDate> {
+    let mut held: BTreeMap<&str, bool> = BTreeMap::new();
+    let mut acquired: BTreeMap<String, NaiveDate> = BTreeMap::new();
+
+    for entry in history {
+        match &entry.nft {
+            Some(nft) => {
+                let holds = nft.share_of(owner_id).is_some();
+                let previous = held.insert(&entry.token_id, holds);
+                if holds && previous != Some(true) {
+                    acquired.insert(entry.token_id.clone(), entry.timestamp.date());
+                }
+            }
+            None => {
+                held.remove(entry.token_id.as_str());
+                acquired.remove(&entry.token_id);
+            }
+        }
+    }
+    acquired
+}
+
+/// Resolve o proprietário pelo Owner ID ou pelo nome registrado.
+pub fn resolve_owner(input: &str, owners: &[Owner]) -> Result<u64, String> {
+    if let Ok(id) = input.trim().parse::<u64>() {
+        return Ok(id);
+    }
+    owners
+        .iter()
+        .find(|o| o.display_name.eq_ignore_ascii_case(input.trim()))
+        .map(|o| o.id)
+        .ok_or_else(|| format!("Proprietário '{}' não encontrado.", input))
+}
+
+pub fn process_portfolio(owner: &str, storage: &mut FileStorage) -> Result<Portfolio, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let owners = storage.load_owners().map_err(|e| e.to_string())?;
+    let history = storage.ledger().load_all().map_err(|e| e.to_string())?;
+    let owner_id = resolve_owner(owner, &owners)?;
+    Ok(compute_portfolio(owner_id, &nfts, &owners, &history))
+}
+
+fn print_portfolio(portfolio: &Portfolio) {
+    match &portfolio.owner_name {
+        Some(name) => println!("Carteira de {} (Owner ID {})", name, portfolio.owner_id),
+        None => println!("Carteira do Owner ID {}", portfolio.owner_id),
+    }
+    if portfolio.equivalent == Decimal::from(portfolio.total) {
+        println!("Total de NFTs: {}", portfolio.total);
+    } else {
+        println!(
+            "Total de NFTs: {} ({} em cotas equivalentes)",
+            portfolio.total,
+            portfolio.equivalent.normalize()
+        );
+    }
+
+    for group in &portfolio.categories {
+        println!("------------------------------");
+        println!(
+            "{} ({}) — criados entre {} e {}",
+            group.category, group.count, group.oldest, group.newest
+        );
+        for holding in &group.nfts {
+            let acquired = holding
+                .acquired_on
+                .map(|date| date.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let share = if holding.fraction == Decimal::ONE {
+                String::new()
+            } else {
+                format!(
+                    "  cota de {}%",
+                    (holding.fraction * Decimal::from(100)).normalize()
+                )
+            };
+            println!(
+                "  {:<24} criado em {}  adquirido em {}{}",
+                holding.token_id, holding.creation_date, acquired, share
+            );
+        }
+    }
+    println!("------------------------------");
+}
+
+/// Executa `portfolio <owner> [--format table|json]`.
+pub fn portfolio_command(db_path: &str, args: &Args) -> bool {
+    let result = match args.positional(0) {
+        Some(owner) => OutputFormat::from_args(args).and_then(|format| {
+            let mut storage = FileStorage::new(db_path);
+            let portfolio = process_portfolio(owner, &mut storage)?;
+            match format {
+                OutputFormat::Table => print_portfolio(&portfolio),
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&portfolio).map_err(|e| e.to_string())?
+                ),
+            }
+            Ok(())
+        }),
+        None => Err("Uso: nft_manager portfolio <owner> [--format table|json]".to_string()),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
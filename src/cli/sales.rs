@@ -0,0 +1,239 @@
+// src/cli/sales.rs
+
+use crate::cli::args::Args;
+use crate::cli::commands::check_transfer_owner;
+use crate::cli::config::{Config, ValidationRules};
+use crate::cli::portfolio::resolve_owner;
+use crate::models::money::{accumulate, Money};
+use crate::models::owner::Owner;
+use crate::models::sale::Sale;
+use crate::storage::file_storage::FileStorage;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+/// Total de royalties devidos a um beneficiário em uma moeda.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoyaltyTotal {
+    pub recipient_id: u64,
+    pub total: Money,
+    pub sales: usize,
+}
+
+/// Registra a venda e transfere o NFT ao comprador, com o royalty do NFT
+/// ou da sua coleção. A data padrão é a de hoje.
+pub fn process_record_sale(
+    token_id: &str,
+    buyer_id: u64,
+    price: Money,
+    date: Option<NaiveDate>,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<Sale, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let collections = storage.load_collections().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter_mut()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+
+    let sale = Sale {
+        token_id: token_id.to_string(),
+        seller_id: nft.owner_id,
+        buyer_id,
+        price,
+        date: date.unwrap_or_else(|| chrono::Local::now().date_naive()),
+        royalty: nft.effective_royalty(&collections),
+    };
+    sale.validate()?;
+    if sale.date < nft.creation_date {
+        return Err("Data da venda é anterior à criação do NFT".to_string());
+    }
+    let mut sales = storage.load_sales().map_err(|e| e.to_string())?;
+    if let Some(last) = sales
+        .iter()
+        .filter(|s| s.token_id == token_id)
+        .map(|s| s.date)
+        .max()
+        .filter(|last| sale.date < *last)
+    {
+        return Err(format!(
+            "Data da venda é anterior à última venda registrada do NFT ({})",
+            last
+        ));
+    }
+    check_transfer_owner(buyer_id, rules, storage)?;
+
+    nft.transfer_to(buyer_id)?;
+    nft.validate()?;
+    sales.push(sale.clone());
+    storage
+        .save_all_with_sales(&nfts, &sales)
+        .map_err(|e| e.to_string())?;
+    Ok(sale)
+}
+
+/// Vendas do NFT (ou de todos, com `None`), em ordem de data.
+pub fn process_list_sales(
+    token_id: Option<&str>,
+    storage: &mut FileStorage,
+) -> Result<Vec<Sale>, String> {
+    let mut sales = storage.load_sales().map_err(|e| e.to_string())?;
+    sales.retain(|sale| token_id.is_none_or(|id| sale.token_id == id));
+    sales.sort_by_key(|sale| sale.date);
+    Ok(sales)
+}
+
+/// Soma os royalties devidos por beneficiário e moeda nas vendas entre
+/// `from` e `to` (inclusive). Ordenado por beneficiário e moeda.
+pub fn process_royalty_report(
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    storage: &mut FileStorage,
+) -> Result<Vec<RoyaltyTotal>, String> {
+    let sales = storage.load_sales().map_err(|e| e.to_string())?;
+    // Totais por moeda e número de vendas por moeda de cada beneficiário
+    let mut by_recipient: BTreeMap<u64, (Vec<Money>, BTreeMap<String, usize>)> = BTreeMap::new();
+    for sale in sales
+        .iter()
+        .filter(|sale| from.is_none_or(|from| sale.date >= from))
+        .filter(|sale| to.is_none_or(|to| sale.date <= to))
+    {
+        let (Some(royalty), Some(due)) = (&sale.royalty, sale.royalty_due()?) else {
+            continue;
+        };
+        let (totals, counts) = by_recipient.entry(royalty.recipient_id).or_default();
+        accumulate(totals, &due)
+            .map_err(|e| format!("Royalties do Owner ID {}: {}", royalty.recipient_id, e))?;
+        *counts.entry(due.currency).or_default() += 1;
+    }
+    Ok(by_recipient
+        .into_iter()
+        .flat_map(|(recipient_id, (totals, counts))| {
+            totals.into_iter().map(move |total| RoyaltyTotal {
+                recipient_id,
+                sales: counts[&total.currency],
+                total,
+            })
+        })
+        .collect())
+}
+
+fn parse_date(args: &Args, option: &str) -> Result<Option<NaiveDate>, String> {
+    args.value(option)
+        .map(|date| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("--{}: data inválida. Formato esperado: AAAA-MM-DD.", option))
+        })
+        .transpose()
+}
+
+fn owner_label(id: u64, owners: &[Owner]) -> String {
+    match owners.iter().find(|o| o.id == id) {
+        Some(owner) => format!("{} ({})", id, owner.display_name),
+        None => id.to_string(),
+    }
+}
+
+fn print_sale(sale: &Sale, owners: &[Owner]) {
+    println!(
+        "{} | {} | {} -> {} | {}",
+        sale.date,
+        sale.token_id,
+        owner_label(sale.seller_id, owners),
+        owner_label(sale.buyer_id, owners),
+        sale.price
+    );
+    match (&sale.royalty, sale.royalty_due()) {
+        (Some(royalty), Ok(Some(due))) => println!("  Royalty: {} ({})", due, royalty),
+        (_, Err(e)) => println!("  Royalty: {}", e),
+        _ => {}
+    }
+}
+
+fn record_sale(
+    token_id: &str,
+    args: &Args,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let owners = storage.load_owners().map_err(|e| e.to_string())?;
+    let buyer_id = match args.value("buyer") {
+        Some(buyer) => resolve_owner(buyer, &owners)?,
+        None => return Err("Informe --buyer com o Owner ID ou o nome do comprador".to_string()),
+    };
+    let price = match (args.value("price"), args.value("currency")) {
+        (Some(amount), Some(currency)) => Money::parse(amount, currency)?,
+        _ => return Err("Informe --price e --currency".to_string()),
+    };
+    let sale = process_record_sale(
+        token_id,
+        buyer_id,
+        price,
+        parse_date(args, "date")?,
+        rules,
+        storage,
+    )?;
+
+    println!(
+        "Venda registrada: {} transferido de {} para {} por {}.",
+        sale.token_id,
+        owner_label(sale.seller_id, &owners),
+        owner_label(sale.buyer_id, &owners),
+        sale.price
+    );
+    match (&sale.royalty, sale.royalty_due()?) {
+        (Some(royalty), Some(due)) => println!("Royalty devido: {} ({}).", due, royalty),
+        _ => println!("Sem royalty configurado para este NFT."),
+    }
+    Ok(())
+}
+
+/// Executa `sale <record|list|royalties> ...`.
+pub fn sale_command(config: &Config, args: &Args) -> bool {
+    let mut storage = FileStorage::new(&config.database.value);
+
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some("record"), Some(token_id)) => {
+            record_sale(token_id, args, &config.validation_rules(), &mut storage)
+        }
+        (Some("list"), token_id) => process_list_sales(token_id, &mut storage).map(|sales| {
+            let owners = storage.load_owners().unwrap_or_default();
+            if sales.is_empty() {
+                println!("Nenhuma venda registrada.");
+            }
+            for sale in &sales {
+                print_sale(sale, &owners);
+            }
+        }),
+        (Some("royalties"), None) => parse_date(args, "from")
+            .and_then(|from| Ok((from, parse_date(args, "to")?)))
+            .and_then(|(from, to)| process_royalty_report(from, to, &mut storage))
+            .map(|totals| {
+                let owners = storage.load_owners().unwrap_or_default();
+                if totals.is_empty() {
+                    println!("Nenhum royalty devido no período.");
+                }
+                for total in &totals {
+                    println!(
+                        "Owner ID {}: {} em {} venda(s)",
+                        owner_label(total.recipient_id, &owners),
+                        total.total,
+                        total.sales
+                    );
+                }
+            }),
+        _ => Err(
+            "Uso: nft_manager sale <record <token_id> --buyer ID --price VALOR --currency MOEDA \
+             [--date AAAA-MM-DD]|list [token_id]|royalties [--from AAAA-MM-DD] [--to AAAA-MM-DD]>"
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// src/cli/search.rs
+
+use crate::cli::args::Args;
+use crate::storage::file_storage::FileStorage;
+use crate::storage::search_index::SearchHit;
+use std::io::{self, IsTerminal};
+
+/// Quantidade de resultados exibidos sem `--limit`.
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// Como destacar os trechos que coincidiram com a busca.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HighlightStyle {
+    /// Negrito colorido, para terminais.
+    Ansi,
+    /// Entre colchetes, para saída redirecionada.
+    Brackets,
+}
+
+/// Busca aproximada por Token ID, categoria, marcadores e metadados.
+/// Retorna no máximo `limit` resultados, do mais ao menos relevante.
+pub fn process_search(
+    query: &str,
+    limit: usize,
+    storage: &mut FileStorage,
+) -> Result<Vec<SearchHit>, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let mut hits = storage
+        .search_index()
+        .search(query, &nfts)
+        .map_err(|e| e.to_string())?;
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+/// Destaca os caracteres de `text` nas `positions` indicadas, agrupando
+/// posições consecutivas.
+pub fn highlight(text: &str, positions: &[usize], style: HighlightStyle) -> String {
+    let (open, close) = match style {
+        HighlightStyle::Ansi => ("\x1b[1;33m", "\x1b[0m"),
+        HighlightStyle::Brackets => ("[", "]"),
+    };
+    let mut output = String::new();
+    let mut inside = false;
+    for (i, c) in text.chars().enumerate() {
+        let selected = positions.contains(&i);
+        if selected != inside {
+            output.push_str(if selected { open } else { close });
+            inside = selected;
+        }
+        output.push(c);
+    }
+    if inside {
+        output.push_str(close);
+    }
+    output
+}
+
+/// Executa `search <texto> [--limit N]`.
+pub fn search_command(db_path: &str, args: &Args) -> bool {
+    let query = args.positionals().join(" ");
+    let result = match args.value("limit").map(str::parse::<usize>) {
+        _ if query.trim().is_empty() => {
+            Err("Uso: nft_manager search <texto> [--limit N]".to_string())
+        }
+        Some(Ok(limit)) if limit > 0 => Ok(limit),
+        Some(_) => Err("--limit deve ser um número inteiro maior que 0".to_string()),
+        None => Ok(DEFAULT_LIMIT),
+    }
+    .and_then(|limit| process_search(&query, limit, &mut FileStorage::new(db_path)));
+
+    let hits = match result {
+        Ok(hits) => hits,
+        Err(e) => {
+            println!("Erro: {}", e);
+            return false;
+        }
+    };
+    if hits.is_empty() {
+        println!("Nenhum NFT encontrado para '{}'.", query);
+        return true;
+    }
+
+    let style = if io::stdout().is_terminal() {
+        HighlightStyle::Ansi
+    } else {
+        HighlightStyle::Brackets
+    };
+    println!("{} resultado(s) para '{}':", hits.len(), query);
+    for (rank, hit) in hits.iter().enumerate() {
+        let text = highlight(&hit.text, &hit.positions, style);
+        if hit.text == hit.token_id {
+            println!("{:>3}. {}", rank + 1, text);
+        } else {
+            println!(
+                "{:>3}. {} ({}: {})",
+                rank + 1,
+                hit.token_id,
+                hit.label,
+                text
+            );
+        }
+    }
+    true
+}
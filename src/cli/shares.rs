@@ -0,0 +1,163 @@
+// src/cli/shares.rs
+
+use crate::cli::args::Args;
+use crate::cli::commands::check_transfer_owner;
+use crate::cli::config::{Config, ValidationRules};
+use crate::cli::portfolio::resolve_owner;
+use crate::models::nft::NFT;
+use crate::models::shares::Shares;
+use crate::storage::file_storage::FileStorage;
+
+fn update_shares<F>(token_id: &str, storage: &mut FileStorage, change: F) -> Result<NFT, String>
+where
+    F: FnOnce(&mut NFT) -> Result<(), String>,
+{
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter_mut()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+    change(nft)?;
+    nft.validate()?;
+    let updated = nft.clone();
+    storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    Ok(updated)
+}
+
+/// Divide o NFT em `total` cotas, todas do proprietário atual.
+pub fn process_split_shares(
+    token_id: &str,
+    total: u64,
+    storage: &mut FileStorage,
+) -> Result<NFT, String> {
+    update_shares(token_id, storage, |nft| {
+        if nft.shares.is_some() {
+            return Err(format!("NFT '{}' já está dividido em cotas.", token_id));
+        }
+        nft.shares = Some(Shares::whole(nft.owner_id, total));
+        Ok(())
+    })
+}
+
+/// Transfere parte das cotas. O Owner ID do NFT passa a ser o do maior
+/// cotista, para que filtros e relatórios por proprietário continuem valendo.
+pub fn process_transfer_shares(
+    token_id: &str,
+    from: u64,
+    to: u64,
+    units: u64,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<NFT, String> {
+    check_transfer_owner(to, rules, storage)?;
+    update_shares(token_id, storage, |nft| {
+        let shares = nft
+            .shares
+            .as_mut()
+            .ok_or_else(|| format!("NFT '{}' não está dividido em cotas.", token_id))?;
+        shares.transfer(from, to, units)?;
+        if let Some(owner_id) = shares.majority_owner().filter(|id| *id != nft.owner_id) {
+            // O endereço da carteira era do proprietário principal anterior
+            nft.owner_id = owner_id;
+            nft.owner_address = None;
+        }
+        Ok(())
+    })
+}
+
+/// Volta a propriedade única quando um só proprietário detém todas as cotas.
+pub fn process_join_shares(token_id: &str, storage: &mut FileStorage) -> Result<NFT, String> {
+    update_shares(token_id, storage, |nft| {
+        let shares = nft
+            .shares
+            .as_ref()
+            .ok_or_else(|| format!("NFT '{}' não está dividido em cotas.", token_id))?;
+        match shares.holders.as_slice() {
+            [single] => {
+                nft.owner_id = single.owner_id;
+                nft.shares = None;
+                Ok(())
+            }
+            _ => Err(format!(
+                "NFT '{}' tem {} cotistas; só é possível unir as cotas de um único proprietário.",
+                token_id,
+                shares.holders.len()
+            )),
+        }
+    })
+}
+
+fn parse_number(args: &Args, option: &str) -> Result<u64, String> {
+    let value = args
+        .value(option)
+        .ok_or_else(|| format!("Informe --{}", option))?;
+    value
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| format!("--{}: número inválido '{}'", option, value))
+}
+
+fn find_nft(token_id: &str, storage: &mut FileStorage) -> Result<NFT, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    nfts.into_iter()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))
+}
+
+fn transfer_shares(
+    token_id: &str,
+    args: &Args,
+    rules: &ValidationRules,
+    storage: &mut FileStorage,
+) -> Result<NFT, String> {
+    let owners = storage.load_owners().map_err(|e| e.to_string())?;
+    let owner = |option: &str| match args.value(option) {
+        Some(input) => resolve_owner(input, &owners),
+        None => Err(format!("Informe --{} com o Owner ID ou o nome", option)),
+    };
+    let (from, to) = (owner("from")?, owner("to")?);
+    let units = parse_number(args, "units")?;
+    let nft = process_transfer_shares(token_id, from, to, units, rules, storage)?;
+    println!(
+        "{} cota(s) de {} transferida(s) de {} para {}.",
+        units, token_id, from, to
+    );
+    Ok(nft)
+}
+
+/// Executa `share <split|transfer|join|show> <token_id> ...`.
+pub fn share_command(config: &Config, args: &Args) -> bool {
+    let mut storage = FileStorage::new(&config.database.value);
+
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some("split"), Some(token_id)) => parse_number(args, "total")
+            .and_then(|total| process_split_shares(token_id, total, &mut storage)),
+        (Some("transfer"), Some(token_id)) => {
+            transfer_shares(token_id, args, &config.validation_rules(), &mut storage)
+        }
+        (Some("join"), Some(token_id)) => process_join_shares(token_id, &mut storage),
+        (Some("show"), Some(token_id)) => find_nft(token_id, &mut storage),
+        _ => Err(
+            "Uso: nft_manager share <split <token_id> --total N|transfer <token_id> \
+                  --from ID --to ID --units N|join <token_id>|show <token_id>>"
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(nft) => {
+            match &nft.shares {
+                Some(shares) => println!("Cotas de {}: {}", nft.token_id, shares),
+                None => println!(
+                    "{} pertence integralmente ao Owner ID {}.",
+                    nft.token_id, nft.owner_id
+                ),
+            }
+            true
+        }
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
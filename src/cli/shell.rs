@@ -0,0 +1,190 @@
+// src/cli/shell.rs
+
+use crate::models::category::{Category, CategoryDefinition};
+use crate::storage::file_storage::FileStorage;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::FileHistory;
+use rustyline::validate::Validator;
+use rustyline::{Config, Context, Editor, Helper};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// Quantidade de linhas guardadas no histórico.
+const HISTORY_SIZE: usize = 1000;
+
+/// Resposta que interrompe a operação em andamento.
+pub const CANCEL_COMMAND: &str = "cancel";
+
+/// Motivo pelo qual um prompt não produziu uma resposta.
+#[derive(Debug)]
+pub enum PromptError {
+    /// O usuário digitou `cancel`.
+    Cancelled,
+    /// A entrada terminou (EOF ou Ctrl-D).
+    EndOfInput,
+    Io(io::Error),
+}
+
+impl fmt::Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PromptError::Cancelled => write!(f, "Operação cancelada."),
+            PromptError::EndOfInput => write!(f, "Fim da entrada; operação cancelada."),
+            PromptError::Io(e) => write!(f, "Erro ao ler entrada: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PromptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PromptError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Origem das respostas dos prompts interativos.
+pub trait LineSource {
+    /// Exibe o prompt e lê uma linha, sem o terminador.
+    /// Retorna `None` no fim da entrada.
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>>;
+}
+
+/// Leitura simples, usada quando a entrada não é um terminal.
+impl<R: BufRead> LineSource for R {
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        print!("{}", prompt);
+        io::stdout().flush()?;
+        let mut input = String::new();
+        match BufRead::read_line(self, &mut input)? {
+            0 => Ok(None),
+            _ => Ok(Some(input.trim_end_matches(['\r', '\n']).to_string())),
+        }
+    }
+}
+
+/// Lê uma resposta sem espaços nas pontas. O fim da entrada e a palavra
+/// `cancel` interrompem a operação.
+pub fn prompt(reader: &mut impl LineSource, prompt: &str) -> Result<String, PromptError> {
+    match reader.read_line(prompt) {
+        Ok(Some(input)) if input.trim().eq_ignore_ascii_case(CANCEL_COMMAND) => {
+            Err(PromptError::Cancelled)
+        }
+        Ok(Some(input)) => Ok(input.trim().to_string()),
+        Ok(None) => Err(PromptError::EndOfInput),
+        Err(e) => Err(PromptError::Io(e)),
+    }
+}
+
+/// Sugestões para o que já foi digitado: Token IDs existentes e
+/// categorias predefinidas ou definidas no banco que começam com o texto (sem diferenciar maiúsculas).
+pub fn completions(
+    input: &str,
+    token_ids: &[String],
+    definitions: &[CategoryDefinition],
+) -> Vec<String> {
+    let prefix = input.to_lowercase();
+    let mut candidates: Vec<String> = token_ids
+        .iter()
+        .cloned()
+        .chain(Category::NAMED.iter().map(|c| c.to_string()))
+        .chain(definitions.iter().map(|d| d.name.clone()))
+        .filter(|candidate| candidate.to_lowercase().starts_with(&prefix))
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+/// Completa a linha inteira, já que as respostas podem conter espaços.
+struct ShellHelper {
+    db_path: String,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        // Relê o banco a cada pedido para incluir NFTs e categorias criados na sessão
+        let mut storage = FileStorage::new(&self.db_path);
+        let token_ids: Vec<String> = storage
+            .load_all()
+            .map(|nfts| nfts.into_iter().map(|n| n.token_id).collect())
+            .unwrap_or_default();
+        let definitions = storage.load_categories().unwrap_or_default();
+        Ok((0, completions(&line[..pos], &token_ids, &definitions)))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+/// Terminal interativo com edição de linha, histórico persistente e
+/// completação por Tab.
+pub struct Shell {
+    editor: Editor<ShellHelper, FileHistory>,
+    history_path: PathBuf,
+}
+
+impl Shell {
+    /// O histórico fica ao lado do banco de dados, em `<db>.history`.
+    pub fn new(db_path: &str) -> Result<Shell, String> {
+        let config = Config::builder()
+            .auto_add_history(true)
+            .max_history_size(HISTORY_SIZE)
+            .map_err(|e| e.to_string())?
+            .build();
+        let mut editor = Editor::with_config(config).map_err(|e| e.to_string())?;
+        editor.set_helper(Some(ShellHelper {
+            db_path: db_path.to_string(),
+        }));
+
+        let history_path = PathBuf::from(format!("{}.history", db_path));
+        // Na primeira execução o arquivo ainda não existe
+        let _ = editor.load_history(&history_path);
+
+        Ok(Shell {
+            editor,
+            history_path,
+        })
+    }
+}
+
+impl LineSource for Shell {
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        match self.editor.readline(prompt) {
+            Ok(line) => Ok(Some(line)),
+            // Ctrl-C descarta a linha digitada
+            Err(ReadlineError::Interrupted) => Ok(Some(String::new())),
+            // Ctrl-D encerra a entrada
+            Err(ReadlineError::Eof) => Ok(None),
+            Err(ReadlineError::Io(e)) => Err(e),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+}
+
+impl Drop for Shell {
+    fn drop(&mut self) {
+        if let Err(e) = self.editor.save_history(&self.history_path) {
+            println!("Erro ao salvar histórico: {}", e);
+        }
+    }
+}
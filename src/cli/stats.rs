@@ -0,0 +1,176 @@
+// src/cli/stats.rs
+
+use crate::cli::args::{Args, OutputFormat};
+use crate::models::nft::NFT;
+use crate::models::owner::Owner;
+use crate::storage::file_storage::FileStorage;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Quantidade de maiores proprietários exibidos por padrão.
+pub const DEFAULT_TOP_HOLDERS: usize = 10;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CategoryCount {
+    pub category: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MonthCount {
+    pub month: String, // AAAA-MM
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HolderCount {
+    pub owner_id: u64,
+    pub owner_name: Option<String>,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Stats {
+    pub total: usize,
+    pub unique_owners: usize,
+    /// Totais por categoria; cada descrição de `Other` é contada separadamente.
+    pub categories: Vec<CategoryCount>,
+    /// NFTs criados por mês, em ordem cronológica.
+    pub minted_per_month: Vec<MonthCount>,
+    pub top_holders: Vec<HolderCount>,
+    /// Coeficiente de Gini da distribuição de NFTs entre proprietários (0 = igualitária).
+    pub gini: f64,
+}
+
+pub fn compute_stats(nfts: &[NFT], owners: &[Owner], top: usize) -> Stats {
+    let mut categories: BTreeMap<String, usize> = BTreeMap::new();
+    let mut months: BTreeMap<String, usize> = BTreeMap::new();
+    let mut holdings: BTreeMap<u64, usize> = BTreeMap::new();
+    for nft in nfts {
+        *categories.entry(nft.category.to_string()).or_default() += 1;
+        *months
+            .entry(nft.creation_date.format("%Y-%m").to_string())
+            .or_default() += 1;
+        *holdings.entry(nft.owner_id).or_default() += 1;
+    }
+
+    let mut categories: Vec<CategoryCount> = categories
+        .into_iter()
+        .map(|(category, count)| CategoryCount { category, count })
+        .collect();
+    categories.sort_by(|a, b| b.count.cmp(&a.count).then(a.category.cmp(&b.category)));
+
+    let mut top_holders: Vec<HolderCount> = holdings
+        .iter()
+        .map(|(&owner_id, &count)| HolderCount {
+            owner_id,
+            owner_name: owners
+                .iter()
+                .find(|o| o.id == owner_id)
+                .map(|o| o.display_name.clone()),
+            count,
+        })
+        .collect();
+    top_holders.sort_by(|a, b| b.count.cmp(&a.count).then(a.owner_id.cmp(&b.owner_id)));
+    top_holders.truncate(top);
+
+    Stats {
+        total: nfts.len(),
+        unique_owners: holdings.len(),
+        categories,
+        minted_per_month: months
+            .into_iter()
+            .map(|(month, count)| MonthCount { month, count })
+            .collect(),
+        top_holders,
+        gini: gini(&holdings.values().copied().collect::<Vec<_>>()),
+    }
+}
+
+/// Coeficiente de Gini das quantidades informadas.
+pub fn gini(counts: &[usize]) -> f64 {
+    let total: usize = counts.iter().sum();
+    if counts.is_empty() || total == 0 {
+        return 0.0;
+    }
+
+    let mut sorted = counts.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len() as f64;
+    let weighted: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| (i as f64 + 1.0) * count as f64)
+        .sum();
+    (2.0 * weighted) / (n * total as f64) - (n + 1.0) / n
+}
+
+pub fn process_stats(storage: &mut FileStorage, top: usize) -> Result<Stats, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let owners = storage.load_owners().map_err(|e| e.to_string())?;
+    Ok(compute_stats(&nfts, &owners, top))
+}
+
+fn print_stats(stats: &Stats) {
+    println!("Total de NFTs: {}", stats.total);
+    println!("Proprietários distintos: {}", stats.unique_owners);
+
+    println!("------------------------------");
+    println!("Por categoria:");
+    for entry in &stats.categories {
+        println!("  {:<30} {:>6}", entry.category, entry.count);
+    }
+
+    println!("------------------------------");
+    println!("Criados por mês:");
+    for entry in &stats.minted_per_month {
+        println!("  {:<30} {:>6}", entry.month, entry.count);
+    }
+
+    println!("------------------------------");
+    println!("Maiores proprietários:");
+    for holder in &stats.top_holders {
+        let label = match &holder.owner_name {
+            Some(name) => format!("{} ({})", holder.owner_id, name),
+            None => holder.owner_id.to_string(),
+        };
+        println!("  {:<30} {:>6}", label, holder.count);
+    }
+    println!("Coeficiente de Gini: {:.3}", stats.gini);
+    println!("------------------------------");
+}
+
+/// Lê `--top N` (padrão: [`DEFAULT_TOP_HOLDERS`]).
+pub(crate) fn top_from_args(args: &Args) -> Result<usize, String> {
+    match args.value("top") {
+        Some(top) => top
+            .parse::<usize>()
+            .map_err(|_| "--top deve ser um número inteiro".to_string()),
+        None => Ok(DEFAULT_TOP_HOLDERS),
+    }
+}
+
+/// Executa `stats [--top N] [--format table|json]`.
+pub fn stats_command(db_path: &str, args: &Args) -> bool {
+    let result = OutputFormat::from_args(args).and_then(|format| {
+        let top = top_from_args(args)?;
+        let mut storage = FileStorage::new(db_path);
+        let stats = process_stats(&mut storage, top)?;
+        match format {
+            OutputFormat::Table => print_stats(&stats),
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&stats).map_err(|e| e.to_string())?
+            ),
+        }
+        Ok(())
+    });
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// src/cli/tags.rs
+
+use crate::cli::args::Args;
+use crate::models::category::category_key;
+use crate::storage::file_storage::FileStorage;
+
+/// Acrescenta os marcadores ao NFT e retorna os que ainda não existiam.
+pub fn process_add_tags(
+    token_id: &str,
+    tags: &[&str],
+    storage: &mut FileStorage,
+) -> Result<Vec<String>, String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter_mut()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+
+    let mut added = Vec::new();
+    for tag in tags {
+        if nft.add_tag(tag) {
+            added.push(tag.trim().to_string());
+        }
+    }
+    nft.validate()?;
+
+    if !added.is_empty() {
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    }
+    Ok(added)
+}
+
+/// Remove os marcadores do NFT. Falha se algum deles não existir.
+pub fn process_remove_tags(
+    token_id: &str,
+    tags: &[&str],
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter_mut()
+        .find(|n| n.token_id == token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+
+    for tag in tags {
+        if !nft.remove_tag(tag) {
+            return Err(format!("Marcador '{}' não encontrado", tag));
+        }
+    }
+    storage.save_all(&nfts).map_err(|e| e.to_string())
+}
+
+/// Renomeia o marcador em todos os NFTs e retorna quantos foram alterados.
+/// NFTs que já têm o novo marcador apenas perdem o antigo.
+pub fn process_rename_tag(
+    from: &str,
+    to: &str,
+    storage: &mut FileStorage,
+) -> Result<usize, String> {
+    if to.trim().is_empty() {
+        return Err("Marcador não pode ser vazio".to_string());
+    }
+    let mut nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let mut updated = 0;
+    for nft in nfts.iter_mut().filter(|nft| nft.has_tag(from)) {
+        // Mantém a posição do marcador, inclusive em mudanças só de maiúsculas
+        let index = nft
+            .tags
+            .iter()
+            .position(|t| category_key(t) == category_key(from))
+            .expect("marcador presente");
+        nft.tags.remove(index);
+        if !nft.has_tag(to) {
+            nft.tags.insert(index, to.trim().to_string());
+        }
+        updated += 1;
+    }
+
+    if updated > 0 {
+        storage.save_all(&nfts).map_err(|e| e.to_string())?;
+    }
+    Ok(updated)
+}
+
+/// Marcadores em uso e a quantidade de NFTs de cada um, em ordem alfabética.
+/// Variações de maiúsculas são contadas juntas, com a grafia encontrada primeiro.
+pub fn process_list_tags(storage: &mut FileStorage) -> Result<Vec<(String, usize)>, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let mut tags: Vec<(String, usize)> = Vec::new();
+    for tag in nfts.iter().flat_map(|nft| &nft.tags) {
+        match tags
+            .iter_mut()
+            .find(|(t, _)| category_key(t) == category_key(tag))
+        {
+            Some((_, count)) => *count += 1,
+            None => tags.push((tag.clone(), 1)),
+        }
+    }
+    tags.sort_by_key(|(tag, _)| category_key(tag));
+    Ok(tags)
+}
+
+/// Executa `tag <add|remove|rename|list> ...`.
+pub fn tag_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+    let positionals: Vec<&str> = args.positionals().iter().map(String::as_str).collect();
+
+    let result = match positionals.as_slice() {
+        ["add", token_id, tags @ ..] if !tags.is_empty() => {
+            process_add_tags(token_id, tags, &mut storage)
+                .map(|added| println!("{} marcador(es) adicionado(s) a {}.", added.len(), token_id))
+        }
+        ["remove", token_id, tags @ ..] if !tags.is_empty() => {
+            process_remove_tags(token_id, tags, &mut storage)
+                .map(|_| println!("Marcador(es) removido(s) de {}.", token_id))
+        }
+        ["rename", from, to] => process_rename_tag(from, to, &mut storage)
+            .map(|updated| println!("Marcador renomeado em {} NFT(s).", updated)),
+        ["list"] => process_list_tags(&mut storage).map(|tags| {
+            if tags.is_empty() {
+                println!("Nenhum marcador encontrado.");
+            }
+            for (tag, count) in &tags {
+                println!("{:<24} {} NFT(s)", tag, count);
+            }
+        }),
+        _ => Err(
+            "Uso: nft_manager tag <add <token_id> <marcador>...|remove <token_id> \
+                  <marcador>...|rename <antigo> <novo>|list>"
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
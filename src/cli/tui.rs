@@ -0,0 +1,654 @@
+// src/cli/tui.rs
+
+use crate::cli::commands::{
+    collect_nft_data, process_create_nft, process_delete_nft, process_token_id,
+    process_update_metadata, process_update_nft,
+};
+use crate::cli::config::{Config, ValidationRules};
+use crate::models::category::CategoryPath;
+use crate::models::nft::NFT;
+use crate::models::owner::Owner;
+use crate::models::token_id::TokenIdStrategy;
+use crate::storage::file_storage::FileStorage;
+use chrono::NaiveDate;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::Frame;
+use std::io;
+
+/// Linhas avançadas por PageUp/PageDown.
+const PAGE_SIZE: usize = 10;
+
+/// Coluna usada na ordenação da tabela.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortColumn {
+    TokenId,
+    Owner,
+    Date,
+    Category,
+}
+
+impl SortColumn {
+    fn next(self) -> SortColumn {
+        match self {
+            SortColumn::TokenId => SortColumn::Owner,
+            SortColumn::Owner => SortColumn::Date,
+            SortColumn::Date => SortColumn::Category,
+            SortColumn::Category => SortColumn::TokenId,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortColumn::TokenId => "Token ID",
+            SortColumn::Owner => "Owner ID",
+            SortColumn::Date => "Data",
+            SortColumn::Category => "Categoria",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FormKind {
+    Create,
+    Edit,
+    Transfer,
+}
+
+/// Formulário aberto sobre a tabela.
+#[derive(Debug, Clone, PartialEq)]
+struct Form {
+    kind: FormKind,
+    /// Token ID do NFT editado ou transferido.
+    token_id: Option<String>,
+    fields: Vec<(&'static str, String)>,
+    focus: usize,
+}
+
+impl Form {
+    fn title(&self) -> String {
+        match (self.kind, &self.token_id) {
+            (FormKind::Create, _) => "Criar NFT".to_string(),
+            (FormKind::Edit, Some(token_id)) => format!("Editar {}", token_id),
+            (FormKind::Transfer, Some(token_id)) => format!("Transferir {}", token_id),
+            (_, None) => String::new(),
+        }
+    }
+
+    fn value(&self, index: usize) -> &str {
+        self.fields[index].1.trim()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    Browse,
+    Filter,
+    Form(Form),
+    ConfirmDelete(String),
+}
+
+/// Estado da interface em tela cheia.
+pub struct App {
+    storage: FileStorage,
+    rules: ValidationRules,
+    token_id_strategy: TokenIdStrategy,
+    nfts: Vec<NFT>,
+    owners: Vec<Owner>,
+    selected: usize,
+    sort: SortColumn,
+    descending: bool,
+    filter: String,
+    mode: Mode,
+    status: Option<String>,
+    quit: bool,
+}
+
+impl App {
+    pub fn new(db_path: &str) -> Result<App, String> {
+        let mut app = App {
+            storage: FileStorage::new(db_path),
+            rules: ValidationRules::default(),
+            token_id_strategy: TokenIdStrategy::default(),
+            nfts: Vec::new(),
+            owners: Vec::new(),
+            selected: 0,
+            sort: SortColumn::TokenId,
+            descending: false,
+            filter: String::new(),
+            mode: Mode::Browse,
+            status: None,
+            quit: false,
+        };
+        app.reload()?;
+        Ok(app)
+    }
+
+    /// Usa as regras de validação e a estratégia de Token ID da configuração.
+    pub fn with_config(mut self, config: &Config) -> App {
+        self.rules = config.validation_rules();
+        self.token_id_strategy = config.token_id_strategy.value.clone();
+        self
+    }
+
+    fn reload(&mut self) -> Result<(), String> {
+        self.nfts = self.storage.load_all().map_err(|e| e.to_string())?;
+        self.owners = self.storage.load_owners().map_err(|e| e.to_string())?;
+        self.clamp_selection();
+        Ok(())
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    pub fn sort(&self) -> (SortColumn, bool) {
+        (self.sort, self.descending)
+    }
+
+    /// NFTs visíveis, já filtrados e ordenados.
+    pub fn visible(&self) -> Vec<&NFT> {
+        let filter = self.filter.to_lowercase();
+        let mut visible: Vec<&NFT> = self
+            .nfts
+            .iter()
+            .filter(|nft| filter.is_empty() || self.matches(nft, &filter))
+            .collect();
+
+        visible.sort_by(|a, b| {
+            let ordering = match self.sort {
+                SortColumn::TokenId => a.token_id.cmp(&b.token_id),
+                SortColumn::Owner => a.owner_id.cmp(&b.owner_id),
+                SortColumn::Date => a.creation_date.cmp(&b.creation_date),
+                SortColumn::Category => a
+                    .category_path()
+                    .to_string()
+                    .cmp(&b.category_path().to_string()),
+            }
+            .then_with(|| a.token_id.cmp(&b.token_id));
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        visible
+    }
+
+    fn matches(&self, nft: &NFT, filter: &str) -> bool {
+        let owner_name = self.owner_name(nft.owner_id).unwrap_or_default();
+        [
+            nft.token_id.as_str(),
+            &nft.owner_id.to_string(),
+            owner_name,
+            &nft.category_path().to_string(),
+            nft.metadata.name.as_deref().unwrap_or_default(),
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(filter))
+            || nft.tags.iter().any(|tag| tag.to_lowercase().contains(filter))
+    }
+
+    fn owner_name(&self, owner_id: u64) -> Option<&str> {
+        self.owners
+            .iter()
+            .find(|o| o.id == owner_id)
+            .map(|o| o.display_name.as_str())
+    }
+
+    /// NFT sob o cursor.
+    pub fn selected(&self) -> Option<&NFT> {
+        self.visible().get(self.selected).copied()
+    }
+
+    fn select_token(&mut self, token_id: &str) {
+        if let Some(index) = self.visible().iter().position(|n| n.token_id == token_id) {
+            self.selected = index;
+        }
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.visible().len();
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.visible().len();
+        if len > 0 {
+            self.selected = self.selected.saturating_add_signed(delta).min(len - 1);
+        }
+    }
+
+    /// Processa uma tecla conforme o modo atual.
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        match std::mem::replace(&mut self.mode, Mode::Browse) {
+            Mode::Browse => self.handle_browse_key(key),
+            Mode::Filter => self.handle_filter_key(key),
+            Mode::Form(form) => self.handle_form_key(form, key),
+            Mode::ConfirmDelete(token_id) => {
+                if matches!(key.code, KeyCode::Char('s') | KeyCode::Char('y')) {
+                    let result = process_delete_nft(&token_id, &mut self.storage)
+                        .and_then(|_| self.reload());
+                    self.status = Some(match result {
+                        Ok(_) => "NFT deletado com sucesso!".to_string(),
+                        Err(e) => format!("Erro ao deletar NFT: {}", e),
+                    });
+                } else {
+                    self.status = Some("Exclusão cancelada.".to_string());
+                }
+            }
+        }
+    }
+
+    fn handle_browse_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(PAGE_SIZE as isize),
+            KeyCode::PageUp => self.move_selection(-(PAGE_SIZE as isize)),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.move_selection(isize::MAX),
+            KeyCode::Char('s') => {
+                self.sort = self.sort.next();
+                self.selected = 0;
+            }
+            KeyCode::Char('r') => {
+                self.descending = !self.descending;
+                self.selected = 0;
+            }
+            KeyCode::Char('/') => self.mode = Mode::Filter,
+            KeyCode::Char('n') => {
+                let today = chrono::Local::now().date_naive().to_string();
+                self.open_form(
+                    FormKind::Create,
+                    None,
+                    vec![
+                        ("Token ID (vazio para gerar)", String::new()),
+                        ("Owner ID", String::new()),
+                        ("Data de Criação (AAAA-MM-DD)", today),
+                        ("Categoria", String::new()),
+                    ],
+                );
+            }
+            KeyCode::Char('e') => {
+                if let Some(nft) = self.selected().cloned() {
+                    let metadata = nft.metadata;
+                    self.open_form(
+                        FormKind::Edit,
+                        Some(nft.token_id),
+                        vec![
+                            ("Nome", metadata.name.unwrap_or_default()),
+                            ("Descrição", metadata.description.unwrap_or_default()),
+                            ("Imagem", metadata.image.unwrap_or_default()),
+                            ("URL Externa", metadata.external_url.unwrap_or_default()),
+                        ],
+                    );
+                }
+            }
+            KeyCode::Char('t') => {
+                if let Some(nft) = self.selected().cloned() {
+                    self.open_form(
+                        FormKind::Transfer,
+                        Some(nft.token_id),
+                        vec![("Novo Owner ID", String::new())],
+                    );
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(nft) = self.selected() {
+                    self.mode = Mode::ConfirmDelete(nft.token_id.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => {}
+            KeyCode::Esc => self.filter.clear(),
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.mode = Mode::Filter;
+            }
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.mode = Mode::Filter;
+            }
+            _ => self.mode = Mode::Filter,
+        }
+        self.clamp_selection();
+    }
+
+    fn open_form(
+        &mut self,
+        kind: FormKind,
+        token_id: Option<String>,
+        fields: Vec<(&'static str, String)>,
+    ) {
+        self.status = None;
+        self.mode = Mode::Form(Form {
+            kind,
+            token_id,
+            fields,
+            focus: 0,
+        });
+    }
+
+    fn handle_form_key(&mut self, mut form: Form, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.status = Some("Operação cancelada.".to_string());
+                return;
+            }
+            KeyCode::Enter => {
+                match self.submit(&form) {
+                    Ok(message) => self.status = Some(message),
+                    Err(e) => {
+                        self.status = Some(format!("Erro: {}", e));
+                        self.mode = Mode::Form(form);
+                    }
+                }
+                return;
+            }
+            KeyCode::Tab | KeyCode::Down => form.focus = (form.focus + 1) % form.fields.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                form.focus = (form.focus + form.fields.len() - 1) % form.fields.len()
+            }
+            KeyCode::Backspace => {
+                form.fields[form.focus].1.pop();
+            }
+            KeyCode::Char(c) => form.fields[form.focus].1.push(c),
+            _ => {}
+        }
+        self.mode = Mode::Form(form);
+    }
+
+    /// Grava o formulário usando as mesmas funções do menu e dos comandos.
+    fn submit(&mut self, form: &Form) -> Result<String, String> {
+        let message = match (form.kind, &form.token_id) {
+            (FormKind::Create, _) => {
+                let token_id = Some(form.value(0)).filter(|t| !t.is_empty());
+                let token_id = process_token_id(
+                    token_id,
+                    &self.token_id_strategy,
+                    None,
+                    &mut self.storage,
+                )?;
+                let owner_id = parse_owner_id(form.value(1))?;
+                let creation_date = NaiveDate::parse_from_str(form.value(2), "%Y-%m-%d")
+                    .map_err(|_| "Data inválida. Formato esperado: AAAA-MM-DD.".to_string())?;
+                if creation_date > chrono::Local::now().date_naive() {
+                    return Err("A data não pode ser no futuro.".to_string());
+                }
+                let definitions = self.storage.load_categories().map_err(|e| e.to_string())?;
+                let path = CategoryPath::resolve(form.value(3), &definitions)?;
+                let mut nft = collect_nft_data(
+                    token_id.clone(),
+                    owner_id,
+                    creation_date,
+                    path.category.clone(),
+                    &self.rules,
+                )?;
+                nft.set_category_path(path);
+                process_create_nft(&nft, &mut self.storage).map_err(|e| e.to_string())?;
+                self.reload()?;
+                self.select_token(&token_id);
+                "NFT salvo com sucesso!"
+            }
+            (FormKind::Edit, Some(token_id)) => {
+                let nft = self
+                    .nfts
+                    .iter()
+                    .find(|n| &n.token_id == token_id)
+                    .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", token_id))?;
+                let mut metadata = nft.metadata.clone();
+                let optional =
+                    |index: usize| Some(form.value(index).to_string()).filter(|v| !v.is_empty());
+                metadata.name = optional(0);
+                metadata.description = optional(1);
+                metadata.image = optional(2);
+                metadata.external_url = optional(3);
+                process_update_metadata(token_id, metadata, &mut self.storage)?;
+                self.reload()?;
+                "Metadados atualizados com sucesso!"
+            }
+            (FormKind::Transfer, Some(token_id)) => {
+                let owner_id = parse_owner_id(form.value(0))?;
+                process_update_nft(token_id, owner_id, &self.rules, &mut self.storage)?;
+                self.reload()?;
+                "NFT atualizado com sucesso!"
+            }
+            (_, None) => return Err("Nenhum NFT selecionado.".to_string()),
+        };
+        Ok(message.to_string())
+    }
+}
+
+fn parse_owner_id(input: &str) -> Result<u64, String> {
+    match input.parse::<u64>() {
+        Ok(id) if id > 0 => Ok(id),
+        _ => Err("Owner ID inválido. Por favor, insira um número inteiro maior que 0.".to_string()),
+    }
+}
+
+/// Desenha a tabela, o painel de detalhes e a barra de status.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(2)]).areas(frame.area());
+    let [table_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(main);
+
+    let visible = app.visible();
+    let arrow = if app.descending { "▼" } else { "▲" };
+    let header = Row::new(
+        [
+            SortColumn::TokenId,
+            SortColumn::Owner,
+            SortColumn::Date,
+            SortColumn::Category,
+        ]
+        .map(|column| {
+            if column == app.sort {
+                Cell::from(format!("{} {}", column.label(), arrow))
+            } else {
+                Cell::from(column.label())
+            }
+        }),
+    )
+    .style(Style::new().add_modifier(Modifier::BOLD));
+    let rows = visible.iter().map(|nft| {
+        Row::new([
+            nft.token_id.clone(),
+            nft.owner_id.to_string(),
+            nft.creation_date.to_string(),
+            nft.category_path().to_string(),
+        ])
+    });
+    let title = if app.filter.is_empty() {
+        format!("NFTs ({})", visible.len())
+    } else {
+        format!(
+            "NFTs ({} de {}) — filtro: {}",
+            visible.len(),
+            app.nfts.len(),
+            app.filter
+        )
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Fill(2),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Fill(2),
+        ],
+    )
+    .header(header)
+    .block(Block::new().borders(Borders::ALL).title(title))
+    .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = TableState::new().with_selected((!visible.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(table, table_area, &mut state);
+
+    let details = match visible.get(app.selected) {
+        Some(nft) => detail_lines(nft, &app.owners),
+        None => vec![Line::from("Nenhum NFT encontrado.")],
+    };
+    frame.render_widget(
+        Paragraph::new(details)
+            .wrap(Wrap { trim: false })
+            .block(Block::new().borders(Borders::ALL).title("Detalhes")),
+        detail_area,
+    );
+
+    let help = match &app.mode {
+        Mode::Browse => {
+            "↑/↓ mover  s ordenar  r inverter  / filtrar  n criar  e editar  t transferir  d deletar  q sair"
+                .to_string()
+        }
+        Mode::Filter => format!("Filtro: {}▏  (Enter confirma, Esc limpa)", app.filter),
+        Mode::Form(_) => "Tab próximo campo  Enter salvar  Esc cancelar".to_string(),
+        Mode::ConfirmDelete(token_id) => format!("Deletar o NFT '{}'? (s/N)", token_id),
+    };
+    let mut footer_lines = vec![Line::from(help)];
+    if let Some(status) = &app.status {
+        footer_lines.push(Line::from(status.as_str()));
+    }
+    frame.render_widget(Paragraph::new(footer_lines), footer);
+
+    if let Mode::Form(form) = &app.mode {
+        draw_form(frame, form);
+    }
+}
+
+fn detail_lines<'a>(nft: &'a NFT, owners: &[Owner]) -> Vec<Line<'a>> {
+    let mut lines = vec![Line::from(format!("Token ID: {}", nft.token_id))];
+    lines.push(Line::from(
+        match owners.iter().find(|o| o.id == nft.owner_id) {
+            Some(owner) => format!("Owner ID: {} ({})", nft.owner_id, owner.display_name),
+            None => format!("Owner ID: {}", nft.owner_id),
+        },
+    ));
+    lines.push(Line::from(format!(
+        "Data de Criação: {}",
+        nft.creation_date
+    )));
+    lines.push(Line::from(format!("Categoria: {}", nft.category_path())));
+    if !nft.tags.is_empty() {
+        lines.push(Line::from(format!("Marcadores: {}", nft.tags.join(", "))));
+    }
+    if let Some(address) = &nft.owner_address {
+        lines.push(Line::from(format!("Carteira: {}", address)));
+    }
+    if let Some(collection_id) = &nft.collection_id {
+        lines.push(Line::from(format!("Coleção: {}", collection_id)));
+    }
+
+    let metadata = &nft.metadata;
+    let fields = [
+        ("Nome", &metadata.name),
+        ("Descrição", &metadata.description),
+        ("Imagem", &metadata.image),
+        ("Animação", &metadata.animation_url),
+        ("URL Externa", &metadata.external_url),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value {
+            lines.push(Line::from(format!("{}: {}", label, value)));
+        }
+    }
+    if !metadata.attributes.is_empty() {
+        lines.push(Line::from("Atributos:"));
+        for attribute in &metadata.attributes {
+            lines.push(Line::from(format!(
+                "  - {}: {}",
+                attribute.trait_type, attribute.value
+            )));
+        }
+    }
+    lines
+}
+
+fn draw_form(frame: &mut Frame, form: &Form) {
+    let area = centered(frame.area(), 60, form.fields.len() as u16 + 2);
+    let lines: Vec<Line> = form
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, (label, value))| {
+            let line = Line::from(format!("{}: {}", label, value));
+            if index == form.focus {
+                line.style(Style::new().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::new().borders(Borders::ALL).title(form.title())),
+        area,
+    );
+}
+
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Abre a interface em tela cheia até o usuário sair.
+pub fn run_tui(config: &Config) -> Result<(), String> {
+    let mut app = App::new(&config.database.value)?.with_config(config);
+    let mut terminal = ratatui::init();
+
+    let result = (|| -> io::Result<()> {
+        while !app.should_quit() {
+            terminal.draw(|frame| draw(frame, &app))?;
+            if let Event::Key(key) = event::read()? {
+                // Ignora a liberação das teclas, reportada em alguns terminais
+                if key.kind != KeyEventKind::Release {
+                    app.handle_key(key);
+                }
+            }
+        }
+        Ok(())
+    })();
+
+    ratatui::restore();
+    result.map_err(|e| e.to_string())
+}
+
+/// Executa `tui`.
+pub fn tui_command(config: &Config) -> bool {
+    match run_tui(config) {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,373 @@
+// src/cli/valuations.rs
+
+use crate::cli::args::{Args, OutputFormat};
+use crate::models::money::{accumulate, Money};
+use crate::models::nft::NFT;
+use crate::models::owner::Owner;
+use crate::models::sale::Sale;
+use crate::models::valuation::{price_history, value_as_of, PricePoint, Valuation};
+use crate::storage::file_storage::FileStorage;
+use crate::storage::ledger::LedgerEntry;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Valor dos NFTs de um proprietário, com um total por moeda.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OwnerValue {
+    pub owner_id: u64,
+    pub owner_name: Option<String>,
+    pub nfts: usize,
+    pub totals: Vec<Money>,
+}
+
+/// Valor dos NFTs de uma categoria, com um total por moeda.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CategoryValue {
+    pub category: String,
+    pub nfts: usize,
+    pub totals: Vec<Money>,
+}
+
+/// Valor das carteiras em uma data. Cada NFT vale a avaliação ou venda mais
+/// recente até a data; os que não têm nenhuma aparecem em `unvalued`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ValuationReport {
+    pub as_of: NaiveDate,
+    pub owners: Vec<OwnerValue>,
+    pub categories: Vec<CategoryValue>,
+    pub totals: Vec<Money>,
+    pub unvalued: Vec<String>,
+}
+
+pub fn process_record_valuation(
+    valuation: &Valuation,
+    storage: &mut FileStorage,
+) -> Result<(), String> {
+    valuation.validate()?;
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let nft = nfts
+        .iter()
+        .find(|n| n.token_id == valuation.token_id)
+        .ok_or_else(|| format!("NFT com Token ID '{}' não encontrado.", valuation.token_id))?;
+    if valuation.date < nft.creation_date {
+        return Err("Data da avaliação é anterior à criação do NFT".to_string());
+    }
+
+    let mut valuations = storage.load_valuations().map_err(|e| e.to_string())?;
+    valuations.push(valuation.clone());
+    storage
+        .save_valuations(&valuations)
+        .map_err(|e| e.to_string())
+}
+
+/// Avaliações e vendas do NFT, em ordem de data.
+pub fn process_price_history(
+    token_id: &str,
+    storage: &mut FileStorage,
+) -> Result<Vec<PricePoint>, String> {
+    let valuations = storage.load_valuations().map_err(|e| e.to_string())?;
+    let sales = storage.load_sales().map_err(|e| e.to_string())?;
+    Ok(price_history(token_id, &valuations, &sales))
+}
+
+/// Valor por proprietário e por categoria na data `as_of`, considerando os
+/// NFTs que existiam naquela data, no estado e com os proprietários daquela
+/// data segundo o histórico de mutações e as vendas. NFTs removidos depois
+/// da data não têm mais preços e aparecem em `unvalued`. Cada cotista de um
+/// NFT compartilhado soma a parcela do valor proporcional às suas cotas.
+pub fn process_valuation_report(
+    as_of: NaiveDate,
+    storage: &mut FileStorage,
+) -> Result<ValuationReport, String> {
+    let nfts = storage.load_all().map_err(|e| e.to_string())?;
+    let owners = storage.load_owners().map_err(|e| e.to_string())?;
+    let valuations = storage.load_valuations().map_err(|e| e.to_string())?;
+    let sales = storage.load_sales().map_err(|e| e.to_string())?;
+    let history = storage.ledger().load_all().map_err(|e| e.to_string())?;
+
+    let mut report = ValuationReport {
+        as_of,
+        owners: Vec::new(),
+        categories: Vec::new(),
+        totals: Vec::new(),
+        unvalued: Vec::new(),
+    };
+    for (nft, entries) in nfts_as_of(&nfts, &history, as_of) {
+        let history = price_history(&nft.token_id, &valuations, &sales);
+        let value = value_as_of(&history, as_of).map(|point| &point.value);
+        match value {
+            Some(value) => accumulate(&mut report.totals, value)?,
+            None => report.unvalued.push(nft.token_id.clone()),
+        }
+
+        let category_name = nft.category.to_string();
+        let category = match report
+            .categories
+            .iter()
+            .position(|c| c.category == category_name)
+        {
+            Some(index) => &mut report.categories[index],
+            None => {
+                report.categories.push(CategoryValue {
+                    category: category_name,
+                    nfts: 0,
+                    totals: Vec::new(),
+                });
+                report.categories.last_mut().expect("categoria inserida")
+            }
+        };
+        category.nfts += 1;
+        if let Some(value) = value {
+            accumulate(&mut category.totals, value)?;
+        }
+
+        for (owner_id, units, total) in holders_as_of(&nft, &entries, &sales, as_of) {
+            let owner = match report.owners.iter().position(|o| o.owner_id == owner_id) {
+                Some(index) => &mut report.owners[index],
+                None => {
+                    report.owners.push(OwnerValue {
+                        owner_id,
+                        owner_name: owner_name(owner_id, &owners),
+                        nfts: 0,
+                        totals: Vec::new(),
+                    });
+                    report.owners.last_mut().expect("proprietário inserido")
+                }
+            };
+            owner.nfts += 1;
+            if let Some(value) = value {
+                accumulate(&mut owner.totals, &value.share(units, total)?)?;
+            }
+        }
+    }
+    report.owners.sort_by_key(|o| o.owner_id);
+    report
+        .categories
+        .sort_by(|a, b| a.category.cmp(&b.category));
+    Ok(report)
+}
+
+/// NFTs que existiam na data, cada um no estado daquela data e com as suas
+/// entradas do histórico. Inclui NFTs removidos depois da data e exclui os
+/// removidos até ela; como as vendas e avaliações são apagadas junto com o
+/// NFT, os removidos depois da data entram no relatório sem avaliação. Um NFT criado antes da data, mas registrado no
+/// histórico só depois dela (bancos anteriores ao histórico ou datas de
+/// criação retroativas), vale pelo primeiro estado registrado.
+fn nfts_as_of<'a>(
+    current: &[NFT],
+    history: &'a [LedgerEntry],
+    as_of: NaiveDate,
+) -> Vec<(NFT, Vec<&'a LedgerEntry>)> {
+    let mut by_token: BTreeMap<&str, Vec<&LedgerEntry>> = BTreeMap::new();
+    for entry in history {
+        by_token.entry(&entry.token_id).or_default().push(entry);
+    }
+    for nft in current {
+        by_token.entry(&nft.token_id).or_default();
+    }
+
+    by_token
+        .into_iter()
+        .filter_map(|(token_id, entries)| {
+            let state = match entries.iter().rev().find(|e| e.timestamp.date() <= as_of) {
+                Some(entry) => entry.nft.clone(),
+                None => match entries.first() {
+                    Some(first) => first.nft.clone(),
+                    None => current.iter().find(|n| n.token_id == token_id).cloned(),
+                },
+            };
+            state
+                .filter(|nft| nft.creation_date <= as_of)
+                .map(|nft| (nft, entries))
+        })
+        .collect()
+}
+
+/// Proprietários do NFT na data, com as cotas de cada um e o total. NFTs
+/// compartilhados usam as cotas do estado da data; os demais, o proprietário
+/// da data (veja [`owner_as_of`]).
+fn holders_as_of(
+    nft: &NFT,
+    entries: &[&LedgerEntry],
+    sales: &[Sale],
+    as_of: NaiveDate,
+) -> Vec<(u64, u64, u64)> {
+    match &nft.shares {
+        Some(shares) => shares
+            .holders
+            .iter()
+            .map(|h| (h.owner_id, h.units, shares.total))
+            .collect(),
+        None => vec![(owner_as_of(nft, entries, sales, as_of), 1, 1)],
+    }
+}
+
+/// Proprietário do NFT na data: o da última mudança até ela, seja uma venda
+/// (pela data da venda) ou uma entrada do histórico (pela data de registro;
+/// no mesmo dia, o histórico prevalece). Antes da primeira mudança, o
+/// proprietário anterior a ela: o vendedor, se for uma venda.
+fn owner_as_of(nft: &NFT, entries: &[&LedgerEntry], sales: &[Sale], as_of: NaiveDate) -> u64 {
+    // (data, vem do histórico, proprietário antes, proprietário depois)
+    let mut changes: Vec<(NaiveDate, bool, u64, u64)> = sales
+        .iter()
+        .filter(|s| s.token_id == nft.token_id)
+        .map(|s| (s.date, false, s.seller_id, s.buyer_id))
+        .chain(entries.iter().filter_map(|entry| {
+            let owner_id = entry.nft.as_ref()?.owner_id;
+            Some((entry.timestamp.date(), true, owner_id, owner_id))
+        }))
+        .collect();
+    // Ordenação estável: entradas do mesmo dia mantêm a ordem do histórico
+    changes.sort_by_key(|&(date, from_history, _, _)| (date, from_history));
+
+    match changes.iter().rev().find(|change| change.0 <= as_of) {
+        Some(&(_, _, _, after)) => after,
+        None => changes
+            .first()
+            .map_or(nft.owner_id, |&(_, _, before, _)| before),
+    }
+}
+
+fn owner_name(owner_id: u64, owners: &[Owner]) -> Option<String> {
+    owners
+        .iter()
+        .find(|o| o.id == owner_id)
+        .map(|o| o.display_name.clone())
+}
+
+fn format_totals(totals: &[Money]) -> String {
+    if totals.is_empty() {
+        return "-".to_string();
+    }
+    totals
+        .iter()
+        .map(Money::to_string)
+        .collect::<Vec<String>>()
+        .join(" + ")
+}
+
+/// Exibe o valor mais recente e o histórico de preços do NFT.
+pub(crate) fn print_price_history(history: &[PricePoint]) {
+    let Some(latest) = history.last() else {
+        return;
+    };
+    println!(
+        "Valor Atual: {} ({}, {} em {})",
+        latest.value, latest.source, latest.kind, latest.date
+    );
+    println!("Histórico de Preços:");
+    for point in history {
+        println!(
+            "  {} | {} | {} | {}",
+            point.date, point.kind, point.value, point.source
+        );
+    }
+}
+
+fn print_report(report: &ValuationReport) {
+    println!("Valor das carteiras em {}", report.as_of);
+    println!("\nPor proprietário:");
+    for owner in &report.owners {
+        let name = match &owner.owner_name {
+            Some(name) => format!("{} ({})", owner.owner_id, name),
+            None => owner.owner_id.to_string(),
+        };
+        println!(
+            "  Owner ID {}: {} em {} NFT(s)",
+            name,
+            format_totals(&owner.totals),
+            owner.nfts
+        );
+    }
+    println!("\nPor categoria:");
+    for category in &report.categories {
+        println!(
+            "  {}: {} em {} NFT(s)",
+            category.category,
+            format_totals(&category.totals),
+            category.nfts
+        );
+    }
+    println!("\nTotal: {}", format_totals(&report.totals));
+    if !report.unvalued.is_empty() {
+        println!("Sem avaliação: {}", report.unvalued.join(", "));
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| "Data inválida. Formato esperado: AAAA-MM-DD.".to_string())
+}
+
+fn record_valuation(token_id: &str, args: &Args, storage: &mut FileStorage) -> Result<(), String> {
+    let value = match (args.value("amount"), args.value("currency")) {
+        (Some(amount), Some(currency)) => Money::parse(amount, currency)?,
+        _ => return Err("Informe --amount e --currency".to_string()),
+    };
+    let valuation = Valuation {
+        token_id: token_id.to_string(),
+        value,
+        date: match args.value("date") {
+            Some(date) => parse_date(date)?,
+            None => chrono::Local::now().date_naive(),
+        },
+        source: args
+            .value("source")
+            .ok_or("Informe --source com a origem da avaliação")?
+            .trim()
+            .to_string(),
+    };
+    process_record_valuation(&valuation, storage)?;
+    println!(
+        "Avaliação registrada: {} vale {} em {}.",
+        token_id, valuation.value, valuation.date
+    );
+    Ok(())
+}
+
+/// Executa `valuation <record|history|report> ...`.
+pub fn valuation_command(db_path: &str, args: &Args) -> bool {
+    let mut storage = FileStorage::new(db_path);
+
+    let result = match (args.positional(0), args.positional(1)) {
+        (Some("record"), Some(token_id)) => record_valuation(token_id, args, &mut storage),
+        (Some("history"), Some(token_id)) => {
+            process_price_history(token_id, &mut storage).map(|history| {
+                if history.is_empty() {
+                    println!("Nenhum preço registrado para {}.", token_id);
+                }
+                print_price_history(&history);
+            })
+        }
+        (Some("report"), None) => OutputFormat::from_args(args).and_then(|format| {
+            let as_of = match args.value("as-of") {
+                Some(date) => parse_date(date)?,
+                None => chrono::Local::now().date_naive(),
+            };
+            let report = process_valuation_report(as_of, &mut storage)?;
+            match format {
+                OutputFormat::Table => print_report(&report),
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?
+                ),
+            }
+            Ok(())
+        }),
+        _ => Err(
+            "Uso: nft_manager valuation <record <token_id> --amount VALOR --currency MOEDA \
+             --source FONTE [--date AAAA-MM-DD]|history <token_id>|report [--as-of AAAA-MM-DD] \
+             [--format table|json]>"
+                .to_string(),
+        ),
+    };
+
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Erro: {}", e);
+            false
+        }
+    }
+}
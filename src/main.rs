@@ -1,10 +1,35 @@
 // src/main.rs
 
-mod cli;
-mod models;
-mod storage;
-
+use nft_manager::cli;
 use std::env;
+use std::process;
+
+/// Opções globais, aceitas antes do comando ou do menu interativo.
+const GLOBAL_OPTIONS: &[&str] = &["--config", "--profile", "--db"];
+
+/// Separa as opções globais do início da linha de comando (`--db NOME` ou
+/// `--db=NOME`) do comando e dos seus argumentos.
+fn split_global_options(args: &[String]) -> (Vec<String>, &[String]) {
+    let mut global = Vec::new();
+    let mut index = 0;
+    while let Some(arg) = args.get(index) {
+        let Some(option) = GLOBAL_OPTIONS
+            .iter()
+            .find(|option| arg == *option || arg.starts_with(&format!("{}=", option)))
+        else {
+            break;
+        };
+        global.push(arg.clone());
+        index += 1;
+        if arg == option {
+            if let Some(value) = args.get(index) {
+                global.push(value.clone());
+                index += 1;
+            }
+        }
+    }
+    (global, &args[index..])
+}
 
 /// Função principal do programa.
 fn main() {
@@ -17,16 +42,104 @@ fn main() {
             println!("Gerenciador de NFTs");
             println!("Uso:");
             println!("  nft_manager [--help]");
+            println!("  nft_manager [--config ARQ] [--profile NOME] [--db NOME] <comando> [argumentos]");
             println!("\nOpções:");
             println!("  --help, -h     Exibe esta mensagem de ajuda");
+            println!("  --config ARQ   Arquivo de configuração (padrão: ~/.config/nft_manager/config.toml)");
+            println!("  --profile NOME Perfil do arquivo de configuração");
+            println!("  --db NOME      Banco de dados registrado na configuração");
+            println!("\nComandos:");
+            println!("  create [token] Cria um NFT: --owner ID, --category C[/SUB...], --tag T,");
+            println!("                 --date AAAA-MM-DD, --collection ID; sem Token ID, ele é");
+            println!("                 gerado automaticamente");
+            println!("                 [--id-strategy uuid4|uuid7|sequential|template:PREFIXO{{n:04}}]");
+            println!("  verify         Verifica a integridade do histórico do banco de dados");
+            println!("  migrate        Regrava o banco de dados no esquema atual, inicia o");
+            println!("                 histórico se ele não existir e corrige descrições de");
+            println!("                 Other inválidas");
+            println!("  show <token>   Exibe um NFT com seus metadados");
+            println!("  list           Lista NFTs: --where '...', --category C[/SUB...], --tag T (todos),");
+            println!("                 --any-tag T (algum), --owner ID, --collection ID, --token ID");
+            println!("                 --where aceita consultas como \"category in (art, music) and");
+            println!("                 created < 2023-01-01 and owner not in (1, 2)\"");
+            println!("  edit <token>   Edita um NFT: --owner-address REDE:ENDEREÇO, --category C[/SUB...]");
+            println!("                 e os metadados");
+            println!("                 --name, --description, --image, --animation-url,");
+            println!("                 --external-url (valor vazio remove o campo),");
+            println!("                 --attribute TRAIT=VALOR, --display-type TRAIT=TIPO,");
+            println!("                 --remove-attribute TRAIT");
+            println!("                 --royalty-bps BPS --royalty-recipient ID (royalty próprio;");
+            println!("                 --royalty-bps \"\" remove e volta a valer o da coleção)");
+            println!("  import <dir>   Importa documentos de metadados ERC-721 (<token_id>.json):");
+            println!("                 --owner ID, --date AAAA-MM-DD, --category-attribute TRAIT,");
+            println!("                 --default-category C[/SUB...]");
+            println!("  export <dir>   Exporta cada NFT para <dir>/<token_id>.json:");
+            println!("                 --category-attribute TRAIT e os filtros de list");
+            println!("  collection     Gerencia coleções:");
+            println!("                 create <id> --name N --symbol S [--chain C] [--contract ADDR]");
+            println!("                        [--max-supply N] [--royalty-bps BPS --royalty-recipient ID]");
+            println!("                 update <id> [opções de create], delete <id>, show <id>, list");
+            println!("                 assign <token_id> <coleção>, unassign <token_id>");
+            println!("  owner          Gerencia proprietários:");
+            println!("                 create <id> --name N [--wallet REDE:ENDEREÇO] [--contact C]");
+            println!("                 update <id> [opções de create] [--remove-wallet W]");
+            println!("                 delete <id>, show <id>, list");
+            println!("                 policy <strict|open>  Exige proprietário registrado nas transferências");
+            println!("  category       Gerencia o registro de categorias:");
+            println!("                 define <nome> [--alias A]... [--description D]");
+            println!("                 update <nome> [opções de define] [--remove-alias A]");
+            println!("                 delete <nome>, list, rename <antiga> <nova>");
+            println!("                 merge <origem>... --into <destino>");
+            println!("  update         Altera em lote: --where 'owner=42 and category=art'");
+            println!("                 --set owner=ID|category=C|collection=ID [--yes] [--dry-run]");
+            println!("  delete         Remove em lote: --where '...' [--yes] [--dry-run]");
+            println!("  search <texto> Busca aproximada por Token ID, categoria, marcadores e metadados");
+            println!("                 [--limit N]");
+            println!("  sale           Vendas no mercado secundário:");
+            println!("                 record <token> --buyer ID --price VALOR --currency MOEDA");
+            println!("                        [--date AAAA-MM-DD]");
+            println!("                 list [token], royalties [--from AAAA-MM-DD] [--to AAAA-MM-DD]");
+            println!("  share          Propriedade compartilhada: split <token> --total N,");
+            println!("                 transfer <token> --from ID --to ID --units N, join <token>, show <token>");
+            println!("  valuation      Avaliações e valor das carteiras:");
+            println!("                 record <token> --amount VALOR --currency MOEDA --source FONTE");
+            println!("                        [--date AAAA-MM-DD]");
+            println!("                 history <token>, report [--as-of AAAA-MM-DD] [--format table|json]");
+            println!("  tag            Gerencia marcadores: add <token> <marcador>...,");
+            println!("                 remove <token> <marcador>..., rename <antigo> <novo>, list");
+            println!("  portfolio <owner>  NFTs de um proprietário por categoria [--format table|json]");
+            println!("  stats          Estatísticas da coleção [--top N] [--format table|json]");
+            println!("  chart          Gráficos no terminal [--top N] [--width N] [--ascii]");
+            println!("  tui            Interface em tela cheia para navegar e editar os NFTs");
+            println!("  config show    Exibe a configuração efetiva e a origem de cada valor");
+            println!("  db list        Lista os bancos de dados registrados");
+            println!("  copy <banco>   Copia NFTs do banco atual para outro (nome registrado ou caminho):");
+            println!("                 [--token ID] [--category C] [--tag T] [--any-tag T] [--owner ID]");
+            println!("                 [--collection ID]");
+            println!("                 [--on-conflict skip|overwrite|rename]");
+            println!("  move <banco>   Como copy, removendo os NFTs transferidos da origem");
+            println!("  diff <a> <b>   NFTs adicionados, removidos e alterados campo a campo [--format table|json]");
+            println!("  merge <base> <nosso> <deles> -o <saída>");
+            println!("                 Mesclagem de três vias por Token ID [--on-conflict fail|ours|theirs]");
             // Sai do programa
             return;
-        } else {
-            println!("Opção de linha de comando desconhecida: {}", arg);
-            println!("Use '--help' para ver as opções disponíveis.");
-            return;
         }
     }
 
-    cli::run_cli();
+    // As opções globais valem para o comando seguinte; sem comando, abre o menu
+    let (global, rest) = split_global_options(&args[1..]);
+    let Some(command) = rest.first() else {
+        cli::run_cli(&global);
+        return;
+    };
+    let mut command_args = global;
+    command_args.extend_from_slice(&rest[1..]);
+    match cli::run_command(command, &command_args) {
+        Some(true) => {}
+        Some(false) => process::exit(1),
+        None => {
+            println!("Opção de linha de comando desconhecida: {}", command);
+            println!("Use '--help' para ver as opções disponíveis.");
+        }
+    }
 }
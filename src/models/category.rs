@@ -1,7 +1,10 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
+/// Categoria do NFT. A forma textual canônica é a de `Display` (`Art`,
+/// `Virtual Real Estate`, `Other: Ingresso`), que `FromStr` interpreta de
+/// volta na mesma categoria.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Category {
     Art,
@@ -12,6 +15,273 @@ pub enum Category {
     Other(String),
 }
 
+/// Categoria definida pelo usuário e persistida no banco de dados.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CategoryDefinition {
+    /// Nome canônico, gravado nos NFTs.
+    pub name: String,
+    /// Outros nomes aceitos na entrada para esta categoria.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl CategoryDefinition {
+    pub fn new(name: &str) -> Self {
+        CategoryDefinition {
+            name: name.trim().to_string(),
+            aliases: Vec::new(),
+            description: None,
+        }
+    }
+
+    /// Nome e apelidos, na forma usada nas comparações.
+    pub fn keys(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .map(|name| category_key(name))
+    }
+
+    /// Verdadeiro se o texto é o nome ou um apelido, sem diferenciar maiúsculas.
+    pub fn matches(&self, text: &str) -> bool {
+        let key = category_key(text);
+        self.keys().any(|k| k == key)
+    }
+
+    /// Categoria gravada nos NFTs: a predefinida de mesmo nome, se houver.
+    pub fn category(&self) -> Category {
+        Category::named(&self.name).unwrap_or_else(|| Category::Other(self.name.clone()))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Nome da categoria não pode ser vazio".to_string());
+        }
+        let names = || std::iter::once(&self.name).chain(&self.aliases);
+        if let Some(name) = names().find(|name| explicit_other(name.trim()).is_some()) {
+            return Err(format!(
+                "'{}' não pode começar com '{}'",
+                name.trim(),
+                OTHER_PREFIX
+            ));
+        }
+        // Um nome predefinido só pode ser apelido da própria categoria predefinida
+        if let Some(name) =
+            names().find(|name| Category::named(name).is_some_and(|named| named != self.category()))
+        {
+            return Err(format!(
+                "'{}' é o nome de uma categoria predefinida",
+                name.trim()
+            ));
+        }
+        if let Some(name) = names().find(|name| name.contains(PATH_SEPARATOR)) {
+            return Err(format!(
+                "'{}' não pode conter '{}'; subcategorias são definidas nos NFTs",
+                name.trim(),
+                PATH_SEPARATOR
+            ));
+        }
+        let mut keys: Vec<String> = Vec::new();
+        for alias in std::iter::once(&self.name).chain(&self.aliases) {
+            let key = category_key(alias);
+            if key.is_empty() {
+                return Err("Apelido da categoria não pode ser vazio".to_string());
+            }
+            if keys.contains(&key) {
+                return Err(format!("Nome '{}' repetido na categoria", alias.trim()));
+            }
+            keys.push(key);
+        }
+        if let Some(description) = &self.description {
+            if description.trim().is_empty() {
+                return Err("Descrição não pode ser vazia".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prefixo da forma canônica de `Category::Other`.
+const OTHER_PREFIX: &str = "Other:";
+
+/// Descrição após o prefixo `Other:`, sem diferenciar maiúsculas.
+fn explicit_other(text: &str) -> Option<&str> {
+    let prefix = text.get(..OTHER_PREFIX.len())?;
+    prefix
+        .eq_ignore_ascii_case(OTHER_PREFIX)
+        .then(|| &text[OTHER_PREFIX.len()..])
+}
+
+/// Forma de comparação de nomes predefinidos: minúsculas, sem espaços,
+/// `_` ou `-` (`Game Item`, `game_item` e `GameItem` são equivalentes).
+fn alias_key(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace() && *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Separador entre a categoria e as subcategorias (`Art/Generative`).
+pub const PATH_SEPARATOR: char = '/';
+
+/// Categoria seguida de subcategorias, do nível mais geral ao mais específico.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryPath {
+    pub category: Category,
+    pub subcategories: Vec<String>,
+}
+
+impl CategoryPath {
+    pub fn new(category: Category, subcategories: Vec<String>) -> Self {
+        CategoryPath {
+            category,
+            subcategories,
+        }
+    }
+
+    /// Verdadeiro se `other` é esta categoria ou uma das suas subcategorias.
+    pub fn contains(&self, other: &CategoryPath) -> bool {
+        self.category.same_as(&other.category)
+            && self.subcategories.len() <= other.subcategories.len()
+            && self
+                .subcategories
+                .iter()
+                .zip(&other.subcategories)
+                .all(|(a, b)| category_key(a) == category_key(b))
+    }
+}
+
+impl fmt::Display for CategoryPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.category)?;
+        for subcategory in &self.subcategories {
+            write!(f, "{}{}", PATH_SEPARATOR, subcategory)?;
+        }
+        Ok(())
+    }
+}
+
+impl CategoryPath {
+    /// O primeiro nível é interpretado por [`Category::resolve`], com os nomes
+    /// e apelidos de `definitions`; os demais são subcategorias livres.
+    pub fn resolve(text: &str, definitions: &[CategoryDefinition]) -> Result<Self, String> {
+        let mut segments = text.split(PATH_SEPARATOR).map(str::trim);
+        let category = Category::resolve(segments.next().unwrap_or_default(), definitions)?;
+        let subcategories: Vec<String> = segments.map(str::to_string).collect();
+        if subcategories.iter().any(|s| s.is_empty()) {
+            return Err(format!("Subcategoria vazia em '{}'", text.trim()));
+        }
+        Ok(CategoryPath::new(category, subcategories))
+    }
+}
+
+/// Interpreta só as categorias predefinidas; veja [`CategoryPath::resolve`].
+impl FromStr for CategoryPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CategoryPath::resolve(s, &[])
+    }
+}
+
+/// Forma de comparação de nomes de categoria: sem espaços nas pontas e em minúsculas.
+pub fn category_key(text: &str) -> String {
+    text.trim().to_lowercase()
+}
+
+impl Category {
+    /// Categorias predefinidas, na ordem em que são apresentadas ao usuário.
+    pub const NAMED: [Category; 5] = [
+        Category::Art,
+        Category::Music,
+        Category::VirtualRealEstate,
+        Category::Collectible,
+        Category::GameItem,
+    ];
+
+    /// Categoria predefinida com o nome informado. Aceita o nome canônico,
+    /// o nome da variante (`GameItem`), snake_case, kebab-case e os nomes em
+    /// português, sem diferenciar maiúsculas.
+    pub fn named(text: &str) -> Option<Category> {
+        let key = alias_key(text);
+        Category::NAMED.into_iter().find(|category| {
+            alias_key(&category.to_string()) == key
+                || category
+                    .localized_names()
+                    .iter()
+                    .any(|name| alias_key(name) == key)
+        })
+    }
+
+    /// Nomes em português aceitos na entrada, com e sem acentos.
+    fn localized_names(&self) -> &'static [&'static str] {
+        match self {
+            Category::Art => &["arte"],
+            Category::Music => &["música", "musica"],
+            Category::VirtualRealEstate => &["imóvel virtual", "imovel virtual"],
+            Category::Collectible => &["colecionável", "colecionavel"],
+            Category::GameItem => &["item de jogo"],
+            Category::Other(_) => &[],
+        }
+    }
+
+    /// Interpreta o texto consultando as categorias definidas: um nome ou
+    /// apelido resolve para o nome canônico. `Other: descrição` é sempre
+    /// `Other`, e texto sem definição também vira `Other`.
+    pub fn resolve(text: &str, definitions: &[CategoryDefinition]) -> Result<Category, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("Categoria inválida".to_string());
+        }
+        if text.contains(PATH_SEPARATOR) {
+            return Err(format!(
+                "Categoria '{}' não pode conter '{}'; use subcategorias",
+                text, PATH_SEPARATOR
+            ));
+        }
+        if let Some(description) = explicit_other(text) {
+            return match description.trim() {
+                "" => Err("Descrição de 'Other' não pode ser vazia".to_string()),
+                description => Ok(Category::Other(description.to_string())),
+            };
+        }
+        if let Some(definition) = definitions.iter().find(|d| d.matches(text)) {
+            return Ok(definition.category());
+        }
+        Ok(Category::named(text).unwrap_or_else(|| Category::Other(text.to_string())))
+    }
+
+    /// Só a descrição de `Other` pode ser inválida: ela precisa voltar igual
+    /// quando a forma de `Display` é interpretada por `CategoryPath::from_str`.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Category::Other(description) if description.trim().is_empty() => {
+                Err("Descrição de 'Other' não pode ser vazia".to_string())
+            }
+            Category::Other(description) if description.trim() != description => Err(format!(
+                "Descrição de 'Other' não pode ter espaços nas pontas: '{}'",
+                description
+            )),
+            Category::Other(description) if description.contains(PATH_SEPARATOR) => Err(format!(
+                "Descrição de 'Other' não pode conter '{}': '{}'",
+                PATH_SEPARATOR, description
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Compara categorias gravadas nos NFTs: as predefinidas pela variante e
+    /// as `Other` pela descrição, sem diferenciar maiúsculas (`Other: Art` não
+    /// é `Art`).
+    pub fn same_as(&self, other: &Category) -> bool {
+        match (self, other) {
+            (Category::Other(a), Category::Other(b)) => category_key(a) == category_key(b),
+            (a, b) => a == b,
+        }
+    }
+}
+
 impl fmt::Display for Category {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -25,18 +295,12 @@ impl fmt::Display for Category {
     }
 }
 
+/// Interpreta só as categorias predefinidas; para consultar também as
+/// definidas no banco de dados, use [`Category::resolve`].
 impl FromStr for Category {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "art" => Ok(Category::Art),
-            "music" => Ok(Category::Music),
-            "virtual real estate" => Ok(Category::VirtualRealEstate),
-            "collectible" => Ok(Category::Collectible),
-            "game item" => Ok(Category::GameItem),
-            _ if !s.trim().is_empty() => Ok(Category::Other(s.to_string())),
-            _ => Err("Categoria inválida".to_string()),
-        }
+        Category::resolve(s, &[])
     }
-}
\ No newline at end of file
+}
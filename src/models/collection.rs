@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Máximo de pontos-base de royalty (100%).
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum Chain {
+    Ethereum,
+    Polygon,
+    Solana,
+    Bitcoin,
+    Other(String),
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Chain::Ethereum => write!(f, "Ethereum"),
+            Chain::Polygon => write!(f, "Polygon"),
+            Chain::Solana => write!(f, "Solana"),
+            Chain::Bitcoin => write!(f, "Bitcoin"),
+            Chain::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl FromStr for Chain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "ethereum" | "eth" => Ok(Chain::Ethereum),
+            "polygon" | "matic" => Ok(Chain::Polygon),
+            "solana" | "sol" => Ok(Chain::Solana),
+            "bitcoin" | "btc" => Ok(Chain::Bitcoin),
+            _ if !s.trim().is_empty() => Ok(Chain::Other(s.trim().to_string())),
+            _ => Err("Rede inválida".to_string()),
+        }
+    }
+}
+
+/// Configuração de royalty: percentual em pontos-base (1/100 de 1%) e beneficiário.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Royalty {
+    pub basis_points: u16,
+    pub recipient_id: u64,
+}
+
+impl Royalty {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.basis_points > MAX_BASIS_POINTS {
+            return Err(format!(
+                "Royalty de {} pontos-base excede o máximo de {}",
+                self.basis_points, MAX_BASIS_POINTS
+            ));
+        }
+        if self.recipient_id == 0 {
+            return Err("Beneficiário do royalty deve ser maior que zero".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Royalty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:02}% para o Owner ID {}",
+            self.basis_points / 100,
+            self.basis_points % 100,
+            self.recipient_id
+        )
+    }
+}
+
+/// Coleção (contrato) à qual NFTs podem pertencer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Collection {
+    pub id: String,
+    pub name: String,
+    pub symbol: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<String>,
+    pub chain: Chain,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_supply: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub royalty: Option<Royalty>,
+}
+
+impl Collection {
+    pub fn new(id: String, name: String, symbol: String, chain: Chain) -> Self {
+        Collection {
+            id,
+            name,
+            symbol,
+            contract_address: None,
+            chain,
+            max_supply: None,
+            royalty: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("ID da coleção não pode ser vazio".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("Nome da coleção não pode ser vazio".to_string());
+        }
+        if self.symbol.trim().is_empty() || self.symbol.chars().any(char::is_whitespace) {
+            return Err("Símbolo da coleção deve ser não vazio e sem espaços".to_string());
+        }
+        if let Some(address) = &self.contract_address {
+            if address.trim().is_empty() {
+                return Err("Endereço do contrato não pode ser vazio".to_string());
+            }
+        }
+        if self.max_supply == Some(0) {
+            return Err("Fornecimento máximo deve ser maior que zero".to_string());
+        }
+        if let Some(royalty) = &self.royalty {
+            royalty.validate()?;
+        }
+        Ok(())
+    }
+}
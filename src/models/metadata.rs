@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Esquemas de URI aceitos para `image`, `animation_url` e `external_url`.
+const URI_SCHEMES: [&str; 5] = ["http://", "https://", "ipfs://", "ar://", "data:"];
+
+/// Tipos de exibição definidos pelo padrão de metadados ERC-721 (OpenSea).
+const DISPLAY_TYPES: [&str; 4] = ["number", "boost_number", "boost_percentage", "date"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum AttributeValue {
+    Number(f64),
+    Text(String),
+}
+
+impl AttributeValue {
+    /// Interpreta a entrada como número quando possível, senão como texto.
+    /// Códigos com zeros à esquerda (`007`) continuam texto, para não
+    /// perderem os zeros.
+    pub fn parse(input: &str) -> Self {
+        let input = input.trim();
+        let digits = input.strip_prefix(['-', '+']).unwrap_or(input).as_bytes();
+        let leading_zero = digits.len() > 1 && digits[0] == b'0' && digits[1].is_ascii_digit();
+        match input.parse::<f64>() {
+            Ok(number) if number.is_finite() && !leading_zero => AttributeValue::Number(number),
+            _ => AttributeValue::Text(input.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeValue::Number(number) => write!(f, "{}", number),
+            AttributeValue::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Atributo no formato ERC-721 (`trait_type`/`value`/`display_type`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: AttributeValue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+}
+
+impl Attribute {
+    pub fn new(trait_type: &str, value: AttributeValue) -> Self {
+        Attribute {
+            trait_type: trait_type.trim().to_string(),
+            value,
+            display_type: None,
+        }
+    }
+}
+
+/// Metadados descritivos opcionais de um NFT.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NftMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Attribute>,
+}
+
+impl NftMetadata {
+    pub fn is_empty(&self) -> bool {
+        *self == NftMetadata::default()
+    }
+
+    /// Adiciona o atributo ou substitui o valor de um atributo de mesmo `trait_type`.
+    pub fn set_attribute(&mut self, attribute: Attribute) {
+        match self
+            .attributes
+            .iter_mut()
+            .find(|a| a.trait_type == attribute.trait_type)
+        {
+            Some(existing) => *existing = attribute,
+            None => self.attributes.push(attribute),
+        }
+    }
+
+    /// Remove o atributo com o `trait_type` informado. Retorna `false` se não existir.
+    pub fn remove_attribute(&mut self, trait_type: &str) -> bool {
+        let original_len = self.attributes.len();
+        self.attributes.retain(|a| a.trait_type != trait_type);
+        self.attributes.len() < original_len
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(name) = &self.name {
+            if name.trim().is_empty() {
+                return Err("Nome não pode ser vazio".to_string());
+            }
+        }
+
+        validate_uri("image", &self.image)?;
+        validate_uri("animation_url", &self.animation_url)?;
+        validate_uri("external_url", &self.external_url)?;
+
+        for attribute in &self.attributes {
+            if attribute.trait_type.trim().is_empty() {
+                return Err("Atributo com trait_type vazio".to_string());
+            }
+            if let Some(display_type) = &attribute.display_type {
+                if !DISPLAY_TYPES.contains(&display_type.as_str()) {
+                    return Err(format!(
+                        "display_type '{}' inválido no atributo '{}'",
+                        display_type, attribute.trait_type
+                    ));
+                }
+                if !matches!(attribute.value, AttributeValue::Number(_)) {
+                    return Err(format!(
+                        "Atributo '{}' com display_type '{}' deve ter valor numérico",
+                        attribute.trait_type, display_type
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_uri(field: &str, value: &Option<String>) -> Result<(), String> {
+    if let Some(uri) = value {
+        let has_scheme = URI_SCHEMES
+            .iter()
+            .any(|scheme| uri.to_lowercase().starts_with(scheme));
+        if !has_scheme || uri.chars().any(char::is_whitespace) {
+            return Err(format!(
+                "URI inválida em {}: '{}' (esquemas aceitos: http, https, ipfs, ar, data)",
+                field, uri
+            ));
+        }
+    }
+    Ok(())
+}
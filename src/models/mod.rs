@@ -1,2 +1,13 @@
 pub mod nft;
-pub mod category;
\ No newline at end of file
+pub mod category;
+pub mod metadata;
+pub mod collection;
+pub mod owner;
+pub mod settings;
+pub mod wallet;
+pub mod token_id;
+pub mod query;
+pub mod money;
+pub mod sale;
+pub mod valuation;
+pub mod shares;
@@ -0,0 +1,121 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Valor monetário em aritmética decimal exata, com o código da moeda
+/// (`ETH`, `USD`, `BRL`...) sempre em maiúsculas.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Money {
+    pub amount: Decimal,
+    pub currency: String,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: &str) -> Result<Money, String> {
+        let money = Money {
+            amount,
+            currency: currency.trim().to_uppercase(),
+        };
+        money.validate()?;
+        Ok(money)
+    }
+
+    /// Interpreta o valor (`1.5` ou `1,5`) e a moeda.
+    pub fn parse(amount: &str, currency: &str) -> Result<Money, String> {
+        let amount = Decimal::from_str(&amount.trim().replace(',', "."))
+            .map_err(|_| format!("Valor inválido: '{}'", amount.trim()))?;
+        Money::new(amount, currency)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.amount.is_sign_negative() && !self.amount.is_zero() {
+            return Err("Valor não pode ser negativo".to_string());
+        }
+        if !(2..=10).contains(&self.currency.len())
+            || !self.currency.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(format!(
+                "Moeda inválida: '{}' (esperado código como ETH ou USD)",
+                self.currency
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parcela do valor em pontos-base (1/100 de 1%), na mesma moeda. Falha
+    /// se o valor for grande demais para o cálculo.
+    pub fn basis_points(&self, basis_points: u16) -> Result<Money, String> {
+        let amount = self
+            .amount
+            .checked_mul(Decimal::from(basis_points))
+            .and_then(|amount| amount.checked_div(Decimal::from(10_000)))
+            .ok_or_else(|| self.out_of_range())?;
+        Ok(Money {
+            amount: amount.normalize(),
+            currency: self.currency.clone(),
+        })
+    }
+
+    /// Parcela do valor correspondente a `units` de `total` cotas, na mesma
+    /// moeda. Multiplica antes de dividir para não acumular arredondamentos;
+    /// se o produto não couber em um `Decimal`, divide antes, arredondando o
+    /// quociente para a precisão do `Decimal` (28 dígitos significativos).
+    pub fn share(&self, units: u64, total: u64) -> Result<Money, String> {
+        let (units, total) = (Decimal::from(units), Decimal::from(total));
+        let amount = match self.amount.checked_mul(units) {
+            Some(product) => product.checked_div(total),
+            None => self
+                .amount
+                .checked_div(total)
+                .and_then(|quotient| quotient.checked_mul(units)),
+        }
+        .ok_or_else(|| self.out_of_range())?;
+        Ok(Money {
+            amount: amount.normalize(),
+            currency: self.currency.clone(),
+        })
+    }
+
+    fn out_of_range(&self) -> String {
+        format!("Valor fora do intervalo suportado: {}", self)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount.normalize(), self.currency)
+    }
+}
+
+impl FromStr for Money {
+    type Err = String;
+
+    /// Aceita `VALOR MOEDA`, como `2.5 ETH`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [amount, currency] => Money::parse(amount, currency),
+            _ => Err(format!(
+                "Valor inválido: '{}' (esperado VALOR MOEDA)",
+                s.trim()
+            )),
+        }
+    }
+}
+
+/// Soma o valor aos totais por moeda, mantidos em ordem de moeda. Falha se
+/// o total exceder o intervalo suportado.
+pub fn accumulate(totals: &mut Vec<Money>, value: &Money) -> Result<(), String> {
+    match totals.binary_search_by(|t| t.currency.cmp(&value.currency)) {
+        Ok(index) => {
+            let total = &mut totals[index];
+            total.amount = total
+                .amount
+                .checked_add(value.amount)
+                .ok_or_else(|| total.out_of_range())?
+                .normalize();
+        }
+        Err(index) => totals.insert(index, value.clone()),
+    }
+    Ok(())
+}
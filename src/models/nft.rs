@@ -1,13 +1,33 @@
 use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use crate::models::category::Category;
+use crate::models::category::{category_key, Category, CategoryPath, PATH_SEPARATOR};
+use crate::models::collection::{Collection, Royalty};
+use crate::models::metadata::NftMetadata;
+use crate::models::shares::Shares;
+use crate::models::wallet::WalletAddress;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NFT {
     pub token_id: String,      // Campo string
     pub owner_id: u64,         // Campo numérico
     pub creation_date: NaiveDate,  // Campo data
-    pub category: Category     // Campo enum
+    pub category: Category,    // Campo enum
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subcategories: Vec<String>, // Subcategorias, da mais geral à mais específica
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>, // Marcadores livres
+    #[serde(default, skip_serializing_if = "NftMetadata::is_empty")]
+    pub metadata: NftMetadata, // Metadados opcionais (nome, descrição, URIs, atributos)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<String>, // Coleção à qual o NFT pertence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_address: Option<WalletAddress>, // Carteira do proprietário
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub royalty: Option<Royalty>, // Royalty próprio; sem ele, vale o da coleção
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shares: Option<Shares>, // Cotas, se a propriedade for compartilhada
 }
 
 impl NFT {
@@ -22,9 +42,116 @@ impl NFT {
             owner_id,
             creation_date,
             category,
+            subcategories: Vec::new(),
+            tags: Vec::new(),
+            metadata: NftMetadata::default(),
+            collection_id: None,
+            owner_address: None,
+            royalty: None,
+            shares: None,
         }
     }
 
+    pub fn with_metadata(mut self, metadata: NftMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Royalty aplicado nas vendas: o do próprio NFT ou, sem ele, o da coleção.
+    pub fn effective_royalty(&self, collections: &[Collection]) -> Option<Royalty> {
+        self.royalty.clone().or_else(|| {
+            let collection_id = self.collection_id.as_ref()?;
+            collections
+                .iter()
+                .find(|c| &c.id == collection_id)
+                .and_then(|c| c.royalty.clone())
+        })
+    }
+
+    /// Transfere o NFT inteiro ao novo proprietário. NFTs divididos em cotas
+    /// só mudam de mãos com `share transfer`, que move as cotas. O endereço
+    /// da carteira era do proprietário anterior e é removido.
+    pub fn transfer_to(&mut self, owner_id: u64) -> Result<(), String> {
+        if owner_id == self.owner_id {
+            return Ok(());
+        }
+        if self.shares.is_some() {
+            return Err(format!(
+                "NFT '{}' está dividido em cotas; use 'share transfer' para transferir as cotas",
+                self.token_id
+            ));
+        }
+        self.owner_id = owner_id;
+        self.owner_address = None;
+        Ok(())
+    }
+
+    /// Fração do NFT que pertence ao proprietário: a das suas cotas ou, sem
+    /// cotas, 1 para o proprietário e `None` para os demais.
+    pub fn share_of(&self, owner_id: u64) -> Option<Decimal> {
+        match &self.shares {
+            Some(shares) if shares.units_of(owner_id) > 0 => Some(shares.fraction_of(owner_id)),
+            Some(_) => None,
+            None => (self.owner_id == owner_id).then_some(Decimal::ONE),
+        }
+    }
+
+    pub fn category_path(&self) -> CategoryPath {
+        CategoryPath::new(self.category.clone(), self.subcategories.clone())
+    }
+
+    pub fn set_category_path(&mut self, path: CategoryPath) {
+        self.category = path.category;
+        self.subcategories = path.subcategories;
+    }
+
+    /// Corrige a descrição de `Other` gravada antes de `Category::validate`:
+    /// tira os espaços das pontas e transforma o que vem depois de '/' em
+    /// subcategorias. Retorna `true` se a categoria mudou.
+    pub fn normalize_category(&mut self) -> bool {
+        let description = match &self.category {
+            Category::Other(description) if self.category.validate().is_err() => description,
+            _ => return false,
+        };
+        let mut segments: Vec<String> = description
+            .split(PATH_SEPARATOR)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect();
+        if segments.is_empty() {
+            return false;
+        }
+        let first = segments.remove(0);
+        segments.append(&mut self.subcategories);
+        self.category = Category::Other(first);
+        self.subcategories = segments;
+        true
+    }
+
+    /// Verdadeiro se o NFT tem o marcador, sem diferenciar maiúsculas.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let key = category_key(tag);
+        self.tags.iter().any(|t| category_key(t) == key)
+    }
+
+    /// Acrescenta o marcador se ainda não existir. Retorna `false` se já existia.
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if self.has_tag(tag) {
+            return false;
+        }
+        self.tags.push(tag.trim().to_string());
+        true
+    }
+
+    /// Remove o marcador. Retorna `false` se ele não existia.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let key = category_key(tag);
+        let original_len = self.tags.len();
+        self.tags.retain(|t| category_key(t) != key);
+        self.tags.len() < original_len
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         // Validação do token_id
         if self.token_id.trim().is_empty() {
@@ -36,12 +163,55 @@ impl NFT {
             return Err("Owner ID deve ser maior que zero".to_string());
         }
 
+        // Validação do endereço da carteira
+        if let Some(address) = &self.owner_address {
+            address.validate()?;
+        }
+
+        // Validação do royalty
+        if let Some(royalty) = &self.royalty {
+            royalty.validate()?;
+        }
+
+        // Validação das cotas: o proprietário principal é o maior cotista
+        if let Some(shares) = &self.shares {
+            shares.validate()?;
+            if shares.majority_owner() != Some(self.owner_id) {
+                return Err(format!(
+                    "Owner ID {} não é o maior cotista do NFT; use 'share transfer' para NFTs compartilhados",
+                    self.owner_id
+                ));
+            }
+        }
+
         // Validação da data
         let current_date = chrono::Local::now().date_naive();
         if self.creation_date > current_date {
             return Err("Data de criação não pode ser no futuro".to_string());
         }
 
+        // Validação da categoria, das subcategorias e dos marcadores
+        self.category.validate()?;
+        for subcategory in &self.subcategories {
+            if subcategory.trim().is_empty() || subcategory.contains(PATH_SEPARATOR) {
+                return Err(format!("Subcategoria inválida: '{}'", subcategory));
+            }
+        }
+        for (index, tag) in self.tags.iter().enumerate() {
+            if tag.trim().is_empty() {
+                return Err("Marcador não pode ser vazio".to_string());
+            }
+            if self.tags[..index]
+                .iter()
+                .any(|t| category_key(t) == category_key(tag))
+            {
+                return Err(format!("Marcador '{}' repetido", tag));
+            }
+        }
+
+        // Validação dos metadados
+        self.metadata.validate()?;
+
         Ok(())
     }
 }
\ No newline at end of file
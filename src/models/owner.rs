@@ -0,0 +1,46 @@
+use crate::models::wallet::WalletAddress;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Proprietário registrado, identificado pelo mesmo `owner_id` usado nos NFTs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Owner {
+    pub id: u64,
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wallets: Vec<String>, // Endereços no formato `rede:endereço`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl Owner {
+    pub fn new(id: u64, display_name: String) -> Self {
+        Owner {
+            id,
+            display_name,
+            wallets: Vec::new(),
+            contact: None,
+            created_at: chrono::Local::now().naive_local(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id == 0 {
+            return Err("Owner ID deve ser maior que zero".to_string());
+        }
+        if self.display_name.trim().is_empty() {
+            return Err("Nome do proprietário não pode ser vazio".to_string());
+        }
+        for wallet in &self.wallets {
+            WalletAddress::from_str(wallet)?;
+        }
+        if let Some(contact) = &self.contact {
+            if contact.trim().is_empty() {
+                return Err("Contato não pode ser vazio".to_string());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,593 @@
+// src/models/query.rs
+
+use crate::models::category::{CategoryDefinition, CategoryPath};
+use crate::models::nft::NFT;
+use chrono::NaiveDate;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Expressão de seleção de NFTs, como
+/// `category in (art, music) and created < 2023-01-01 and not owner in (1, 2)`.
+///
+/// Condições têm a forma `campo operador valor` e são combinadas com `and`,
+/// `or`, `not` e parênteses (`not` tem a maior precedência e `or` a menor).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Condition { field: Field, test: Test },
+}
+
+/// Campos do NFT disponíveis nas condições.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    TokenId,
+    Owner,
+    /// Categoria com subcategorias; igualdade inclui as subcategorias abaixo.
+    Category,
+    Collection,
+    Created,
+    /// Verdadeiro se algum dos marcadores do NFT atender à condição.
+    Tag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Test {
+    Compare(Comparison, Value),
+    In(Vec<Value>),
+    /// Trecho de texto, sem diferenciar maiúsculas.
+    Contains(String),
+    Matches(Pattern),
+}
+
+/// Valor de uma condição, já convertido para o tipo do campo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(u64),
+    Date(NaiveDate),
+    Category(CategoryPath),
+}
+
+/// Expressão regular de `matches` (ou `~`).
+#[derive(Debug, Clone)]
+pub struct Pattern(pub Regex);
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+/// Erro de interpretação, com a posição (em caracteres, a partir de 1)
+/// onde o problema foi encontrado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl QueryError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        QueryError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Mensagem com a expressão e um `^` sob a posição do erro.
+    pub fn render(&self, source: &str) -> String {
+        format!(
+            "Consulta inválida na posição {}: {}\n  {}\n  {}^",
+            self.position,
+            self.message,
+            source,
+            " ".repeat(self.position.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "posição {}: {}", self.position, self.message)
+    }
+}
+
+impl Field {
+    const NAMES: &'static str = "token_id, owner, category, collection, created ou tag";
+
+    fn named(name: &str) -> Option<Field> {
+        match name.to_lowercase().as_str() {
+            "token_id" | "token" => Some(Field::TokenId),
+            "owner" | "owner_id" => Some(Field::Owner),
+            "category" => Some(Field::Category),
+            "collection" => Some(Field::Collection),
+            "created" | "date" | "creation_date" => Some(Field::Created),
+            "tag" | "tags" => Some(Field::Tag),
+            _ => None,
+        }
+    }
+
+    fn is_ordered(self) -> bool {
+        matches!(self, Field::Owner | Field::Created)
+    }
+
+    fn is_textual(self) -> bool {
+        !self.is_ordered()
+    }
+
+    /// Converte o texto de um valor para o tipo do campo.
+    fn value(self, text: &str, definitions: &[CategoryDefinition]) -> Result<Value, String> {
+        match self {
+            Field::Owner => text
+                .parse::<u64>()
+                .map(Value::Number)
+                .map_err(|_| format!("'{}' não é um Owner ID válido", text)),
+            Field::Created => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .map(Value::Date)
+                .map_err(|_| format!("data inválida '{}' (esperado AAAA-MM-DD)", text)),
+            Field::Category => CategoryPath::resolve(text, definitions).map(Value::Category),
+            Field::TokenId | Field::Collection | Field::Tag => Ok(Value::Text(text.to_string())),
+        }
+    }
+
+    fn equals(self, value: &Value, nft: &NFT) -> bool {
+        match (self, value) {
+            (Field::TokenId, Value::Text(text)) => nft.token_id == *text,
+            (Field::Owner, Value::Number(id)) => nft.owner_id == *id,
+            (Field::Created, Value::Date(date)) => nft.creation_date == *date,
+            (Field::Category, Value::Category(path)) => path.contains(&nft.category_path()),
+            (Field::Collection, Value::Text(text)) => nft.collection_id.as_ref() == Some(text),
+            (Field::Tag, Value::Text(text)) => nft.has_tag(text),
+            _ => false,
+        }
+    }
+
+    fn compare(self, value: &Value, nft: &NFT) -> Option<Ordering> {
+        match (self, value) {
+            (Field::Owner, Value::Number(id)) => Some(nft.owner_id.cmp(id)),
+            (Field::Created, Value::Date(date)) => Some(nft.creation_date.cmp(date)),
+            _ => None,
+        }
+    }
+
+    /// Textos usados por `contains` e `matches`.
+    fn texts(self, nft: &NFT) -> Vec<String> {
+        match self {
+            Field::TokenId => vec![nft.token_id.clone()],
+            Field::Category => vec![nft.category_path().to_string()],
+            Field::Collection => nft.collection_id.iter().cloned().collect(),
+            Field::Tag => nft.tags.clone(),
+            Field::Owner | Field::Created => Vec::new(),
+        }
+    }
+}
+
+impl Comparison {
+    fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Eq => ordering == Ordering::Equal,
+            Comparison::Ne => ordering != Ordering::Equal,
+            Comparison::Lt => ordering == Ordering::Less,
+            Comparison::Le => ordering != Ordering::Greater,
+            Comparison::Gt => ordering == Ordering::Greater,
+            Comparison::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+impl Test {
+    fn evaluate(&self, field: Field, nft: &NFT) -> bool {
+        match self {
+            Test::Compare(Comparison::Eq, value) => field.equals(value, nft),
+            Test::Compare(Comparison::Ne, value) => !field.equals(value, nft),
+            Test::Compare(comparison, value) => field
+                .compare(value, nft)
+                .is_some_and(|ordering| comparison.accepts(ordering)),
+            Test::In(values) => values.iter().any(|value| field.equals(value, nft)),
+            Test::Contains(text) => {
+                let text = text.to_lowercase();
+                field
+                    .texts(nft)
+                    .iter()
+                    .any(|t| t.to_lowercase().contains(&text))
+            }
+            Test::Matches(pattern) => field.texts(nft).iter().any(|t| pattern.0.is_match(t)),
+        }
+    }
+}
+
+impl Query {
+    pub fn matches(&self, nft: &NFT) -> bool {
+        match self {
+            Query::And(left, right) => left.matches(nft) && right.matches(nft),
+            Query::Or(left, right) => left.matches(nft) || right.matches(nft),
+            Query::Not(query) => !query.matches(nft),
+            Query::Condition { field, test } => test.evaluate(*field, nft),
+        }
+    }
+}
+
+impl Query {
+    /// Interpreta a consulta resolvendo as categorias com os nomes e apelidos
+    /// de `definitions`, as categorias definidas no banco consultado.
+    pub fn parse(source: &str, definitions: &[CategoryDefinition]) -> Result<Query, QueryError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            index: 0,
+            definitions,
+            depth: 0,
+        };
+        if parser.peek().kind == TokenKind::End {
+            return Err(QueryError::new(1, "consulta vazia"));
+        }
+        let query = parser.parse_or()?;
+        let token = parser.peek();
+        if token.kind != TokenKind::End {
+            return Err(QueryError::new(
+                token.position,
+                format!("'{}' inesperado", token.text),
+            ));
+        }
+        Ok(query)
+    }
+}
+
+/// Interpreta só as categorias predefinidas; veja [`Query::parse`].
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Query::parse(s, &[])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word,
+    Quoted,
+    Operator,
+    Open,
+    Close,
+    Comma,
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    position: usize,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn describe(&self) -> String {
+        match self.kind {
+            TokenKind::End => "fim da consulta".to_string(),
+            _ => format!("'{}'", self.text),
+        }
+    }
+}
+
+/// Divide a expressão em palavras, textos entre aspas, operadores,
+/// parênteses e vírgulas.
+fn tokenize(source: &str) -> Result<Vec<Token>, QueryError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i + 1;
+        let token = |kind, text: &str| Token {
+            kind,
+            text: text.to_string(),
+            position,
+        };
+        match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => tokens.push(token(TokenKind::Open, "(")),
+            ')' => tokens.push(token(TokenKind::Close, ")")),
+            ',' => tokens.push(token(TokenKind::Comma, ",")),
+            '=' | '~' => tokens.push(token(TokenKind::Operator, &c.to_string())),
+            '!' | '<' | '>' => {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(token(TokenKind::Operator, &format!("{}=", c)));
+                    i += 1;
+                } else if c == '!' {
+                    return Err(QueryError::new(position, "esperado '!='"));
+                } else {
+                    tokens.push(token(TokenKind::Operator, &c.to_string()));
+                }
+            }
+            '\'' | '"' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&ch| ch == c)
+                    .ok_or_else(|| QueryError::new(position, "aspas sem fechamento"))?;
+                let text: String = chars[i + 1..i + 1 + end].iter().collect();
+                tokens.push(token(TokenKind::Quoted, &text));
+                i += end + 1;
+            }
+            _ => {
+                let length = chars[i..]
+                    .iter()
+                    .position(|&ch| ch.is_whitespace() || "()=,~!<>'\"".contains(ch))
+                    .unwrap_or(chars.len() - i);
+                let text: String = chars[i..i + length].iter().collect();
+                tokens.push(token(TokenKind::Word, &text));
+                i += length;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    tokens.push(Token {
+        kind: TokenKind::End,
+        text: String::new(),
+        position: chars.len() + 1,
+    });
+    Ok(tokens)
+}
+
+/// Analisador descendente recursivo:
+///
+/// ```text
+/// or        := and ("or" and)*
+/// and       := not ("and" not)*
+/// not       := "not" not | "(" or ")" | condição
+/// condição  := campo (operador valor | ["not"] "in" lista | "contains" valor
+///              | ("matches" | "~") valor)
+/// ```
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    index: usize,
+    definitions: &'a [CategoryDefinition],
+    /// Níveis de `and`, `or`, `not` e parênteses em aberto.
+    depth: usize,
+}
+
+/// Limite de níveis de uma consulta, para que a análise e a avaliação
+/// recursivas não esgotem a pilha.
+const MAX_DEPTH: usize = 64;
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+        if token.kind != TokenKind::End {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Result<Token, QueryError> {
+        let token = self.next();
+        if token.kind == kind {
+            Ok(token)
+        } else {
+            Err(QueryError::new(
+                token.position,
+                format!("esperado {}, encontrado {}", expected, token.describe()),
+            ))
+        }
+    }
+
+    /// Abre um nível no token seguinte, que é consumido.
+    fn enter(&mut self) -> Result<Token, QueryError> {
+        let token = self.next();
+        if self.depth == MAX_DEPTH {
+            return Err(QueryError::new(
+                token.position,
+                format!(
+                    "consulta complexa demais (máximo de {} níveis de and, or, not e parênteses)",
+                    MAX_DEPTH
+                ),
+            ));
+        }
+        self.depth += 1;
+        Ok(token)
+    }
+
+    /// Cada `or` da sequência é um nível, já que forma uma árvore encadeada.
+    fn parse_or(&mut self) -> Result<Query, QueryError> {
+        let depth = self.depth;
+        let mut query = self.parse_and()?;
+        while self.peek().is_keyword("or") {
+            self.enter()?;
+            query = Query::Or(Box::new(query), Box::new(self.parse_and()?));
+        }
+        self.depth = depth;
+        Ok(query)
+    }
+
+    fn parse_and(&mut self) -> Result<Query, QueryError> {
+        let depth = self.depth;
+        let mut query = self.parse_not()?;
+        while self.peek().is_keyword("and") {
+            self.enter()?;
+            query = Query::And(Box::new(query), Box::new(self.parse_not()?));
+        }
+        self.depth = depth;
+        Ok(query)
+    }
+
+    fn parse_not(&mut self) -> Result<Query, QueryError> {
+        if self.peek().is_keyword("not") {
+            self.enter()?;
+            let query = Query::Not(Box::new(self.parse_not()?));
+            self.depth -= 1;
+            return Ok(query);
+        }
+        if self.peek().kind == TokenKind::Open {
+            self.enter()?;
+            let query = self.parse_or()?;
+            self.expect(TokenKind::Close, "')'")?;
+            self.depth -= 1;
+            return Ok(query);
+        }
+        self.parse_condition()
+    }
+
+    fn parse_condition(&mut self) -> Result<Query, QueryError> {
+        let name = self.expect(TokenKind::Word, "um campo")?;
+        let field = Field::named(&name.text).ok_or_else(|| {
+            QueryError::new(
+                name.position,
+                format!(
+                    "campo '{}' desconhecido (esperado {})",
+                    name.text,
+                    Field::NAMES
+                ),
+            )
+        })?;
+
+        let operator = self.next();
+        let test = match operator.kind {
+            TokenKind::Operator if operator.text == "~" => {
+                self.parse_pattern(field, &name, &operator)?
+            }
+            TokenKind::Operator => {
+                let comparison = match operator.text.as_str() {
+                    "=" => Comparison::Eq,
+                    "!=" => Comparison::Ne,
+                    "<" => Comparison::Lt,
+                    "<=" => Comparison::Le,
+                    ">" => Comparison::Gt,
+                    _ => Comparison::Ge,
+                };
+                if !matches!(comparison, Comparison::Eq | Comparison::Ne) && !field.is_ordered() {
+                    return Err(QueryError::new(
+                        operator.position,
+                        format!("'{}' não se aplica ao campo '{}'", operator.text, name.text),
+                    ));
+                }
+                Test::Compare(comparison, self.parse_value(field)?)
+            }
+            TokenKind::Word if operator.is_keyword("in") => Test::In(self.parse_list(field)?),
+            TokenKind::Word if operator.is_keyword("not") => {
+                let keyword = self.next();
+                if !keyword.is_keyword("in") {
+                    return Err(QueryError::new(
+                        keyword.position,
+                        format!(
+                            "esperado 'in' após 'not', encontrado {}",
+                            keyword.describe()
+                        ),
+                    ));
+                }
+                let test = Test::In(self.parse_list(field)?);
+                return Ok(Query::Not(Box::new(Query::Condition { field, test })));
+            }
+            TokenKind::Word if operator.is_keyword("contains") => {
+                self.check_textual(field, &name, &operator)?;
+                Test::Contains(self.parse_text()?.text)
+            }
+            TokenKind::Word if operator.is_keyword("matches") => {
+                self.parse_pattern(field, &name, &operator)?
+            }
+            _ => return Err(QueryError::new(
+                operator.position,
+                format!(
+                    "esperado operador após '{}' (=, !=, <, <=, >, >=, in, contains ou matches), \
+                         encontrado {}",
+                    name.text,
+                    operator.describe()
+                ),
+            )),
+        };
+        Ok(Query::Condition { field, test })
+    }
+
+    fn check_textual(
+        &self,
+        field: Field,
+        name: &Token,
+        operator: &Token,
+    ) -> Result<(), QueryError> {
+        if field.is_textual() {
+            Ok(())
+        } else {
+            Err(QueryError::new(
+                operator.position,
+                format!("'{}' não se aplica ao campo '{}'", operator.text, name.text),
+            ))
+        }
+    }
+
+    fn parse_pattern(
+        &mut self,
+        field: Field,
+        name: &Token,
+        operator: &Token,
+    ) -> Result<Test, QueryError> {
+        self.check_textual(field, name, operator)?;
+        let text = self.parse_text()?;
+        Regex::new(&text.text)
+            .map(|regex| Test::Matches(Pattern(regex)))
+            .map_err(|_| {
+                QueryError::new(
+                    text.position,
+                    format!("expressão regular inválida '{}'", text.text),
+                )
+            })
+    }
+
+    fn parse_text(&mut self) -> Result<Token, QueryError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Word | TokenKind::Quoted => Ok(token),
+            _ => Err(QueryError::new(
+                token.position,
+                format!("esperado valor, encontrado {}", token.describe()),
+            )),
+        }
+    }
+
+    fn parse_value(&mut self, field: Field) -> Result<Value, QueryError> {
+        let token = self.parse_text()?;
+        field
+            .value(&token.text, self.definitions)
+            .map_err(|e| QueryError::new(token.position, e))
+    }
+
+    fn parse_list(&mut self, field: Field) -> Result<Vec<Value>, QueryError> {
+        self.expect(TokenKind::Open, "'(' após 'in'")?;
+        let mut values = vec![self.parse_value(field)?];
+        loop {
+            let token = self.next();
+            match token.kind {
+                TokenKind::Comma => values.push(self.parse_value(field)?),
+                TokenKind::Close => return Ok(values),
+                _ => {
+                    return Err(QueryError::new(
+                        token.position,
+                        format!("esperado ',' ou ')', encontrado {}", token.describe()),
+                    ))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::models::collection::Royalty;
+use crate::models::money::Money;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Venda de um NFT no mercado secundário. Guarda o royalty vigente na data
+/// da venda, para que alterações posteriores não mudem o valor devido.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Sale {
+    pub token_id: String,
+    pub seller_id: u64,
+    pub buyer_id: u64,
+    pub price: Money,
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub royalty: Option<Royalty>,
+}
+
+impl Sale {
+    /// Royalty devido ao beneficiário, na moeda da venda.
+    pub fn royalty_due(&self) -> Result<Option<Money>, String> {
+        self.royalty
+            .as_ref()
+            .map(|royalty| self.price.basis_points(royalty.basis_points))
+            .transpose()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.seller_id == self.buyer_id {
+            return Err("Comprador já é o proprietário do NFT".to_string());
+        }
+        if self.buyer_id == 0 {
+            return Err("Comprador deve ser maior que zero".to_string());
+        }
+        if self.date > chrono::Local::now().date_naive() {
+            return Err("Data da venda não pode ser no futuro".to_string());
+        }
+        self.price.validate()?;
+        if let Some(royalty) = &self.royalty {
+            royalty.validate()?;
+        }
+        self.royalty_due()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Regras de validação persistidas junto ao banco de dados.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Settings {
+    /// Exige que transferências tenham como destino um proprietário registrado.
+    #[serde(default)]
+    pub require_known_owners: bool,
+}
@@ -0,0 +1,139 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Cotas de um proprietário em um NFT de propriedade compartilhada.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShareHolding {
+    pub owner_id: u64,
+    pub units: u64,
+}
+
+/// Propriedade fracionada: o NFT é dividido em `total` cotas, distribuídas
+/// entre os proprietários em `holders` (em ordem de Owner ID).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Shares {
+    pub total: u64,
+    pub holders: Vec<ShareHolding>,
+}
+
+impl Shares {
+    /// Divide o NFT em `total` cotas, todas do proprietário atual.
+    pub fn whole(owner_id: u64, total: u64) -> Shares {
+        Shares {
+            total,
+            holders: vec![ShareHolding {
+                owner_id,
+                units: total,
+            }],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.total == 0 {
+            return Err("Total de cotas deve ser maior que zero".to_string());
+        }
+        for (index, holding) in self.holders.iter().enumerate() {
+            if holding.owner_id == 0 {
+                return Err("Owner ID deve ser maior que zero".to_string());
+            }
+            if holding.units == 0 {
+                return Err(format!(
+                    "Owner ID {} não pode ter zero cotas",
+                    holding.owner_id
+                ));
+            }
+            if self.holders[..index]
+                .iter()
+                .any(|h| h.owner_id == holding.owner_id)
+            {
+                return Err(format!(
+                    "Owner ID {} aparece mais de uma vez nas cotas",
+                    holding.owner_id
+                ));
+            }
+        }
+        let sum = self
+            .holders
+            .iter()
+            .try_fold(0u64, |sum, h| sum.checked_add(h.units));
+        if sum != Some(self.total) {
+            return Err(format!(
+                "Soma das cotas ({}) difere do total ({})",
+                sum.map_or_else(|| "estouro".to_string(), |s| s.to_string()),
+                self.total
+            ));
+        }
+        Ok(())
+    }
+
+    /// Cotas do proprietário (zero se não tiver nenhuma).
+    pub fn units_of(&self, owner_id: u64) -> u64 {
+        self.holders
+            .iter()
+            .find(|h| h.owner_id == owner_id)
+            .map_or(0, |h| h.units)
+    }
+
+    /// Fração do NFT que pertence ao proprietário, entre 0 e 1.
+    pub fn fraction_of(&self, owner_id: u64) -> Decimal {
+        if self.total == 0 {
+            return Decimal::ZERO;
+        }
+        (Decimal::from(self.units_of(owner_id)) / Decimal::from(self.total)).normalize()
+    }
+
+    /// Maior cotista; em caso de empate, o de menor Owner ID.
+    pub fn majority_owner(&self) -> Option<u64> {
+        self.holders
+            .iter()
+            .max_by(|a, b| a.units.cmp(&b.units).then(b.owner_id.cmp(&a.owner_id)))
+            .map(|h| h.owner_id)
+    }
+
+    /// Transfere `units` cotas de `from` para `to`. Quem fica sem cotas sai
+    /// da lista; o vendedor nunca fica com saldo negativo.
+    pub fn transfer(&mut self, from: u64, to: u64, units: u64) -> Result<(), String> {
+        if units == 0 {
+            return Err("Quantidade de cotas deve ser maior que zero".to_string());
+        }
+        if from == to {
+            return Err("Origem e destino da transferência são o mesmo proprietário".to_string());
+        }
+        if to == 0 {
+            return Err("Owner ID deve ser maior que zero".to_string());
+        }
+        let available = self.units_of(from);
+        if available < units {
+            return Err(format!(
+                "Owner ID {} possui apenas {} cota(s); não é possível transferir {}",
+                from, available, units
+            ));
+        }
+
+        for holding in self.holders.iter_mut().filter(|h| h.owner_id == from) {
+            holding.units -= units;
+        }
+        match self.holders.iter_mut().find(|h| h.owner_id == to) {
+            Some(holding) => holding.units += units,
+            None => self.holders.push(ShareHolding {
+                owner_id: to,
+                units,
+            }),
+        }
+        self.holders.retain(|h| h.units > 0);
+        self.holders.sort_by_key(|h| h.owner_id);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Shares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let holders: Vec<String> = self
+            .holders
+            .iter()
+            .map(|h| format!("Owner ID {} ({}/{})", h.owner_id, h.units, self.total))
+            .collect();
+        write!(f, "{}", holders.join(", "))
+    }
+}
@@ -0,0 +1,164 @@
+use crate::models::nft::NFT;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Marcador do contador nos modelos (`ART-{n}` ou `ART-{n:04}`).
+const COUNTER_PLACEHOLDER: &str = "{n";
+
+/// Estratégia de geração de Token IDs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TokenIdStrategy {
+    /// UUID aleatório (versão 4).
+    #[default]
+    UuidV4,
+    /// UUID ordenado pelo horário de criação (versão 7).
+    UuidV7,
+    /// Contador por coleção: `<coleção>-1`, `<coleção>-2`...; `1`, `2`... sem coleção.
+    Sequential,
+    /// Prefixo e sufixo fixos com contador, como `ART-{n:04}`.
+    Template(String),
+}
+
+impl fmt::Display for TokenIdStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenIdStrategy::UuidV4 => write!(f, "uuid4"),
+            TokenIdStrategy::UuidV7 => write!(f, "uuid7"),
+            TokenIdStrategy::Sequential => write!(f, "sequential"),
+            TokenIdStrategy::Template(template) => write!(f, "template:{}", template),
+        }
+    }
+}
+
+impl FromStr for TokenIdStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "uuid4" => Ok(TokenIdStrategy::UuidV4),
+            "uuid7" => Ok(TokenIdStrategy::UuidV7),
+            "sequential" => Ok(TokenIdStrategy::Sequential),
+            other => match other.strip_prefix("template:") {
+                Some(template) => {
+                    Template::parse(template)?;
+                    Ok(TokenIdStrategy::Template(template.to_string()))
+                }
+                None => Err(format!(
+                    "Estratégia de Token ID '{}' inválida (esperado uuid4, uuid7, sequential ou template:PREFIXO{{n}})",
+                    other
+                )),
+            },
+        }
+    }
+}
+
+/// Modelo decomposto em prefixo, largura mínima do contador e sufixo.
+struct Template<'a> {
+    prefix: &'a str,
+    width: usize,
+    suffix: &'a str,
+}
+
+impl<'a> Template<'a> {
+    fn parse(template: &'a str) -> Result<Template<'a>, String> {
+        let invalid = || format!("Modelo '{}' inválido: use {{n}} ou {{n:04}}", template);
+        let start = template.find(COUNTER_PLACEHOLDER).ok_or_else(invalid)?;
+        let end = start + template[start..].find('}').ok_or_else(invalid)?;
+        let width = match &template[start + COUNTER_PLACEHOLDER.len()..end] {
+            "" => 0,
+            spec => spec
+                .strip_prefix(':')
+                .and_then(|width| width.parse().ok())
+                .ok_or_else(invalid)?,
+        };
+        let suffix = &template[end + 1..];
+        if suffix.contains(COUNTER_PLACEHOLDER) {
+            return Err(invalid());
+        }
+        Ok(Template {
+            prefix: &template[..start],
+            width,
+            suffix,
+        })
+    }
+
+    fn format(&self, counter: u64) -> String {
+        format!(
+            "{}{:0width$}{}",
+            self.prefix,
+            counter,
+            self.suffix,
+            width = self.width
+        )
+    }
+
+    /// Contador de um Token ID gerado por este modelo.
+    fn counter(&self, token_id: &str) -> Option<u64> {
+        let digits = token_id
+            .strip_prefix(self.prefix)?
+            .strip_suffix(self.suffix)?;
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    }
+}
+
+impl TokenIdStrategy {
+    /// Gera um Token ID que não existe entre `existing`. `collection_id`
+    /// define o contador da estratégia sequencial.
+    pub fn generate(
+        &self,
+        existing: &[NFT],
+        collection_id: Option<&str>,
+    ) -> Result<String, String> {
+        let taken = |token_id: &str| existing.iter().any(|nft| nft.token_id == token_id);
+        match self {
+            TokenIdStrategy::UuidV4 | TokenIdStrategy::UuidV7 => loop {
+                let uuid = match self {
+                    TokenIdStrategy::UuidV7 => Uuid::now_v7(),
+                    _ => Uuid::new_v4(),
+                };
+                let token_id = uuid.to_string();
+                if !taken(&token_id) {
+                    break Ok(token_id);
+                }
+            },
+            TokenIdStrategy::Sequential => {
+                // O Id da coleção entra como prefixo literal, sem passar pelo
+                // interpretador de modelos
+                let prefix = collection_id
+                    .map(|id| format!("{}-", id))
+                    .unwrap_or_default();
+                let template = Template {
+                    prefix: &prefix,
+                    width: 0,
+                    suffix: "",
+                };
+                next_from_template(&template, existing)
+            }
+            TokenIdStrategy::Template(template) => {
+                next_from_template(&Template::parse(template)?, existing)
+            }
+        }
+    }
+}
+
+/// Próximo valor do contador após o maior já usado, pulando Token IDs ocupados.
+fn next_from_template(template: &Template, existing: &[NFT]) -> Result<String, String> {
+    let mut counter = existing
+        .iter()
+        .filter_map(|nft| template.counter(&nft.token_id))
+        .max()
+        .unwrap_or(0);
+    loop {
+        counter = counter
+            .checked_add(1)
+            .ok_or("Contador de Token IDs esgotado")?;
+        let token_id = template.format(counter);
+        if !existing.iter().any(|nft| nft.token_id == token_id) {
+            break Ok(token_id);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::models::money::Money;
+use crate::models::sale::Sale;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Avaliação de um NFT em uma data, feita por uma fonte (avaliador,
+/// marketplace, oráculo de preços...).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Valuation {
+    pub token_id: String,
+    pub value: Money,
+    pub date: NaiveDate,
+    pub source: String,
+}
+
+impl Valuation {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.source.trim().is_empty() {
+            return Err("Fonte da avaliação não pode ser vazia".to_string());
+        }
+        if self.date > chrono::Local::now().date_naive() {
+            return Err("Data da avaliação não pode ser no futuro".to_string());
+        }
+        self.value.validate()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum PriceKind {
+    Appraisal,
+    Sale,
+}
+
+impl fmt::Display for PriceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceKind::Appraisal => write!(f, "Avaliação"),
+            PriceKind::Sale => write!(f, "Venda"),
+        }
+    }
+}
+
+/// Ponto do histórico de preços de um NFT: uma avaliação ou uma venda.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PricePoint {
+    pub date: NaiveDate,
+    pub value: Money,
+    pub kind: PriceKind,
+    pub source: String,
+}
+
+/// Avaliações e vendas do NFT em ordem de data. No mesmo dia, a venda vem
+/// depois das avaliações e passa a ser o valor mais recente.
+pub fn price_history(token_id: &str, valuations: &[Valuation], sales: &[Sale]) -> Vec<PricePoint> {
+    let appraisals = valuations
+        .iter()
+        .filter(|v| v.token_id == token_id)
+        .map(|v| PricePoint {
+            date: v.date,
+            value: v.value.clone(),
+            kind: PriceKind::Appraisal,
+            source: v.source.clone(),
+        });
+    let sales = sales
+        .iter()
+        .filter(|s| s.token_id == token_id)
+        .map(|s| PricePoint {
+            date: s.date,
+            value: s.price.clone(),
+            kind: PriceKind::Sale,
+            source: format!("Owner ID {} -> {}", s.seller_id, s.buyer_id),
+        });
+    let mut history: Vec<PricePoint> = appraisals.chain(sales).collect();
+    history.sort_by_key(|point| (point.date, point.kind == PriceKind::Sale));
+    history
+}
+
+/// Valor mais recente do histórico até a data (inclusive).
+pub fn value_as_of(history: &[PricePoint], date: NaiveDate) -> Option<&PricePoint> {
+    history.iter().rev().find(|point| point.date <= date)
+}
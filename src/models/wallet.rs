@@ -0,0 +1,260 @@
+use crate::models::collection::Chain;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Endereço de carteira associado a uma rede, no formato `rede:endereço`.
+/// Sem prefixo, a rede é deduzida do formato (`0x…`, `bc1…`/`tb1…` ou base58).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WalletAddress {
+    pub chain: Chain,
+    pub address: String,
+}
+
+impl WalletAddress {
+    /// Valida o endereço segundo as regras da rede, indicando a verificação que falhou.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.chain {
+            Chain::Ethereum | Chain::Polygon => validate_ethereum(&self.address),
+            Chain::Solana => validate_solana(&self.address),
+            Chain::Bitcoin => validate_bitcoin(&self.address),
+            Chain::Other(_) => {
+                if self.address.trim().is_empty() || self.address.chars().any(char::is_whitespace)
+                {
+                    Err("Endereço não pode ser vazio nem conter espaços".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        .map_err(|e| format!("Endereço {} inválido: {}", self.chain, e))
+    }
+
+    /// Forma canônica do endereço: checksum EIP-55 para redes EVM e
+    /// minúsculas para bech32. Endereços base58 diferenciam maiúsculas.
+    pub fn normalized(&self) -> WalletAddress {
+        let address = match self.chain {
+            Chain::Ethereum | Chain::Polygon => match self.address.strip_prefix("0x") {
+                Some(hex) => eip55_checksum(hex),
+                None => self.address.clone(),
+            },
+            Chain::Bitcoin => self.address.to_lowercase(),
+            _ => self.address.clone(),
+        };
+        WalletAddress {
+            chain: self.chain.clone(),
+            address,
+        }
+    }
+}
+
+impl fmt::Display for WalletAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chain.to_string().to_lowercase(), self.address)
+    }
+}
+
+/// Interpreta, valida e normaliza um endereço informado pelo usuário.
+impl FromStr for WalletAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (chain, address) = match s.split_once(':') {
+            Some((chain, address)) => (Chain::from_str(chain)?, address.trim()),
+            None => (infer_chain(s), s),
+        };
+        let wallet = WalletAddress {
+            chain,
+            address: address.to_string(),
+        };
+        wallet.validate()?;
+        Ok(wallet.normalized())
+    }
+}
+
+fn infer_chain(address: &str) -> Chain {
+    let lower = address.to_lowercase();
+    if lower.starts_with("0x") {
+        Chain::Ethereum
+    } else if lower.starts_with("bc1") || lower.starts_with("tb1") {
+        Chain::Bitcoin
+    } else {
+        Chain::Solana
+    }
+}
+
+fn validate_ethereum(address: &str) -> Result<(), String> {
+    let hex = address
+        .strip_prefix("0x")
+        .ok_or("deve começar com 0x")?;
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("deve conter 40 dígitos hexadecimais após 0x".to_string());
+    }
+
+    // Endereços só em minúsculas ou só em maiúsculas não carregam checksum
+    let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper && eip55_checksum(hex) != address {
+        return Err("checksum EIP-55 não confere".to_string());
+    }
+    Ok(())
+}
+
+/// Aplica o checksum EIP-55 aos 40 dígitos hexadecimais do endereço.
+fn eip55_checksum(hex: &str) -> String {
+    let lower = hex.to_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+fn validate_solana(address: &str) -> Result<(), String> {
+    let bytes = base58_decode(address).ok_or("caractere fora do alfabeto base58")?;
+    if bytes.len() != 32 {
+        return Err(format!(
+            "chave pública deve ter 32 bytes (encontrados {})",
+            bytes.len()
+        ));
+    }
+    Ok(())
+}
+
+fn base58_decode(input: &str) -> Option<Vec<u8>> {
+    if input.is_empty() {
+        return None;
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in input.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Cada '1' inicial representa um byte zero
+    let leading_zeros = input.bytes().take_while(|&c| c == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+    Some(decoded)
+}
+
+fn validate_bitcoin(address: &str) -> Result<(), String> {
+    if address.chars().any(|c| c.is_ascii_lowercase())
+        && address.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err("bech32 não admite maiúsculas e minúsculas misturadas".to_string());
+    }
+    let address = address.to_lowercase();
+    if address.len() > 90 {
+        return Err("bech32 admite no máximo 90 caracteres".to_string());
+    }
+
+    let (hrp, data) = address.rsplit_once('1').ok_or("separador '1' ausente")?;
+    if hrp != "bc" && hrp != "tb" {
+        return Err(format!("prefixo '{}' desconhecido (esperado bc ou tb)", hrp));
+    }
+    if data.len() < 7 {
+        return Err("parte de dados curta demais".to_string());
+    }
+    let values: Vec<u8> = data
+        .bytes()
+        .map(|c| BECH32_CHARSET.iter().position(|&a| a == c).map(|v| v as u8))
+        .collect::<Option<_>>()
+        .ok_or("caractere fora do alfabeto bech32")?;
+
+    let witness_version = values[0];
+    let expected_const = if witness_version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    };
+    if bech32_polymod(hrp, &values) != expected_const {
+        return Err("checksum bech32 não confere".to_string());
+    }
+
+    if witness_version > 16 {
+        return Err("versão de witness inválida".to_string());
+    }
+    let program = convert_bits(&values[1..values.len() - 6], 5, 8)
+        .ok_or("programa de witness com preenchimento inválido")?;
+    let valid_length = match witness_version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len()),
+    };
+    if !valid_length {
+        return Err(format!(
+            "programa de witness com tamanho inválido ({} bytes)",
+            program.len()
+        ));
+    }
+    Ok(())
+}
+
+fn bech32_polymod(hrp: &str, values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+
+    let expanded = hrp
+        .bytes()
+        .map(|c| c >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 31))
+        .chain(values.iter().copied());
+
+    let mut checksum: u32 = 1;
+    for value in expanded {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn convert_bits(data: &[u8], from: u32, to: u32) -> Option<Vec<u8>> {
+    let mut accumulator: u32 = 0;
+    let mut bits: u32 = 0;
+    let max_value = (1 << to) - 1;
+    let mut result = Vec::new();
+
+    for &value in data {
+        accumulator = (accumulator << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((accumulator >> bits) & max_value) as u8);
+        }
+    }
+
+    if bits >= from || ((accumulator << (to - bits)) & max_value) != 0 {
+        return None;
+    }
+    Some(result)
+}
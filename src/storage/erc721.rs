@@ -0,0 +1,149 @@
+use crate::models::category::{CategoryDefinition, CategoryPath};
+use crate::models::metadata::{Attribute, AttributeValue, NftMetadata};
+use crate::models::nft::NFT;
+use crate::storage::file_storage::StorageError;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// Atributo usado por padrão para mapear a categoria do NFT.
+pub const DEFAULT_CATEGORY_ATTRIBUTE: &str = "Category";
+
+/// Parâmetros da importação que não constam no documento de metadados.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportOptions {
+    pub owner_id: u64,
+    pub creation_date: NaiveDate,
+    pub category_attribute: String,
+    pub default_category: Option<CategoryPath>,
+}
+
+/// Documento de metadados no formato ERC-721 / OpenSea.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Erc721Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl Erc721Metadata {
+    /// Gera o documento de um NFT, incluindo a categoria (com as
+    /// subcategorias, como `Art/Generative`) como atributo.
+    pub fn from_nft(nft: &NFT, category_attribute: &str) -> Self {
+        let metadata = nft.metadata.clone();
+        let mut attributes: Vec<Attribute> = metadata
+            .attributes
+            .into_iter()
+            .filter(|a| a.trait_type != category_attribute)
+            .collect();
+        attributes.push(Attribute::new(
+            category_attribute,
+            AttributeValue::Text(nft.category_path().to_string()),
+        ));
+
+        Erc721Metadata {
+            name: metadata.name,
+            description: metadata.description,
+            image: metadata.image,
+            animation_url: metadata.animation_url,
+            external_url: metadata.external_url,
+            attributes,
+        }
+    }
+
+    /// Converte o documento em NFT. O atributo de categoria, se presente,
+    /// define a categoria e as subcategorias e é removido da lista de
+    /// atributos; o valor é resolvido com as categorias definidas no banco
+    /// de destino.
+    pub fn into_nft(
+        self,
+        token_id: &str,
+        options: &ImportOptions,
+        definitions: &[CategoryDefinition],
+    ) -> Result<NFT, String> {
+        let mut category = options.default_category.clone();
+        let mut attributes = Vec::new();
+        for attribute in self.attributes {
+            if attribute.trait_type == options.category_attribute {
+                category = Some(CategoryPath::resolve(
+                    &attribute.value.to_string(),
+                    definitions,
+                )?);
+            } else {
+                attributes.push(attribute);
+            }
+        }
+
+        let metadata = NftMetadata {
+            name: self.name,
+            description: self.description,
+            image: self.image,
+            animation_url: self.animation_url,
+            external_url: self.external_url,
+            attributes,
+        };
+
+        let category = category.ok_or_else(|| {
+            format!(
+                "Atributo de categoria '{}' ausente",
+                options.category_attribute
+            )
+        })?;
+        let mut nft = NFT::new(
+            token_id.to_string(),
+            options.owner_id,
+            options.creation_date,
+            category.category.clone(),
+        )
+        .with_metadata(metadata);
+        nft.set_category_path(category);
+        nft.validate()?;
+        Ok(nft)
+    }
+}
+
+pub fn read_document(path: &Path) -> Result<Erc721Metadata, StorageError> {
+    let file = File::open(path).map_err(StorageError::Io)?;
+    serde_json::from_reader(BufReader::new(file)).map_err(StorageError::Json)
+}
+
+pub fn write_document(path: &Path, document: &Erc721Metadata) -> Result<(), StorageError> {
+    let file = File::create(path).map_err(StorageError::Io)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), document).map_err(StorageError::Json)
+}
+
+/// Lista os arquivos `.json` do diretório em ordem alfabética.
+pub fn list_documents(dir: &Path) -> Result<Vec<PathBuf>, StorageError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(StorageError::Io)? {
+        let path = entry.map_err(StorageError::Io)?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Caminho do documento de um token: `<dir>/<token_id>.json`.
+/// Token IDs que não formam um nome de arquivo simples são recusados.
+pub fn document_path(dir: &Path, token_id: &str) -> Result<PathBuf, String> {
+    if token_id.is_empty() || token_id.starts_with('.') || token_id.contains(['/', '\\']) {
+        return Err(format!(
+            "Token ID '{}' não pode ser usado como nome de arquivo",
+            token_id
+        ));
+    }
+    Ok(dir.join(format!("{}.json", token_id)))
+}
@@ -1,13 +1,40 @@
+use crate::models::category::CategoryDefinition;
+use crate::models::collection::Collection;
 use crate::models::nft::NFT;
+use crate::models::owner::Owner;
+use crate::models::sale::Sale;
+use crate::models::valuation::Valuation;
+use crate::models::settings::Settings;
+use crate::storage::ledger::{duplicate_token_id, Ledger};
+use crate::storage::search_index::SearchIndex;
+use serde::{Deserialize, Serialize};
+use serde_cbor::value::{from_value, Value};
 use serde_cbor::{from_reader, to_writer};
+use std::collections::HashSet;
 use std::fmt;
 use std::fs::OpenOptions;
 use std::io::{BufReader, BufWriter};
 
+/// Versão atual do esquema do arquivo de banco de dados.
+///
+/// - Versão 0: lista CBOR de NFTs, sem envelope.
+/// - Versão 1: envelope com `schema_version`; NFTs com metadados opcionais.
+/// - Versão 2: coleções e referência `collection_id` nos NFTs.
+/// - Versão 3: registro de proprietários e regras de validação.
+/// - Versão 4: registro de categorias definidas pelo usuário.
+/// - Versão 5: subcategorias e marcadores nos NFTs.
+/// - Versão 6: royalty por NFT e registro de vendas.
+/// - Versão 7: avaliações dos NFTs.
+/// - Versão 8: cotas de propriedade compartilhada nos NFTs.
+pub const SCHEMA_VERSION: u32 = 8;
+
 #[derive(Debug)]
 pub enum StorageError {
     Io(std::io::Error),
     Serde(serde_cbor::Error),
+    Json(serde_json::Error),
+    UnsupportedSchema(u32),
+    DuplicateTokenId(String),
 }
 
 impl fmt::Display for StorageError {
@@ -15,6 +42,15 @@ impl fmt::Display for StorageError {
         match self {
             StorageError::Io(e) => write!(f, "Erro de IO: {}", e),
             StorageError::Serde(e) => write!(f, "Erro de Serialização: {}", e),
+            StorageError::Json(e) => write!(f, "Erro de JSON: {}", e),
+            StorageError::UnsupportedSchema(version) => write!(
+                f,
+                "Versão de esquema {} não suportada (máxima: {})",
+                version, SCHEMA_VERSION
+            ),
+            StorageError::DuplicateTokenId(token_id) => {
+                write!(f, "Token ID '{}' repetido entre os NFTs gravados", token_id)
+            }
         }
     }
 }
@@ -24,10 +60,32 @@ impl std::error::Error for StorageError {
         match self {
             StorageError::Io(e) => Some(e),
             StorageError::Serde(e) => Some(e),
+            StorageError::Json(e) => Some(e),
+            StorageError::UnsupportedSchema(_) | StorageError::DuplicateTokenId(_) => None,
         }
     }
 }
 
+/// Conteúdo persistido no arquivo de banco de dados. A versão do esquema é
+/// sempre a atual ao gravar.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Database {
+    schema_version: u32,
+    pub nfts: Vec<NFT>,
+    #[serde(default)]
+    pub collections: Vec<Collection>,
+    #[serde(default)]
+    pub owners: Vec<Owner>,
+    #[serde(default)]
+    pub settings: Settings,
+    #[serde(default)]
+    pub categories: Vec<CategoryDefinition>,
+    #[serde(default)]
+    pub sales: Vec<Sale>,
+    #[serde(default)]
+    pub valuations: Vec<Valuation>,
+}
+
 pub struct FileStorage {
     pub file_path: String,
 }
@@ -46,15 +104,206 @@ impl FileStorage {
     }
 
     pub fn load_all(&mut self) -> Result<Vec<NFT>, StorageError> {
+        Ok(self.load_database()?.nfts)
+    }
+
+    /// Versão do esquema gravada no arquivo, ou `None` se o arquivo não existe.
+    pub fn schema_version(&mut self) -> Result<Option<u32>, StorageError> {
+        match self.read_value()? {
+            Some(value) => Ok(Some(migrate_value(value)?.schema_version)),
+            None => Ok(None),
+        }
+    }
+
+    /// Regrava o arquivo no esquema atual, inicia o histórico se ele não
+    /// existir e corrige as categorias gravadas antes de `Category::validate`
+    /// (veja `NFT::normalize_category`), com as correções registradas no
+    /// histórico. Retorna a versão encontrada antes da migração.
+    pub fn migrate(&mut self) -> Result<Option<u32>, StorageError> {
+        let from_version = self.schema_version()?;
+        if let Some(version) = from_version {
+            let mut database = self.load_database()?;
+            let previous = database.nfts.clone();
+            if let Some(token_id) = duplicate_token_id(&previous) {
+                return Err(StorageError::DuplicateTokenId(token_id.to_string()));
+            }
+            self.ledger().start(&previous)?;
+            let mut normalized = false;
+            for nft in &mut database.nfts {
+                normalized |= nft.normalize_category();
+            }
+            if normalized {
+                self.write_database(&database)?;
+                self.ledger().record(&previous, &database.nfts)?;
+                self.search_index().update(&previous, &database.nfts)?;
+            } else if version < SCHEMA_VERSION {
+                self.write_database(&database)?;
+            }
+        }
+        Ok(from_version)
+    }
+
+    /// Histórico encadeado de mutações deste banco de dados.
+    pub fn ledger(&self) -> Ledger {
+        Ledger::for_database(&self.file_path)
+    }
+
+    /// Índice de busca deste banco de dados.
+    pub fn search_index(&self) -> SearchIndex {
+        SearchIndex::for_database(&self.file_path)
+    }
+
+    pub fn save_all(&mut self, nfts: &[NFT]) -> Result<(), StorageError> {
+        self.save_nfts_with(nfts, |_| {})
+    }
+
+    /// Grava os NFTs e as vendas em uma única escrita do banco de dados.
+    pub fn save_all_with_sales(
+        &mut self,
+        nfts: &[NFT],
+        sales: &[Sale],
+    ) -> Result<(), StorageError> {
+        self.save_nfts_with(nfts, |database| database.sales = sales.to_vec())
+    }
+
+    /// Grava os NFTs, as vendas e as avaliações em uma única escrita.
+    pub fn save_all_with_prices(
+        &mut self,
+        nfts: &[NFT],
+        sales: &[Sale],
+        valuations: &[Valuation],
+    ) -> Result<(), StorageError> {
+        self.save_nfts_with(nfts, |database| {
+            database.sales = sales.to_vec();
+            database.valuations = valuations.to_vec();
+        })
+    }
+
+    /// Grava os NFTs e as categorias definidas em uma única escrita.
+    pub fn save_all_with_categories(
+        &mut self,
+        nfts: &[NFT],
+        categories: &[CategoryDefinition],
+    ) -> Result<(), StorageError> {
+        self.save_nfts_with(nfts, |database| database.categories = categories.to_vec())
+    }
+
+    /// Substitui todo o conteúdo do banco de dados em uma única escrita.
+    pub fn save_database(&mut self, mut database: Database) -> Result<(), StorageError> {
+        let nfts = std::mem::take(&mut database.nfts);
+        self.save_nfts_with(&nfts, |current| {
+            database.schema_version = current.schema_version;
+            database.nfts = std::mem::take(&mut current.nfts);
+            *current = database;
+        })
+    }
+
+    /// Grava os NFTs junto com as demais alterações de `change`, em uma única
+    /// escrita, e então atualiza o histórico e o índice de busca. As vendas e
+    /// avaliações de NFTs removidos são apagadas, para que um NFT criado
+    /// depois com o mesmo Token ID não as herde.
+    fn save_nfts_with<F>(&mut self, nfts: &[NFT], change: F) -> Result<(), StorageError>
+    where
+        F: FnOnce(&mut Database),
+    {
+        if let Some(token_id) = duplicate_token_id(nfts) {
+            return Err(StorageError::DuplicateTokenId(token_id.to_string()));
+        }
+        let mut database = self.load_database()?;
+        let previous = std::mem::replace(&mut database.nfts, nfts.to_vec());
+        let kept: HashSet<&str> = nfts.iter().map(|nft| nft.token_id.as_str()).collect();
+        let removed: HashSet<&str> = previous
+            .iter()
+            .map(|nft| nft.token_id.as_str())
+            .filter(|token_id| !kept.contains(token_id))
+            .collect();
+        database
+            .sales
+            .retain(|sale| !removed.contains(sale.token_id.as_str()));
+        database
+            .valuations
+            .retain(|valuation| !removed.contains(valuation.token_id.as_str()));
+        change(&mut database);
+
+        self.write_database(&database)?;
+
+        self.ledger().record(&previous, nfts)?;
+        self.search_index().update(&previous, nfts)
+    }
+
+    pub fn load_collections(&mut self) -> Result<Vec<Collection>, StorageError> {
+        Ok(self.load_database()?.collections)
+    }
+
+    pub fn save_collections(&mut self, collections: &[Collection]) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.collections = collections.to_vec();
+        self.write_database(&database)
+    }
+
+    pub fn load_owners(&mut self) -> Result<Vec<Owner>, StorageError> {
+        Ok(self.load_database()?.owners)
+    }
+
+    pub fn save_owners(&mut self, owners: &[Owner]) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.owners = owners.to_vec();
+        self.write_database(&database)
+    }
+
+    pub fn load_settings(&mut self) -> Result<Settings, StorageError> {
+        Ok(self.load_database()?.settings)
+    }
+
+    pub fn save_settings(&mut self, settings: &Settings) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.settings = settings.clone();
+        self.write_database(&database)
+    }
+
+    pub fn load_categories(&mut self) -> Result<Vec<CategoryDefinition>, StorageError> {
+        Ok(self.load_database()?.categories)
+    }
+
+    pub fn save_categories(
+        &mut self,
+        categories: &[CategoryDefinition],
+    ) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.categories = categories.to_vec();
+        self.write_database(&database)
+    }
+
+    pub fn load_sales(&mut self) -> Result<Vec<Sale>, StorageError> {
+        Ok(self.load_database()?.sales)
+    }
+
+    pub fn save_sales(&mut self, sales: &[Sale]) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.sales = sales.to_vec();
+        self.write_database(&database)
+    }
+
+    pub fn load_valuations(&mut self) -> Result<Vec<Valuation>, StorageError> {
+        Ok(self.load_database()?.valuations)
+    }
+
+    pub fn save_valuations(&mut self, valuations: &[Valuation]) -> Result<(), StorageError> {
+        let mut database = self.load_database()?;
+        database.valuations = valuations.to_vec();
+        self.write_database(&database)
+    }
+
+    fn read_value(&self) -> Result<Option<Value>, StorageError> {
         match OpenOptions::new().read(true).open(&self.file_path) {
             Ok(file) => {
                 let reader = BufReader::new(file);
-                let nfts = from_reader(reader).map_err(StorageError::Serde)?;
-                Ok(nfts)
+                let value = from_reader(reader).map_err(StorageError::Serde)?;
+                Ok(Some(value))
             }
             Err(e) => {
                 if e.kind() == std::io::ErrorKind::NotFound {
-                    Ok(vec![])
+                    Ok(None)
                 } else {
                     Err(StorageError::Io(e))
                 }
@@ -62,7 +311,21 @@ impl FileStorage {
         }
     }
 
-    pub fn save_all(&mut self, nfts: &[NFT]) -> Result<(), StorageError> {
+    fn load_database(&self) -> Result<Database, StorageError> {
+        match self.read_value()? {
+            Some(value) => {
+                let mut database = migrate_value(value)?;
+                database.schema_version = SCHEMA_VERSION;
+                Ok(database)
+            }
+            None => Ok(Database {
+                schema_version: SCHEMA_VERSION,
+                ..Database::default()
+            }),
+        }
+    }
+
+    fn write_database(&self, database: &Database) -> Result<(), StorageError> {
         let file = OpenOptions::new()
             .write(true)
             .create(true)
@@ -71,7 +334,27 @@ impl FileStorage {
             .map_err(StorageError::Io)?;
 
         let writer = BufWriter::new(file);
-        to_writer(writer, &nfts).map_err(StorageError::Serde)?;
+        to_writer(writer, database).map_err(StorageError::Serde)?;
         Ok(())
     }
 }
+
+/// Converte o conteúdo lido do arquivo para o esquema atual.
+/// A versão original é preservada em `schema_version`.
+fn migrate_value(value: Value) -> Result<Database, StorageError> {
+    match value {
+        // Versão 0: lista de NFTs sem envelope
+        Value::Array(_) => Ok(Database {
+            schema_version: 0,
+            nfts: from_value(value).map_err(StorageError::Serde)?,
+            ..Database::default()
+        }),
+        value => {
+            let database: Database = from_value(value).map_err(StorageError::Serde)?;
+            if database.schema_version > SCHEMA_VERSION {
+                return Err(StorageError::UnsupportedSchema(database.schema_version));
+            }
+            Ok(database)
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use crate::models::nft::NFT;
+use crate::storage::file_storage::StorageError;
+use chrono::{Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_cbor::{from_reader, to_writer};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{BufReader, BufWriter};
+
+/// Hash usado como `prev_hash` da primeira entrada do histórico.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LedgerAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for LedgerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerAction::Create => write!(f, "Criação"),
+            LedgerAction::Update => write!(f, "Atualização"),
+            LedgerAction::Delete => write!(f, "Remoção"),
+        }
+    }
+}
+
+/// Entrada do histórico de mutações. Cada entrada guarda o hash da anterior,
+/// formando uma cadeia que denuncia qualquer alteração ou remoção.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerEntry {
+    pub sequence: u64,
+    pub timestamp: NaiveDateTime,
+    pub action: LedgerAction,
+    pub token_id: String,
+    pub nft: Option<NFT>,   // Estado do NFT após a mutação (None na remoção)
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// Conteúdo da entrada coberto pelo hash (todos os campos exceto o próprio hash).
+#[derive(Serialize)]
+struct EntryBody<'a> {
+    sequence: u64,
+    timestamp: &'a NaiveDateTime,
+    action: &'a LedgerAction,
+    token_id: &'a str,
+    nft: &'a Option<NFT>,
+    prev_hash: &'a str,
+}
+
+impl LedgerEntry {
+    /// Calcula o SHA-256 do CBOR canônico da entrada, em hexadecimal.
+    pub fn compute_hash(&self) -> String {
+        let body = EntryBody {
+            sequence: self.sequence,
+            timestamp: &self.timestamp,
+            action: &self.action,
+            token_id: &self.token_id,
+            nft: &self.nft,
+            prev_hash: &self.prev_hash,
+        };
+        let bytes = serde_cbor::to_vec(&body).expect("serialização CBOR de entrada do histórico");
+        Sha256::digest(&bytes)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+/// Primeiro problema encontrado ao percorrer o histórico.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LedgerIssue {
+    Missing { expected: u64, found: u64 },
+    BrokenLink { sequence: u64 },
+    Tampered { sequence: u64 },
+    StateMismatch { token_id: String },
+    /// Banco de dados com NFTs e sem histórico (removido ou anterior a ele).
+    MissingHistory,
+    DuplicateTokenId { token_id: String },
+}
+
+impl fmt::Display for LedgerIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerIssue::Missing { expected, found } => write!(
+                f,
+                "Entrada #{} ausente no histórico (encontrada #{} em seu lugar)",
+                expected, found
+            ),
+            LedgerIssue::BrokenLink { sequence } => write!(
+                f,
+                "Entrada #{} não encadeia com a anterior (entrada #{} adulterada ou ausente)",
+                sequence,
+                sequence.saturating_sub(1)
+            ),
+            LedgerIssue::Tampered { sequence } => {
+                write!(f, "Entrada #{} adulterada: hash não confere", sequence)
+            }
+            LedgerIssue::StateMismatch { token_id } => write!(
+                f,
+                "NFT '{}' no banco de dados diverge do histórico",
+                token_id
+            ),
+            LedgerIssue::MissingHistory => write!(
+                f,
+                "Histórico ausente para um banco de dados com NFTs; \
+                 execute 'nft_manager migrate' para iniciá-lo a partir do estado atual"
+            ),
+            LedgerIssue::DuplicateTokenId { token_id } => write!(
+                f,
+                "Token ID '{}' aparece mais de uma vez no banco de dados",
+                token_id
+            ),
+        }
+    }
+}
+
+/// Resultado da verificação do histórico.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerReport {
+    pub entries_checked: usize,
+    pub issue: Option<LedgerIssue>,
+}
+
+impl LedgerReport {
+    pub fn is_valid(&self) -> bool {
+        self.issue.is_none()
+    }
+}
+
+pub struct Ledger {
+    pub file_path: String,
+}
+
+impl Ledger {
+    pub fn new(file_path: &str) -> Self {
+        Ledger {
+            file_path: file_path.to_string(),
+        }
+    }
+
+    /// Histórico associado a um banco de dados (`<db>.ledger`).
+    pub fn for_database(db_path: &str) -> Self {
+        Ledger::new(&format!("{}.ledger", db_path))
+    }
+
+    pub fn load_all(&self) -> Result<Vec<LedgerEntry>, StorageError> {
+        match OpenOptions::new().read(true).open(&self.file_path) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                let entries = from_reader(reader).map_err(StorageError::Serde)?;
+                Ok(entries)
+            }
+            Err(e) => {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    Ok(vec![])
+                } else {
+                    Err(StorageError::Io(e))
+                }
+            }
+        }
+    }
+
+    pub fn save_all(&self, entries: &[LedgerEntry]) -> Result<(), StorageError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.file_path)
+            .map_err(StorageError::Io)?;
+
+        let writer = BufWriter::new(file);
+        to_writer(writer, &entries).map_err(StorageError::Serde)?;
+        Ok(())
+    }
+
+    /// Registra as mutações que levam de `previous` a `current`.
+    ///
+    /// Sem histórico e com NFTs no estado anterior, nada é registrado: o
+    /// histórico foi removido ou o banco é anterior a ele, e `verify` acusa a
+    /// falta até que `start` seja chamado por `migrate`.
+    pub fn record(&self, previous: &[NFT], current: &[NFT]) -> Result<(), StorageError> {
+        let entries = self.load_all()?;
+        if entries.is_empty() && !previous.is_empty() {
+            return Ok(());
+        }
+        self.append(entries, diff_states(previous, current))
+    }
+
+    /// Inicia o histórico registrando `nfts` como criações. Retorna `false`
+    /// se o histórico já existia.
+    pub fn start(&self, nfts: &[NFT]) -> Result<bool, StorageError> {
+        let entries = self.load_all()?;
+        if !entries.is_empty() {
+            return Ok(false);
+        }
+        self.append(entries, diff_states(&[], nfts))?;
+        Ok(true)
+    }
+
+    fn append(
+        &self,
+        mut entries: Vec<LedgerEntry>,
+        changes: Vec<(LedgerAction, String, Option<NFT>)>,
+    ) -> Result<(), StorageError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let timestamp = Local::now().naive_local();
+        for (action, token_id, nft) in changes {
+            let (sequence, prev_hash) = match entries.last() {
+                Some(last) => (last.sequence + 1, last.hash.clone()),
+                None => (1, GENESIS_HASH.to_string()),
+            };
+            let mut entry = LedgerEntry {
+                sequence,
+                timestamp,
+                action,
+                token_id,
+                nft,
+                prev_hash,
+                hash: String::new(),
+            };
+            entry.hash = entry.compute_hash();
+            entries.push(entry);
+        }
+
+        self.save_all(&entries)
+    }
+
+    /// Percorre a cadeia e aponta a primeira entrada adulterada ou ausente.
+    /// Por fim, compara o estado reconstruído com o conteúdo atual do banco.
+    pub fn verify(&self, current: &[NFT]) -> Result<LedgerReport, StorageError> {
+        let entries = self.load_all()?;
+        let mut state: BTreeMap<String, NFT> = BTreeMap::new();
+        let mut prev_hash = GENESIS_HASH.to_string();
+
+        for (index, entry) in entries.iter().enumerate() {
+            let expected = index as u64 + 1;
+            let issue = if entry.sequence != expected {
+                Some(LedgerIssue::Missing {
+                    expected,
+                    found: entry.sequence,
+                })
+            } else if entry.prev_hash != prev_hash {
+                Some(LedgerIssue::BrokenLink {
+                    sequence: entry.sequence,
+                })
+            } else if entry.compute_hash() != entry.hash {
+                Some(LedgerIssue::Tampered {
+                    sequence: entry.sequence,
+                })
+            } else {
+                None
+            };
+
+            if issue.is_some() {
+                return Ok(LedgerReport {
+                    entries_checked: index,
+                    issue,
+                });
+            }
+
+            match &entry.nft {
+                Some(nft) => {
+                    state.insert(entry.token_id.clone(), nft.clone());
+                }
+                None => {
+                    state.remove(&entry.token_id);
+                }
+            }
+            prev_hash = entry.hash.clone();
+        }
+
+        if entries.is_empty() && !current.is_empty() {
+            return Ok(LedgerReport {
+                entries_checked: 0,
+                issue: Some(LedgerIssue::MissingHistory),
+            });
+        }
+        if let Some(token_id) = duplicate_token_id(current) {
+            return Ok(LedgerReport {
+                entries_checked: entries.len(),
+                issue: Some(LedgerIssue::DuplicateTokenId {
+                    token_id: token_id.to_string(),
+                }),
+            });
+        }
+
+        let actual = index_by_token(current);
+        let mismatch = state
+            .keys()
+            .chain(actual.keys())
+            .filter(|token_id| state.get(*token_id) != actual.get(*token_id))
+            .min()
+            .cloned();
+
+        Ok(LedgerReport {
+            entries_checked: entries.len(),
+            issue: mismatch.map(|token_id| LedgerIssue::StateMismatch { token_id }),
+        })
+    }
+}
+
+/// Primeiro Token ID repetido, que `index_by_token` esconderia.
+pub(crate) fn duplicate_token_id(nfts: &[NFT]) -> Option<&str> {
+    let mut seen = HashSet::new();
+    nfts.iter()
+        .map(|nft| nft.token_id.as_str())
+        .find(|token_id| !seen.insert(*token_id))
+}
+
+fn index_by_token(nfts: &[NFT]) -> BTreeMap<String, NFT> {
+    nfts.iter()
+        .map(|nft| (nft.token_id.clone(), nft.clone()))
+        .collect()
+}
+
+/// Lista as mutações por token_id entre dois estados do banco.
+pub(crate) fn diff_states(previous: &[NFT], current: &[NFT]) -> Vec<(LedgerAction, String, Option<NFT>)> {
+    let before = index_by_token(previous);
+    let after = index_by_token(current);
+    let mut changes = Vec::new();
+
+    for (token_id, nft) in &after {
+        match before.get(token_id) {
+            None => changes.push((LedgerAction::Create, token_id.clone(), Some(nft.clone()))),
+            Some(old) if old != nft => {
+                changes.push((LedgerAction::Update, token_id.clone(), Some(nft.clone())))
+            }
+            Some(_) => {}
+        }
+    }
+    for token_id in before.keys() {
+        if !after.contains_key(token_id) {
+            changes.push((LedgerAction::Delete, token_id.clone(), None));
+        }
+    }
+
+    changes
+}
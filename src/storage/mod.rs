@@ -1 +1,4 @@
 pub mod file_storage;
+pub mod ledger;
+pub mod erc721;
+pub mod search_index;
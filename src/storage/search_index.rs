@@ -0,0 +1,395 @@
+use crate::models::nft::NFT;
+use crate::storage::file_storage::StorageError;
+use crate::storage::ledger::diff_states;
+use serde::{Deserialize, Serialize};
+use serde_cbor::{from_reader, to_writer};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::OpenOptions;
+use std::io::{BufReader, BufWriter};
+
+/// Versão do formato do índice. Índices de outra versão são reconstruídos,
+/// o que também acontece ao indexar novos campos.
+///
+/// - Versão 1: campos e trigramas dos NFTs.
+/// - Versão 2: impressão digital do conteúdo indexado.
+pub const INDEX_VERSION: u32 = 2;
+
+/// Texto de um campo do NFT, com o rótulo exibido nos resultados.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndexedField {
+    pub label: String,
+    pub text: String,
+}
+
+/// Resultado de uma busca: o campo do NFT que melhor atendeu à consulta e
+/// as posições (em caracteres) do texto que coincidiram com ela.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub token_id: String,
+    pub score: u32,
+    pub label: String,
+    pub text: String,
+    pub positions: Vec<usize>,
+}
+
+/// Conteúdo persistido: os campos de cada NFT e, para cada trigrama, os
+/// NFTs em que ele aparece.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexData {
+    version: u32,
+    documents: BTreeMap<String, Vec<IndexedField>>,
+    trigrams: BTreeMap<String, BTreeSet<String>>,
+    /// Impressão digital de `documents` (veja [`fingerprint`]).
+    #[serde(default)]
+    fingerprint: String,
+}
+
+/// Índice de busca associado a um banco de dados (`<db>.index`), atualizado
+/// a cada gravação apenas com os NFTs criados, alterados ou removidos.
+pub struct SearchIndex {
+    pub file_path: String,
+}
+
+/// Campos pesquisáveis do NFT, do mais ao menos relevante.
+pub fn indexed_fields(nft: &NFT) -> Vec<IndexedField> {
+    let field = |label: &str, text: &str| IndexedField {
+        label: label.to_string(),
+        text: text.to_string(),
+    };
+    let mut fields = vec![
+        field("Token ID", &nft.token_id),
+        field("Categoria", &nft.category_path().to_string()),
+    ];
+    if let Some(name) = &nft.metadata.name {
+        fields.push(field("Nome", name));
+    }
+    fields.extend(nft.tags.iter().map(|tag| field("Marcador", tag)));
+    for attribute in &nft.metadata.attributes {
+        fields.push(field(
+            &format!("Atributo {}", attribute.trait_type),
+            &attribute.value.to_string(),
+        ));
+    }
+    if let Some(description) = &nft.metadata.description {
+        fields.push(field("Descrição", description));
+    }
+    fields
+}
+
+impl SearchIndex {
+    pub fn new(file_path: &str) -> Self {
+        SearchIndex {
+            file_path: file_path.to_string(),
+        }
+    }
+
+    /// Índice associado a um banco de dados (`<db>.index`).
+    pub fn for_database(db_path: &str) -> Self {
+        SearchIndex::new(&format!("{}.index", db_path))
+    }
+
+    /// Reindexa os NFTs que mudaram de `previous` para `current`.
+    ///
+    /// Se o índice ainda não existe, é de outra versão ou não corresponde
+    /// ao estado anterior, esse estado é indexado primeiro.
+    pub fn update(&self, previous: &[NFT], current: &[NFT]) -> Result<(), StorageError> {
+        let mut data = match self.load()? {
+            Some(data) if data.version == INDEX_VERSION && data.covers(previous) => data,
+            _ => IndexData::build(previous),
+        };
+        for (_, token_id, nft) in diff_states(previous, current) {
+            data.remove(&token_id);
+            if let Some(nft) = nft {
+                data.insert(&nft);
+            }
+        }
+        data.fingerprint = fingerprint(&data.documents);
+        self.save(&data)
+    }
+
+    /// Descarta o índice e indexa novamente todos os NFTs.
+    pub fn rebuild(&self, nfts: &[NFT]) -> Result<(), StorageError> {
+        self.save(&IndexData::build(nfts))
+    }
+
+    /// Busca aproximada, do resultado mais relevante ao menos relevante.
+    /// Um índice ausente ou que não corresponde a `nfts` é reconstruído antes.
+    pub fn search(&self, query: &str, nfts: &[NFT]) -> Result<Vec<SearchHit>, StorageError> {
+        let data = match self.load()? {
+            Some(data) if data.version == INDEX_VERSION && data.covers(nfts) => data,
+            _ => {
+                let data = IndexData::build(nfts);
+                self.save(&data)?;
+                data
+            }
+        };
+        Ok(data.search(query))
+    }
+
+    fn load(&self) -> Result<Option<IndexData>, StorageError> {
+        match OpenOptions::new().read(true).open(&self.file_path) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                let data = from_reader(reader).map_err(StorageError::Serde)?;
+                Ok(Some(data))
+            }
+            Err(e) => {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    Ok(None)
+                } else {
+                    Err(StorageError::Io(e))
+                }
+            }
+        }
+    }
+
+    fn save(&self, data: &IndexData) -> Result<(), StorageError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.file_path)
+            .map_err(StorageError::Io)?;
+
+        let writer = BufWriter::new(file);
+        to_writer(writer, data).map_err(StorageError::Serde)?;
+        Ok(())
+    }
+}
+
+impl IndexData {
+    fn build(nfts: &[NFT]) -> IndexData {
+        let mut data = IndexData {
+            version: INDEX_VERSION,
+            ..IndexData::default()
+        };
+        for nft in nfts {
+            data.insert(nft);
+        }
+        data.fingerprint = fingerprint(&data.documents);
+        data
+    }
+
+    /// Verdadeiro se o índice foi gerado com o conteúdo atual de `nfts`: os
+    /// mesmos Token IDs e os mesmos campos indexados, comparados pela
+    /// impressão digital.
+    fn covers(&self, nfts: &[NFT]) -> bool {
+        let documents: BTreeMap<String, Vec<IndexedField>> = nfts
+            .iter()
+            .map(|nft| (nft.token_id.clone(), indexed_fields(nft)))
+            .collect();
+        documents.len() == nfts.len() && fingerprint(&documents) == self.fingerprint
+    }
+
+    fn insert(&mut self, nft: &NFT) {
+        let fields = indexed_fields(nft);
+        for field in &fields {
+            for trigram in trigrams(&normalize(&field.text)) {
+                self.trigrams
+                    .entry(trigram)
+                    .or_default()
+                    .insert(nft.token_id.clone());
+            }
+        }
+        self.documents.insert(nft.token_id.clone(), fields);
+    }
+
+    fn remove(&mut self, token_id: &str) {
+        if let Some(fields) = self.documents.remove(token_id) {
+            for field in &fields {
+                for trigram in trigrams(&normalize(&field.text)) {
+                    if let Some(tokens) = self.trigrams.get_mut(&trigram) {
+                        tokens.remove(token_id);
+                        if tokens.is_empty() {
+                            self.trigrams.remove(&trigram);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn search(&self, query: &str) -> Vec<SearchHit> {
+        let query = normalize(query.trim());
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        // Consultas curtas não têm trigramas; as demais só avaliam os NFTs
+        // que compartilham ao menos um trigrama com elas
+        let query_trigrams = trigrams(&query);
+        let candidates: BTreeSet<&String> = if query_trigrams.is_empty() {
+            self.documents.keys().collect()
+        } else {
+            query_trigrams
+                .iter()
+                .filter_map(|trigram| self.trigrams.get(trigram))
+                .flatten()
+                .collect()
+        };
+
+        let mut hits: Vec<SearchHit> = candidates
+            .into_iter()
+            .filter_map(|token_id| {
+                let fields = self.documents.get(token_id)?;
+                fields
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(rank, field)| {
+                        let (score, positions) = score_text(&query, &field.text)?;
+                        // Em caso de empate, vale o campo mais relevante
+                        Some((score * 10 - rank.min(9) as u32, field, positions))
+                    })
+                    .max_by_key(|(score, _, _)| *score)
+                    .map(|(score, field, positions)| SearchHit {
+                        token_id: token_id.clone(),
+                        score,
+                        label: field.label.clone(),
+                        text: field.text.clone(),
+                        positions,
+                    })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.token_id.cmp(&b.token_id))
+        });
+        hits
+    }
+}
+
+/// SHA-256 do CBOR dos campos indexados, em hexadecimal. Muda sempre que um
+/// NFT indexado é criado, alterado ou removido, mesmo fora do programa.
+fn fingerprint(documents: &BTreeMap<String, Vec<IndexedField>>) -> String {
+    let bytes = serde_cbor::to_vec(documents).expect("serialização CBOR do índice de busca");
+    Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Minúsculas caractere a caractere, preservando as posições do texto original.
+fn normalize(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+fn trigrams(text: &[char]) -> BTreeSet<String> {
+    text.windows(3)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Pontua o texto para a consulta já normalizada. Da maior para a menor
+/// pontuação: texto idêntico, prefixo, trecho, caracteres em ordem
+/// (`art3` em `ART-0003`) e palavras com até um ou dois erros de digitação.
+fn score_text(query: &[char], text: &str) -> Option<(u32, Vec<usize>)> {
+    let text = normalize(text);
+    let span = |start: usize| (start..start + query.len()).collect::<Vec<usize>>();
+
+    if text == query {
+        return Some((100, span(0)));
+    }
+    if let Some(start) = find(&text, query) {
+        let score = if start == 0 {
+            90
+        } else if !text[start - 1].is_alphanumeric() {
+            80
+        } else {
+            70
+        };
+        return Some((score, span(start)));
+    }
+    if let Some(positions) = subsequence(query, &text) {
+        let gaps = positions[positions.len() - 1] - positions[0] + 1 - query.len();
+        return Some((60 - gaps.min(20) as u32, positions));
+    }
+
+    let max_typos = match query.len() {
+        0..=3 => return None,
+        4..=7 => 1,
+        _ => 2,
+    };
+    words(&text)
+        .into_iter()
+        .chain(std::iter::once((0, text.len())))
+        .filter_map(|(start, end)| {
+            let distance = edit_distance(query, &text[start..end], max_typos)?;
+            Some((30 - 10 * distance as u32, (start..end).collect()))
+        })
+        .max_by_key(|(score, _)| *score)
+}
+
+fn find(text: &[char], query: &[char]) -> Option<usize> {
+    text.windows(query.len()).position(|window| window == query)
+}
+
+/// Posições dos caracteres da consulta no texto, na mesma ordem. Prefere a
+/// ocorrência mais compacta a partir de cada início possível.
+fn subsequence(query: &[char], text: &[char]) -> Option<Vec<usize>> {
+    let mut best: Option<Vec<usize>> = None;
+    for start in (0..text.len()).filter(|&i| text[i] == query[0]) {
+        let mut positions = vec![start];
+        let mut next = start + 1;
+        for c in &query[1..] {
+            match text[next..].iter().position(|t| t == c) {
+                Some(offset) => {
+                    positions.push(next + offset);
+                    next += offset + 1;
+                }
+                None => return best,
+            }
+        }
+        let length = |p: &Vec<usize>| p[p.len() - 1] - p[0];
+        if best.as_ref().is_none_or(|b| length(&positions) < length(b)) {
+            best = Some(positions);
+        }
+    }
+    best
+}
+
+/// Intervalos `[início, fim)` das palavras (sequências alfanuméricas).
+fn words(text: &[char]) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.iter().enumerate() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, text.len()));
+    }
+    words
+}
+
+/// Distância de edição em que a troca de dois caracteres vizinhos conta
+/// como um só erro, ou `None` se ela for maior que `limit`.
+fn edit_distance(a: &[char], b: &[char], limit: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > limit {
+        return None;
+    }
+    let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i];
+        for j in 1..=b.len() {
+            let mut distance = (rows[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]))
+                .min(rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            row.push(distance);
+        }
+        rows.push(row);
+    }
+    Some(rows[a.len()][b.len()]).filter(|&distance| distance <= limit)
+}
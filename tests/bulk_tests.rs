@@ -0,0 +1,130 @@
+use chrono::NaiveDate;
+use nft_manager::cli::bulk::{process_bulk_delete, process_bulk_update, BulkChange};
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::databases::NftFilter;
+use nft_manager::models::category::{Category, CategoryPath};
+use nft_manager::models::collection::{Chain, Collection};
+use nft_manager::models::nft::NFT;
+use nft_manager::models::query::Query;
+use nft_manager::storage::file_storage::FileStorage;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, owner_id: u64, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        category,
+    )
+}
+
+fn storage(dir: &tempfile::TempDir) -> FileStorage {
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage
+        .save_all(&[
+            nft("a", 42, Category::Art),
+            nft("b", 42, Category::Music),
+            nft("c", 42, Category::Art),
+            nft("d", 7, Category::Art),
+        ])
+        .unwrap();
+    storage
+}
+
+fn filter(expression: &str) -> NftFilter {
+    NftFilter {
+        query: Some(Query::from_str(expression).unwrap()),
+        ..NftFilter::default()
+    }
+}
+
+#[test]
+fn test_where_expressions() {
+    let mut nft = nft("a", 42, Category::VirtualRealEstate);
+    nft.add_tag("a b");
+    assert!(filter("owner=42 AND category = 'Virtual Real Estate' and tag=\"a b\"").matches(&nft));
+    assert!(!filter("owner=1 or owner=2").matches(&nft));
+    for invalid in ["", "owner", "owner=", "owner==1", "tag='a", "price=2"] {
+        assert!(
+            Query::from_str(invalid).is_err(),
+            "{} deveria ser inválida",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_bulk_update_is_atomic() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let mut collection = Collection::new(
+        "c1".to_string(),
+        "C1".to_string(),
+        "C".to_string(),
+        Chain::Ethereum,
+    );
+    collection.max_supply = Some(2);
+    storage.save_collections(&[collection]).unwrap();
+
+    let change = BulkChange {
+        owner_id: Some(99),
+        category: Some(CategoryPath::from_str("Art/Generative").unwrap()),
+        collection_id: None,
+    };
+    assert_eq!(
+        process_bulk_update(
+            &filter("owner=42 and category=art"),
+            &change,
+            &ValidationRules::default(),
+            &mut storage
+        ),
+        Ok(vec!["a".to_string(), "c".to_string()])
+    );
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(
+        nfts.iter().map(|n| n.owner_id).collect::<Vec<_>>(),
+        [99, 42, 99, 7]
+    );
+    assert_eq!(nfts[0].subcategories, ["Generative"]);
+
+    // Três NFTs não cabem na coleção: nada é gravado
+    let change = BulkChange {
+        collection_id: Some(Some("c1".to_string())),
+        ..BulkChange::default()
+    };
+    assert!(process_bulk_update(
+        &filter("category=Art"),
+        &change,
+        &ValidationRules::default(),
+        &mut storage
+    )
+    .is_err());
+    assert!(storage
+        .load_all()
+        .unwrap()
+        .iter()
+        .all(|n| n.collection_id.is_none()));
+}
+
+#[test]
+fn test_bulk_delete() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+
+    assert_eq!(
+        process_bulk_delete(&filter("category=Art and owner=42"), &mut storage),
+        Ok(vec!["a".to_string(), "c".to_string()])
+    );
+    let remaining: Vec<String> = storage
+        .load_all()
+        .unwrap()
+        .into_iter()
+        .map(|n| n.token_id)
+        .collect();
+    assert_eq!(remaining, ["b", "d"]);
+    assert_eq!(
+        process_bulk_delete(&filter("owner=1"), &mut storage),
+        Ok(vec![])
+    );
+}
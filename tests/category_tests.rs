@@ -0,0 +1,285 @@
+use chrono::NaiveDate;
+use nft_manager::cli::categories::{
+    process_define_category, process_merge_categories, process_rename_category,
+};
+use nft_manager::models::category::{Category, CategoryDefinition, CategoryPath};
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use proptest::prelude::*;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        category,
+    )
+}
+
+fn definition(name: &str, aliases: &[&str]) -> CategoryDefinition {
+    let mut definition = CategoryDefinition::new(name);
+    definition.aliases = aliases.iter().map(|a| a.to_string()).collect();
+    definition
+}
+
+#[test]
+fn test_category_resolve_aliases() {
+    let definitions = vec![
+        definition("Art", &["arte", "artwork"]),
+        definition("Ingresso", &["ticket"]),
+    ];
+
+    assert_eq!(Category::resolve(" ARTE ", &definitions), Ok(Category::Art));
+    assert_eq!(
+        Category::resolve("Ticket", &definitions),
+        Ok(Category::Other("Ingresso".to_string()))
+    );
+    assert_eq!(Category::resolve("art ", &[]), Ok(Category::Art));
+    assert_eq!(
+        Category::resolve(" Domínio ", &[]),
+        Ok(Category::Other("Domínio".to_string()))
+    );
+    assert!(Category::resolve("  ", &definitions).is_err());
+
+    // from_str só conhece as categorias predefinidas
+    assert_eq!(
+        CategoryPath::resolve("artwork/Pintura", &definitions).map(|p| p.category),
+        Ok(Category::Art)
+    );
+    assert_eq!(
+        Category::from_str("artwork"),
+        Ok(Category::Other("artwork".to_string()))
+    );
+
+    assert!(definition("Art", &["arte", "ARTE"]).validate().is_err());
+    assert!(definition("Art", &[" "]).validate().is_err());
+}
+
+#[test]
+fn test_define_rejects_conflicting_names() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+
+    process_define_category(&definition("Ingresso", &["ticket"]), &mut storage).unwrap();
+    let result = process_define_category(&definition("Evento", &["Ticket"]), &mut storage);
+    assert_eq!(
+        result,
+        Err("'Ticket' já é usado pela categoria 'Ingresso'.".to_string())
+    );
+    assert_eq!(storage.load_categories().unwrap().len(), 1);
+}
+
+#[test]
+fn test_merge_and_rename_categories() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage
+        .save_all(&[
+            nft("a", Category::Other("art".to_string())),
+            nft("b", Category::Other("Art ".to_string())),
+            nft("c", Category::Other("arte".to_string())),
+            nft("d", Category::Art),
+            nft("e", Category::Other("ticket".to_string())),
+        ])
+        .unwrap();
+    process_define_category(&definition("Arte", &["arts"]), &mut storage).unwrap();
+
+    // A definição "Arte" é absorvida por Art, com os seus nomes como apelidos
+    let sources = ["Other: art", "Other: arte", "Arte"];
+    let updated = process_merge_categories(&sources, "Art", &mut storage).unwrap();
+    assert_eq!(updated, 3);
+    let nfts = storage.load_all().unwrap();
+    assert!(nfts[..4].iter().all(|n| n.category == Category::Art));
+    assert_eq!(
+        storage.load_categories().unwrap(),
+        vec![definition("Art", &["Arte", "arts"])]
+    );
+
+    // Renomear mantém o nome antigo como apelido
+    process_define_category(&definition("Ticket", &[]), &mut storage).unwrap();
+    assert_eq!(
+        process_rename_category("ticket", "Ingresso", &mut storage),
+        Ok(1)
+    );
+    assert_eq!(
+        storage.load_all().unwrap()[4].category,
+        Category::Other("Ingresso".to_string())
+    );
+    assert_eq!(
+        Category::resolve("TICKET", &storage.load_categories().unwrap()),
+        Ok(Category::Other("Ingresso".to_string()))
+    );
+    assert!(process_rename_category("Ingresso", "arts", &mut storage).is_err());
+}
+
+#[test]
+fn test_other_with_predefined_name_is_a_different_category() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage
+        .save_all(&[
+            nft("a", Category::Other("Art".to_string())),
+            nft("b", Category::Art),
+        ])
+        .unwrap();
+
+    let art = CategoryPath::from_str("Art").unwrap();
+    let nfts = storage.load_all().unwrap();
+    assert!(!art.contains(&nfts[0].category_path()));
+    assert!(art.contains(&nfts[1].category_path()));
+
+    assert_eq!(
+        process_merge_categories(&["Art"], "Ingresso", &mut storage),
+        Ok(1)
+    );
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(nfts[0].category, Category::Other("Art".to_string()));
+    assert_eq!(nfts[1].category, Category::Other("Ingresso".to_string()));
+}
+
+#[test]
+fn test_rename_undefined_category_keeps_old_name_as_alias() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage
+        .save_all(&[
+            nft("a", Category::Other("Ticket".to_string())),
+            nft("b", Category::Art),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        process_rename_category("Other: ticket", "Ingresso", &mut storage),
+        Ok(1)
+    );
+    let definitions = storage.load_categories().unwrap();
+    assert_eq!(definitions, vec![definition("Ingresso", &["ticket"])]);
+    assert_eq!(
+        Category::resolve("Ticket", &definitions),
+        Ok(Category::Other("Ingresso".to_string()))
+    );
+    assert_eq!(
+        storage.load_all().unwrap()[0].category,
+        Category::Other("Ingresso".to_string())
+    );
+
+    // Nomes predefinidos não viram apelidos
+    assert_eq!(
+        process_rename_category("Art", "Arte Fina", &mut storage),
+        Ok(1)
+    );
+    assert_eq!(storage.load_categories().unwrap().len(), 1);
+    assert_eq!(
+        storage.load_all().unwrap()[1].category,
+        Category::Other("Arte Fina".to_string())
+    );
+}
+
+/// Texto qualquer, inclusive com espaços nas pontas e '/'; inclui nomes
+/// predefinidos e o prefixo `Other:`.
+fn description() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "\\PC{0,24}",
+        prop::sample::select(vec![
+            "Art",
+            "arte",
+            "game_item",
+            "Other: x",
+            "ticket",
+            " a/b "
+        ])
+        .prop_map(str::to_string),
+    ]
+}
+
+fn category() -> impl Strategy<Value = Category> {
+    prop_oneof![
+        prop::sample::select(Category::NAMED.to_vec()),
+        description().prop_map(Category::Other),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn test_category_display_round_trips(category in category()) {
+        // Descrições que não voltam iguais são recusadas na validação do NFT
+        if category.validate().is_err() {
+            prop_assert!(nft("a", category).validate().is_err());
+            return Ok(());
+        }
+        prop_assert_eq!(Category::from_str(&category.to_string()), Ok(category.clone()));
+
+        // Apelidos definidos não alteram a forma canônica
+        let definitions = vec![definition("Ingresso", &["ticket", "evento"])];
+        prop_assert_eq!(Category::resolve(&category.to_string(), &definitions), Ok(category));
+    }
+
+    #[test]
+    fn test_category_path_display_round_trips(
+        category in category(),
+        subcategories in prop::collection::vec("[A-Za-zÀ-ú0-9]([A-Za-zÀ-ú0-9 ]{0,10}[A-Za-zÀ-ú0-9])?", 0..4),
+    ) {
+        let mut nft = nft("a", category.clone());
+        nft.set_category_path(CategoryPath::new(category, subcategories));
+        if nft.validate().is_err() {
+            // A migração corrige a descrição, exceto quando ela é vazia
+            prop_assert!(!nft.normalize_category() || nft.validate().is_ok());
+            return Ok(());
+        }
+        let path = nft.category_path();
+        prop_assert_eq!(CategoryPath::from_str(&path.to_string()), Ok(path));
+    }
+}
+
+#[test]
+fn test_category_accepts_case_styles_and_localized_names() {
+    let cases = [
+        (
+            Category::VirtualRealEstate,
+            &[
+                "virtual_real_estate",
+                "virtual-real-estate",
+                "VirtualRealEstate",
+                "Imóvel Virtual",
+                "imovel virtual",
+            ][..],
+        ),
+        (
+            Category::GameItem,
+            &["game_item", "GAME-ITEM", "GameItem", "item de jogo"][..],
+        ),
+        (
+            Category::Collectible,
+            &["collectible", "colecionável", "Colecionavel"][..],
+        ),
+        (Category::Music, &["MUSIC", "música", "musica"][..]),
+        (Category::Art, &[" art ", "Arte"][..]),
+    ];
+    for (expected, inputs) in cases {
+        for input in inputs {
+            assert_eq!(
+                Category::resolve(input, &[]),
+                Ok(expected.clone()),
+                "{}",
+                input
+            );
+        }
+    }
+
+    assert_eq!(
+        Category::resolve("other: Art", &[]),
+        Ok(Category::Other("Art".to_string()))
+    );
+    assert_eq!(
+        Category::resolve("Other:   Ingresso ", &[]),
+        Ok(Category::Other("Ingresso".to_string()))
+    );
+    assert!(Category::resolve("Other:  ", &[]).is_err());
+
+    // Nomes predefinidos e o prefixo `Other:` não podem ser apelidos de outra categoria
+    assert!(definition("Ingresso", &["música"]).validate().is_err());
+    assert!(definition("Other: Ingresso", &[]).validate().is_err());
+    assert!(definition("Art", &["arte", "artwork"]).validate().is_ok());
+}
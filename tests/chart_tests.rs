@@ -0,0 +1,54 @@
+use nft_manager::cli::chart::{fill_months, render_bar_chart, render_sparkline, ChartStyle};
+use nft_manager::cli::stats::MonthCount;
+
+#[test]
+fn test_render_bar_chart_fits_width() {
+    let entries = vec![("Art".to_string(), 10), ("Music".to_string(), 5)];
+
+    let lines = render_bar_chart(&entries, 40, ChartStyle::Unicode);
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|line| line.chars().count() == 40));
+    assert!(lines[0].starts_with("Art   █"));
+    assert!(lines[0].ends_with(" 10"));
+
+    let lines = render_bar_chart(&entries, 40, ChartStyle::Ascii);
+    let full = lines[0].matches('#').count();
+    let half = lines[1].matches('#').count();
+    assert_eq!(full, 40 - 5 - 2 - 2);
+    assert_eq!(half, full.div_ceil(2));
+    assert!(lines.iter().all(|line| line.is_ascii()));
+
+    // Rótulos maiores que um terço da largura são truncados com reticências
+    let entries = vec![("Colecionáveis".to_string(), 3), ("Art".to_string(), 1)];
+    let lines = render_bar_chart(&entries, 30, ChartStyle::Ascii);
+    assert!(lines[0].starts_with("Colecio... #"), "{}", lines[0]);
+    assert!(lines.iter().all(|line| line.is_ascii()));
+    let lines = render_bar_chart(&entries, 30, ChartStyle::Unicode);
+    assert!(lines[0].starts_with("Colecioná… █"), "{}", lines[0]);
+}
+
+#[test]
+fn test_render_sparkline() {
+    assert_eq!(render_sparkline(&[0, 4, 8], ChartStyle::Unicode), "▁▄█");
+    assert_eq!(render_sparkline(&[0, 4, 8], ChartStyle::Ascii), "_-#");
+    assert_eq!(render_sparkline(&[], ChartStyle::Ascii), "");
+}
+
+#[test]
+fn test_fill_months_inserts_gaps() {
+    let months = vec![
+        MonthCount {
+            month: "2022-11".to_string(),
+            count: 2,
+        },
+        MonthCount {
+            month: "2023-02".to_string(),
+            count: 1,
+        },
+    ];
+
+    let filled = fill_months(&months);
+    let labels: Vec<&str> = filled.iter().map(|(m, _)| m.as_str()).collect();
+    assert_eq!(labels, vec!["2022-11", "2022-12", "2023-01", "2023-02"]);
+    assert_eq!(filled[1].1, 0);
+}
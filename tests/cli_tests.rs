@@ -1,6 +1,7 @@
 // tests/cli_tests.rs
 
 use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use std::str;
 use tempfile::tempdir;
@@ -311,3 +312,870 @@ token_delete_test
 
     assert!(stdout.contains("NFT deletado com sucesso!"));
 }
+
+#[test]
+fn test_cli_verify_ledger() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    let input = "\
+1
+token_verify_test
+123
+2023-11-05
+Arte
+5
+";
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .write_stdin(input)
+        .assert()
+        .success();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .arg("verify")
+        .assert()
+        .success()
+        .stdout(contains("Histórico íntegro: 1 entradas verificadas."));
+}
+
+#[test]
+fn test_cli_edit_and_show_metadata() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    let input = "\
+1
+token_metadata_test
+123
+2023-11-05
+Arte
+5
+";
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .write_stdin(input)
+        .assert()
+        .success();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["edit", "token_metadata_test", "--name", "Punk #1"])
+        .args(["--image", "ipfs://QmHash/1.png", "--attribute", "Cor=Azul"])
+        .assert()
+        .success()
+        .stdout(contains("Metadados atualizados com sucesso!"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["show", "token_metadata_test"])
+        .assert()
+        .success()
+        .stdout(contains("Nome: Punk #1"))
+        .stdout(contains("Imagem: ipfs://QmHash/1.png"))
+        .stdout(contains("  - Cor: Azul"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["edit", "token_metadata_test", "--image", "not a uri"])
+        .assert()
+        .failure()
+        .stdout(contains("URI inválida"));
+}
+
+#[test]
+fn test_cli_collection_commands() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["collection", "create", "punks", "--name", "Crypto Punks", "--symbol", "PUNK"])
+        .args(["--royalty-bps", "250", "--royalty-recipient", "42"])
+        .assert()
+        .success()
+        .stdout(contains("Coleção criada com sucesso!"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["collection", "list"])
+        .assert()
+        .success()
+        .stdout(contains("Nome: Crypto Punks"))
+        .stdout(contains("Royalty: 2.50% para o Owner ID 42"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["collection", "delete", "unknown"])
+        .assert()
+        .failure()
+        .stdout(contains("Coleção 'unknown' não encontrada."));
+}
+
+#[test]
+fn test_cli_list_resolves_owner_names() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["owner", "create", "123", "--name", "Alice"])
+        .assert()
+        .success()
+        .stdout(contains("Proprietário criado com sucesso!"));
+
+    let input = "\
+1
+token_owner_test
+123
+2023-11-05
+Arte
+2
+5
+";
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(contains("Owner ID: 123 (Alice)"));
+}
+
+#[test]
+fn test_cli_portfolio_json() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    let input = "\
+1
+token_portfolio_test
+42
+2023-11-05
+Art
+5
+";
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .write_stdin(input)
+        .assert()
+        .success();
+
+    let assert = Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .args(["portfolio", "42", "--format", "json"])
+        .assert()
+        .success();
+
+    let json: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    assert_eq!(json["owner_id"], 42);
+    assert_eq!(json["total"], 1);
+    assert_eq!(json["categories"][0]["category"], "Art");
+    assert_eq!(json["categories"][0]["nfts"][0]["token_id"], "token_portfolio_test");
+}
+
+#[test]
+fn test_cli_chart_piped_output_is_ascii() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+
+    let input = "\
+1
+token_chart_test
+42
+2023-11-05
+Art
+5
+";
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .write_stdin(input)
+        .assert()
+        .success();
+
+    let assert = Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path_str)
+        .arg("chart")
+        .assert()
+        .success()
+        .stdout(contains("NFTs por categoria"))
+        .stdout(contains("2023-11 ... 2023-11"));
+
+    let stdout = str::from_utf8(&assert.get_output().stdout).unwrap();
+    assert!(!stdout.contains('█') && !stdout.contains('…'));
+    assert!(stdout.contains("Art #"));
+}
+
+#[test]
+fn test_cli_menu_exits_at_end_of_input() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .write_stdin("2\n")
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .success()
+        .stdout(contains("Nenhum NFT encontrado."))
+        .stdout(contains("Saindo..."));
+}
+
+#[test]
+fn test_create_nft_from_cursor() {
+    use nft_manager::cli::commands::create_nft;
+    use nft_manager::cli::config::ValidationRules;
+    use nft_manager::models::token_id::TokenIdStrategy;
+    use nft_manager::storage::file_storage::FileStorage;
+    use std::io::Cursor;
+
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+    let (rules, strategy) = (ValidationRules::default(), TokenIdStrategy::default());
+
+    let mut input = Cursor::new("token_cursor\nabc\n12\n2023-10-22\nMusic\n");
+    create_nft(&mut input, db_path_str, &rules, &strategy).expect("Criação deveria ser concluída");
+
+    // Token ID repetido é recusado; vazio gera um UUID
+    let mut input = Cursor::new("token_cursor\n\n3\n2023-10-23\nArt\n");
+    create_nft(&mut input, db_path_str, &rules, &strategy).expect("Criação deveria ser concluída");
+
+    let nfts = FileStorage::new(db_path_str).load_all().unwrap();
+    assert_eq!(nfts.len(), 2);
+    assert_eq!(nfts[0].token_id, "token_cursor");
+    assert_eq!(nfts[0].owner_id, 12);
+    assert!(uuid::Uuid::parse_str(&nfts[1].token_id).is_ok());
+    assert_eq!(nfts[1].owner_id, 3);
+}
+
+#[test]
+fn test_create_nft_cancelled_or_truncated() {
+    use nft_manager::cli::commands::create_nft;
+    use nft_manager::cli::config::ValidationRules;
+    use nft_manager::cli::shell::PromptError;
+    use nft_manager::models::token_id::TokenIdStrategy;
+    use nft_manager::storage::file_storage::FileStorage;
+    use std::io::Cursor;
+
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path_str = db_path.to_str().unwrap();
+    let (rules, strategy) = (ValidationRules::default(), TokenIdStrategy::default());
+
+    // "cancel" interrompe uma criação pela metade
+    let mut input = Cursor::new("token_cancel\n12\nCANCEL\n2023-10-22\nArt\n");
+    assert!(matches!(
+        create_nft(&mut input, db_path_str, &rules, &strategy),
+        Err(PromptError::Cancelled)
+    ));
+
+    // O fim da entrada também cancela, inclusive durante a repetição de um prompt
+    let mut input = Cursor::new("token_truncated\n\n\n");
+    assert!(matches!(
+        create_nft(&mut input, db_path_str, &rules, &strategy),
+        Err(PromptError::EndOfInput)
+    ));
+
+    assert!(FileStorage::new(db_path_str).load_all().unwrap().is_empty());
+}
+
+#[test]
+fn test_cli_truncated_script_ends_session() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .write_stdin("1\ntoken_truncated\n")
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .success()
+        .stdout(contains("Fim da entrada; operação cancelada."))
+        .stdout(contains("Saindo..."));
+}
+
+#[test]
+fn test_cli_config_profile() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        "[profiles.prod]\nformat = \"json\"\n\n[profiles.prod.validation]\nmax_token_id_length = 5\n",
+    )
+    .unwrap();
+    let config_path = config_path.to_str().unwrap();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["config", "show", "--config", config_path, "--profile", "prod"])
+        .assert()
+        .success()
+        .stdout(contains("Perfil: prod"))
+        .stdout(contains("(variável de ambiente DB_PATH)"))
+        .stdout(contains("(perfil 'prod')"));
+
+    // O formato do perfil é usado quando --format não é informado
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["stats", "--config", config_path, "--profile", "prod"])
+        .assert()
+        .success()
+        .stdout(contains("\"total\": 0"));
+
+    // As regras de validação do perfil valem no menu interativo
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["--config", config_path, "--profile", "prod"])
+        .write_stdin("1\ntoken_longo\n1\n2023-01-01\nArt\n5\n")
+        .assert()
+        .success()
+        .stdout(contains("Token ID deve ter no máximo 5 caracteres"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["config", "show", "--config", config_path, "--profile", "qa"])
+        .assert()
+        .failure()
+        .stdout(contains("Perfil 'qa' não encontrado"));
+}
+
+#[test]
+fn test_cli_global_options_before_command() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, "[profiles.prod]\nformat = \"json\"\n").unwrap();
+    let config_path = config_path.to_str().unwrap();
+
+    // As opções antes do comando valem para ele, sem abrir o menu
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["--config", config_path, "--profile", "prod", "stats"])
+        .assert()
+        .success()
+        .stdout(contains("\"total\": 0"))
+        .stdout(contains("Gerenciador de NFTs").not());
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args([&format!("--config={}", config_path), "config", "show"])
+        .assert()
+        .success()
+        .stdout(contains(format!("Arquivo de configuração: {}", config_path)));
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env("DB_PATH", db_path.to_str().unwrap())
+        .args(["--config", config_path, "desconhecido"])
+        .assert()
+        .stdout(contains("Opção de linha de comando desconhecida: desconhecido"));
+}
+
+#[test]
+fn test_cli_named_databases_copy_and_move() {
+    let dir = tempdir().unwrap();
+    let client_a = dir.path().join("client_a.db");
+    let client_b = dir.path().join("client_b.db");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            "[databases]\nclient_a = {:?}\nclient_b = {:?}\n",
+            client_a.to_str().unwrap(),
+            client_b.to_str().unwrap()
+        ),
+    )
+    .unwrap();
+    let config_path = config_path.to_str().unwrap();
+
+    // Cria o mesmo Token ID nos dois bancos pelo menu
+    for db in ["client_a", "client_b"] {
+        Command::cargo_bin("nft_manager")
+            .unwrap()
+            .env_remove("DB_PATH")
+            .args(["--config", config_path, "--db", db])
+            .write_stdin("1\ntoken_shared\n7\n2023-02-01\nArt\n5\n")
+            .assert()
+            .success()
+            .stdout(contains("NFT salvo com sucesso!"));
+    }
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env_remove("DB_PATH")
+        .args(["copy", "client_b", "--db", "client_a", "--config", config_path])
+        .args(["--on-conflict", "rename"])
+        .assert()
+        .success()
+        .stdout(contains("Renomeado: token_shared -> token_shared_2"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env_remove("DB_PATH")
+        .args(["move", "client_b", "--db", "client_a", "--config", config_path])
+        .assert()
+        .success()
+        .stdout(contains("Ignorado (já existe no destino): token_shared"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env_remove("DB_PATH")
+        .args(["db", "list", "--config", config_path, "--db", "client_b"])
+        .assert()
+        .success()
+        .stdout(contains("* client_b"))
+        .stdout(contains("2 NFT(s)"));
+
+    // `--db` antes do comando seleciona o banco
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env_remove("DB_PATH")
+        .args(["--db", "client_b", "--config", config_path, "list"])
+        .assert()
+        .success()
+        .stdout(contains("token_shared_2"))
+        .stdout(contains("=== Gerenciador de NFTs ===").not());
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .env_remove("DB_PATH")
+        .args(["stats", "--config", config_path, "--db", "client_c"])
+        .assert()
+        .failure()
+        .stdout(contains("Banco de dados 'client_c' não registrado"));
+}
+
+#[test]
+fn test_cli_move_onto_same_database_through_alias_is_refused() {
+    use nft_manager::storage::file_storage::FileStorage;
+
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("s.db").to_str().unwrap());
+    storage
+        .save_all(&[nft_manager::models::nft::NFT::new(
+            "token_s".to_string(),
+            1,
+            chrono::NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+            nft_manager::models::category::Category::Art,
+        )])
+        .unwrap();
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, "").unwrap();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .current_dir(dir.path())
+        .env("DB_PATH", "s.db")
+        .args(["move", "./s.db", "--on-conflict", "overwrite"])
+        .args(["--config", config_path.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout(contains("Origem e destino são o mesmo banco de dados"));
+    assert_eq!(storage.load_all().unwrap().len(), 1);
+}
+
+#[test]
+fn test_cli_diff_and_merge() {
+    use nft_manager::storage::file_storage::FileStorage;
+
+    let dir = tempdir().unwrap();
+    let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+    let nft = |owner_id: u64| {
+        nft_manager::models::nft::NFT::new(
+            "token_merge".to_string(),
+            owner_id,
+            chrono::NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            nft_manager::models::category::Category::Music,
+        )
+    };
+    FileStorage::new(&path("base.db")).save_all(&[nft(1)]).unwrap();
+    FileStorage::new(&path("ours.db")).save_all(&[nft(2)]).unwrap();
+    FileStorage::new(&path("theirs.db")).save_all(&[nft(3)]).unwrap();
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .args(["diff", &path("base.db"), &path("ours.db")])
+        .assert()
+        .success()
+        .stdout(contains("~ token_merge"))
+        .stdout(contains("owner_id: 1 -> 2"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .args(["merge", &path("base.db"), &path("ours.db"), &path("theirs.db")])
+        .args(["-o", &path("out.db")])
+        .assert()
+        .failure()
+        .stdout(contains("Conflito em token_merge (owner_id)"))
+        .stdout(contains("nada foi gravado"));
+
+    Command::cargo_bin("nft_manager")
+        .unwrap()
+        .args(["merge", &path("base.db"), &path("ours.db"), &path("theirs.db")])
+        .args(["-o", &path("out.db"), "--on-conflict", "theirs"])
+        .assert()
+        .success()
+        .stdout(contains("1 conflito(s) resolvido(s)"));
+
+    let merged = FileStorage::new(&path("out.db")).load_all().unwrap();
+    assert_eq!(merged[0].owner_id, 3);
+}
+
+#[test]
+fn test_cli_create_generates_token_ids() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, "token_id_strategy = \"template:ART-{n:03}\"\n").unwrap();
+    let create = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path)
+            .args(["create", "--config", config_path.to_str().unwrap()])
+            .args(args)
+            .args(["--owner", "3", "--category", "Art", "--date", "2023-05-01"])
+            .assert()
+    };
+
+    // A estratégia vem da configuração, ou de --id-strategy
+    create(&[]).success().stdout(contains("Token ID: ART-001"));
+    create(&[]).success().stdout(contains("Token ID: ART-002"));
+    create(&["--id-strategy", "sequential"])
+        .success()
+        .stdout(contains("Token ID: 1"));
+    create(&["manual"]).success().stdout(contains("Token ID: manual"));
+    create(&["manual"])
+        .failure()
+        .stdout(contains("NFT com Token ID 'manual' já existe."));
+    create(&["--collection", "inexistente"]).failure();
+
+    let nfts = nft_manager::storage::file_storage::FileStorage::new(db_path.to_str().unwrap())
+        .load_all()
+        .unwrap();
+    let token_ids: Vec<&str> = nfts.iter().map(|n| n.token_id.as_str()).collect();
+    assert_eq!(token_ids, ["ART-001", "ART-002", "1", "manual"]);
+}
+
+#[test]
+fn test_cli_category_registry() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+
+    run(&[
+        "category",
+        "define",
+        "Ingresso",
+        "--alias",
+        "ticket",
+        "--description",
+        "Entradas para eventos",
+    ])
+    .success()
+    .stdout(contains("Categoria definida com sucesso!"));
+
+    // O apelido é resolvido para o nome canônico na criação
+    run(&["create", "t1", "--owner", "1", "--category", "TICKET "]).success();
+    run(&["show", "t1"])
+        .success()
+        .stdout(contains("Categoria: Other: Ingresso"));
+
+    run(&["create", "t2", "--owner", "1", "--category", "evento"]).success();
+    run(&["category", "merge", "evento", "--into", "ingresso"])
+        .success()
+        .stdout(contains("1 NFT(s) atualizado(s)"));
+    run(&["category", "list"])
+        .success()
+        .stdout(contains("Apelidos: ticket"))
+        .stdout(contains("NFTs: 2"));
+    run(&["category", "update", "ticket", "--remove-alias", "nada"])
+        .failure()
+        .stdout(contains("Apelido 'nada' não encontrado"));
+}
+
+#[test]
+fn test_cli_subcategories_and_tags() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+
+    run(&["create", "t1", "--owner", "1", "--category", "Art/Generative"]).success();
+    run(&["create", "t2", "--owner", "1", "--category", "Music", "--tag", "raro"]).success();
+    run(&["tag", "add", "t1", "raro", "1/1"])
+        .success()
+        .stdout(contains("2 marcador(es) adicionado(s) a t1."));
+    run(&["edit", "t2", "--category", "Art/Photography"]).success();
+
+    run(&["list", "--category", "art/generative"])
+        .success()
+        .stdout(contains("Categoria: Art/Generative"))
+        .stdout(contains("Marcadores: raro, 1/1"))
+        .stdout(contains("1 NFT(s) encontrado(s)."));
+    run(&["list", "--category", "Art", "--tag", "raro"])
+        .success()
+        .stdout(contains("2 NFT(s) encontrado(s)."));
+    run(&["tag", "rename", "raro", "rare"])
+        .success()
+        .stdout(contains("Marcador renomeado em 2 NFT(s)."));
+    run(&["tag", "remove", "t2", "raro"])
+        .failure()
+        .stdout(contains("Marcador 'raro' não encontrado"));
+    run(&["tag", "list"]).success().stdout(contains("rare"));
+}
+
+#[test]
+fn test_cli_bulk_update_and_delete() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    for n in 0..12 {
+        let token_id = format!("t{}", n);
+        run(&["create", &token_id, "--owner", "42", "--category", "Art"]).success();
+    }
+
+    // Acima do limite, a operação só é aplicada com --yes
+    run(&["update", "--where", "owner=42 and category=art", "--set", "owner=99"])
+        .failure()
+        .stdout(contains("12 NFT(s) selecionado(s) para atualização"))
+        .stdout(contains("repita com --yes"));
+    run(&["update", "--where", "owner=42", "--set", "owner=99", "--yes", "--dry-run"]).success();
+    run(&["list", "--owner", "42"])
+        .success()
+        .stdout(contains("12 NFT(s) encontrado(s)."));
+    run(&["update", "--where", "owner=42", "--set", "owner=99", "--yes"])
+        .success()
+        .stdout(contains("12 NFT(s) atualizado(s) com sucesso!"));
+
+    run(&["delete", "--where", "token=t3"])
+        .success()
+        .stdout(contains("1 NFT(s) deletado(s) com sucesso!"));
+    run(&["delete"])
+        .failure()
+        .stdout(contains("Informe --where"));
+    run(&["list", "--where", "owner=99"])
+        .success()
+        .stdout(contains("11 NFT(s) encontrado(s)."));
+}
+
+#[test]
+fn test_cli_where_query() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    for (token_id, owner, category, date) in [
+        ("a1", "1", "Art", "2022-03-01"),
+        ("m5", "5", "Music", "2022-08-01"),
+        ("a6", "6", "Art/Generative", "2024-01-10"),
+        ("t7", "7", "Ticket", "2021-01-01"),
+    ] {
+        run(&["create", token_id, "--owner", owner, "--category", category, "--date", date])
+            .success();
+    }
+
+    let query = "category in (art, music) and created < 2023-01-01 and owner not in (1, 2)";
+    run(&["list", "--where", query])
+        .success()
+        .stdout(contains("Token ID: m5"))
+        .stdout(contains("1 NFT(s) encontrado(s)."));
+    run(&["list", "--where", "owner in (1, 2"])
+        .failure()
+        .stdout(contains("Consulta inválida na posição 15: esperado ',' ou ')'"));
+
+    let export_dir = dir.path().join("export");
+    run(&["export", export_dir.to_str().unwrap(), "--where", "token_id matches '^a'"])
+        .success()
+        .stdout(contains("Documentos exportados: 2"));
+    assert!(export_dir.join("a6.json").exists());
+    assert!(!export_dir.join("m5.json").exists());
+
+    run(&["delete", "--where", "not category = art"])
+        .success()
+        .stdout(contains("2 NFT(s) deletado(s) com sucesso!"));
+    run(&["list"])
+        .success()
+        .stdout(contains("2 NFT(s) encontrado(s)."));
+}
+
+#[test]
+fn test_cli_search() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    run(&["create", "ART-0003", "--owner", "1", "--category", "Art"]).success();
+    run(&["create", "TCK-0001", "--owner", "1", "--category", "Concert ticket"]).success();
+
+    run(&["search", "art3"])
+        .success()
+        .stdout(contains("1 resultado(s) para 'art3':"))
+        .stdout(contains("1. [ART]-000[3]"));
+    run(&["search", "concert", "tiket"])
+        .success()
+        .stdout(contains("1. TCK-0001 (Categoria: Other: [Concert ti]c[ket])"));
+
+    // O índice acompanha as remoções
+    run(&["delete", "--where", "token_id = ART-0003"]).success();
+    run(&["search", "art3"])
+        .success()
+        .stdout(contains("Nenhum NFT encontrado para 'art3'."));
+    run(&["search"]).failure().stdout(contains("Uso: nft_manager search"));
+}
+
+#[test]
+fn test_cli_sale_and_royalties() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    run(&["create", "t1", "--owner", "1", "--category", "Art", "--date", "2023-01-01"]).success();
+    run(&["edit", "t1", "--royalty-bps", "750", "--royalty-recipient", "9"]).success();
+    run(&["show", "t1"])
+        .success()
+        .stdout(contains("Royalty: 7.50% para o Owner ID 9"));
+
+    let sale = [
+        "sale", "record", "t1", "--buyer", "2", "--price", "1,2", "--currency", "eth", "--date",
+        "2024-05-01",
+    ];
+    run(&sale)
+        .success()
+        .stdout(contains("Venda registrada: t1 transferido de 1 para 2 por 1.2 ETH."))
+        .stdout(contains("Royalty devido: 0.09 ETH (7.50% para o Owner ID 9)."));
+    run(&sale)
+        .failure()
+        .stdout(contains("Comprador já é o proprietário do NFT"));
+    run(&["show", "t1"]).success().stdout(contains("Owner ID: 2"));
+    run(&["sale", "list", "t1"])
+        .success()
+        .stdout(contains("2024-05-01 | t1 | 1 -> 2 | 1.2 ETH"));
+    run(&["sale", "royalties", "--from", "2024-01-01", "--to", "2024-12-31"])
+        .success()
+        .stdout(contains("Owner ID 9: 0.09 ETH em 1 venda(s)"));
+    run(&["sale", "royalties", "--from", "2025-01-01"])
+        .success()
+        .stdout(contains("Nenhum royalty devido no período."));
+}
+
+#[test]
+fn test_cli_valuation_history_and_report() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    run(&["create", "t1", "--owner", "1", "--category", "Art", "--date", "2023-01-01"]).success();
+    run(&["create", "t2", "--owner", "1", "--category", "Music", "--date", "2023-01-01"]).success();
+
+    let appraisal = [
+        "valuation", "record", "t1", "--amount", "2", "--currency", "eth", "--source", "Avaliador",
+        "--date", "2024-01-10",
+    ];
+    run(&appraisal)
+        .success()
+        .stdout(contains("Avaliação registrada: t1 vale 2 ETH em 2024-01-10."));
+    run(&[
+        "sale", "record", "t1", "--buyer", "2", "--price", "3", "--currency", "ETH", "--date",
+        "2024-03-01",
+    ])
+    .success();
+    run(&["show", "t1"])
+        .success()
+        .stdout(contains("Valor Atual: 3 ETH (Owner ID 1 -> 2, Venda em 2024-03-01)"))
+        .stdout(contains("2024-01-10 | Avaliação | 2 ETH | Avaliador"));
+
+    run(&["valuation", "report", "--as-of", "2024-02-01"])
+        .success()
+        .stdout(contains("Owner ID 1: 2 ETH em 2 NFT(s)"))
+        .stdout(contains("Sem avaliação: t2"));
+    run(&["valuation", "report", "--as-of", "2024-06-01"])
+        .success()
+        .stdout(contains("Owner ID 2: 3 ETH em 1 NFT(s)"))
+        .stdout(contains("Owner ID 1: - em 1 NFT(s)"));
+    run(&[
+        "valuation", "record", "t1", "--amount", "1", "--currency", "ETH", "--source", "X",
+        "--date", "2022-01-01",
+    ])
+    .failure()
+    .stdout(contains("Data da avaliação é anterior à criação do NFT"));
+}
+
+#[test]
+fn test_cli_share_split_and_transfer() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let run = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("nft_manager").unwrap();
+        cmd.env("DB_PATH", &db_path).args(args).assert()
+    };
+    run(&["create", "t1", "--owner", "1", "--category", "Art", "--date", "2023-01-01"]).success();
+    run(&["share", "split", "t1", "--total", "100"])
+        .success()
+        .stdout(contains("Cotas de t1: Owner ID 1 (100/100)"));
+    run(&["share", "transfer", "t1", "--from", "1", "--to", "2", "--units", "40"])
+        .success()
+        .stdout(contains("40 cota(s) de t1 transferida(s) de 1 para 2."))
+        .stdout(contains("Owner ID 1 (60/100), Owner ID 2 (40/100)"));
+    run(&["share", "transfer", "t1", "--from", "2", "--to", "3", "--units", "41"])
+        .failure()
+        .stdout(contains("Owner ID 2 possui apenas 40 cota(s)"));
+    run(&["show", "t1"])
+        .success()
+        .stdout(contains("Cotas: Owner ID 1 (60/100), Owner ID 2 (40/100)"));
+    run(&["portfolio", "2"])
+        .success()
+        .stdout(contains("Total de NFTs: 1 (0.4 em cotas equivalentes)"))
+        .stdout(contains("cota de 40%"));
+    run(&["share", "join", "t1"])
+        .failure()
+        .stdout(contains("tem 2 cotistas"));
+}
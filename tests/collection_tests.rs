@@ -0,0 +1,94 @@
+use chrono::NaiveDate;
+use nft_manager::cli::collections::{
+    process_assign_collection, process_create_collection, process_delete_collection,
+    process_list_collection_nfts, process_update_collection,
+};
+use nft_manager::models::category::Category;
+use nft_manager::models::collection::{Chain, Collection, Royalty};
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use tempfile::tempdir;
+
+fn sample_collection() -> Collection {
+    let mut collection = Collection::new(
+        "punks".to_string(),
+        "Crypto Punks".to_string(),
+        "PUNK".to_string(),
+        Chain::Ethereum,
+    );
+    collection.max_supply = Some(1);
+    collection.royalty = Some(Royalty {
+        basis_points: 250,
+        recipient_id: 42,
+    });
+    collection
+}
+
+fn sample_nft(token_id: &str) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    )
+}
+
+#[test]
+fn test_collection_validation() {
+    let mut collection = sample_collection();
+    assert!(collection.validate().is_ok());
+
+    collection.royalty = Some(Royalty {
+        basis_points: 10_001,
+        recipient_id: 42,
+    });
+    assert!(collection.validate().is_err());
+}
+
+#[test]
+fn test_collection_crud_and_membership() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    storage.save(&sample_nft("token_a")).unwrap();
+    storage.save(&sample_nft("token_b")).unwrap();
+
+    process_create_collection(&sample_collection(), &mut storage).unwrap();
+    assert!(process_create_collection(&sample_collection(), &mut storage).is_err());
+
+    assert!(process_assign_collection("token_a", Some("unknown"), &mut storage).is_err());
+    process_assign_collection("token_a", Some("punks"), &mut storage).unwrap();
+
+    // Fornecimento máximo de 1 NFT já atingido
+    assert!(process_assign_collection("token_b", Some("punks"), &mut storage).is_err());
+    // Reatribuir o mesmo NFT não conta contra o limite
+    process_assign_collection("token_a", Some("punks"), &mut storage).unwrap();
+
+    let (collection, nfts) = process_list_collection_nfts("punks", &mut storage).unwrap();
+    assert_eq!(collection.name, "Crypto Punks");
+    assert_eq!(nfts.len(), 1);
+    assert_eq!(nfts[0].token_id, "token_a");
+
+    let mut renamed = sample_collection();
+    renamed.name = "Punks".to_string();
+    process_update_collection(&renamed, &mut storage).unwrap();
+    assert_eq!(storage.load_collections().unwrap()[0].name, "Punks");
+}
+
+#[test]
+fn test_delete_collection_with_members_is_refused() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    storage.save(&sample_nft("token_a")).unwrap();
+
+    process_create_collection(&sample_collection(), &mut storage).unwrap();
+    process_assign_collection("token_a", Some("punks"), &mut storage).unwrap();
+
+    assert!(process_delete_collection("punks", &mut storage).is_err());
+
+    process_assign_collection("token_a", None, &mut storage).unwrap();
+    process_delete_collection("punks", &mut storage).unwrap();
+    assert!(storage.load_collections().unwrap().is_empty());
+    assert_eq!(storage.load_all().unwrap().len(), 1);
+}
@@ -0,0 +1,137 @@
+use nft_manager::cli::args::OutputFormat;
+use nft_manager::cli::config::{Config, ConfigFile, Source};
+use std::path::{Path, PathBuf};
+
+const TOML_CONFIG: &str = r#"
+default_profile = "staging"
+database = "base.db"
+format = "table"
+
+[profiles.staging]
+database = "staging.db"
+
+[profiles.prod]
+database = "prod.db"
+format = "json"
+
+[profiles.prod.validation]
+require_known_owners = true
+max_token_id_length = 16
+"#;
+
+fn config_file() -> (PathBuf, ConfigFile) {
+    let path = PathBuf::from("config.toml");
+    let file = ConfigFile::parse(&path, TOML_CONFIG).expect("Configuração deveria ser válida");
+    (path, file)
+}
+
+#[test]
+fn test_config_profiles_and_sources() {
+    // Sem arquivo, valem os padrões
+    let config = Config::resolve(None, None, None, None).unwrap();
+    assert_eq!(config.database.value, "nfts.db");
+    assert_eq!(config.database.source, Source::Default);
+
+    // O perfil padrão do arquivo é aplicado sobre o topo do arquivo
+    let config = Config::resolve(Some(config_file()), None, None, None).unwrap();
+    assert_eq!(config.profile.as_deref(), Some("staging"));
+    assert_eq!(config.database.value, "staging.db");
+    assert_eq!(
+        config.database.source,
+        Source::Profile("staging".to_string())
+    );
+    assert_eq!(
+        config.format.source,
+        Source::File(PathBuf::from("config.toml"))
+    );
+
+    let config = Config::resolve(Some(config_file()), Some("prod"), None, None).unwrap();
+    assert_eq!(config.format.value, OutputFormat::Json);
+    assert_eq!(config.validation_rules().require_known_owners, Some(true));
+    assert_eq!(config.validation_rules().max_token_id_length, Some(16));
+
+    // DB_PATH prevalece sobre o arquivo, mas não sobre um perfil escolhido com --profile
+    let config =
+        Config::resolve(Some(config_file()), None, Some("env.db".to_string()), None).unwrap();
+    assert_eq!(config.database.value, "env.db");
+    assert_eq!(config.database.source, Source::Env("DB_PATH"));
+    let config = Config::resolve(
+        Some(config_file()),
+        Some("prod"),
+        Some("env.db".to_string()),
+        None,
+    )
+    .unwrap();
+    assert_eq!(config.database.value, "prod.db");
+    assert_eq!(config.database.source, Source::Profile("prod".to_string()));
+
+    let err = Config::resolve(Some(config_file()), Some("qa"), None, None).unwrap_err();
+    assert!(err.contains("Perfil 'qa' não encontrado"));
+    assert!(Config::resolve(None, Some("prod"), None, None).is_err());
+}
+
+#[test]
+fn test_config_json_and_invalid_values() {
+    let path = Path::new("config.json");
+    let file = ConfigFile::parse(
+        path,
+        r#"{ "profiles": { "prod": { "database": "prod.db", "format": "json" } } }"#,
+    )
+    .unwrap();
+    assert!(file.profiles.contains_key("prod"));
+
+    let file = ConfigFile::parse(Path::new("config.toml"), "format = \"xml\"").unwrap();
+    assert!(
+        Config::resolve(Some((PathBuf::from("config.toml"), file)), None, None, None).is_err()
+    );
+
+    assert!(ConfigFile::parse(Path::new("config.toml"), "format = ").is_err());
+}
+
+#[test]
+fn test_config_rejects_unknown_keys() {
+    let toml_path = Path::new("config.toml");
+    let error =
+        ConfigFile::parse(toml_path, "database = \"x.db\"\nbackend = \"sqlite\"\n").unwrap_err();
+    assert_eq!(
+        error,
+        "Configuração inválida em config.toml: opção desconhecida 'backend'"
+    );
+
+    let error = ConfigFile::parse(toml_path, "[profiles.prod]\ncodec = \"json\"\n").unwrap_err();
+    assert!(error.ends_with("opção desconhecida 'codec'"), "{}", error);
+
+    let error =
+        ConfigFile::parse(toml_path, "[validation]\nmax_token_id_lenght = 3\n").unwrap_err();
+    assert!(
+        error.ends_with("opção desconhecida 'max_token_id_lenght'"),
+        "{}",
+        error
+    );
+
+    let error =
+        ConfigFile::parse(Path::new("config.json"), r#"{ "locale": "pt-BR" }"#).unwrap_err();
+    assert!(error.ends_with("opção desconhecida 'locale'"), "{}", error);
+}
+
+#[test]
+fn test_config_relative_database_paths() {
+    let file = ConfigFile::parse(
+        Path::new("config.toml"),
+        "database = \"main.db\"\n\n[databases]\nclient = \"clients/a.db\"\nabsolute = \"/srv/b.db\"\n",
+    )
+    .unwrap();
+    let path = PathBuf::from("/home/user/.config/nft_manager/config.toml");
+    let config = Config::resolve(Some((path, file)), None, None, Some("client")).unwrap();
+
+    // Caminhos relativos partem do diretório do arquivo de configuração
+    assert_eq!(
+        config.database.value,
+        "/home/user/.config/nft_manager/clients/a.db"
+    );
+    assert_eq!(config.database_path("absolute"), "/srv/b.db".to_string());
+    assert_eq!(
+        config.databases["client"].value,
+        "/home/user/.config/nft_manager/clients/a.db"
+    );
+}
@@ -0,0 +1,311 @@
+use chrono::NaiveDate;
+use nft_manager::cli::args::Args;
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::databases::{process_copy, same_database, ConflictStrategy, NftFilter};
+use nft_manager::models::category::{Category, CategoryDefinition, CategoryPath};
+use nft_manager::models::money::Money;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::sale::Sale;
+use nft_manager::models::valuation::Valuation;
+use nft_manager::storage::file_storage::FileStorage;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, owner_id: u64, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        category,
+    )
+}
+
+fn storages(dir: &tempfile::TempDir) -> (FileStorage, FileStorage) {
+    let mut source = FileStorage::new(dir.path().join("a.db").to_str().unwrap());
+    let mut destination = FileStorage::new(dir.path().join("b.db").to_str().unwrap());
+    source
+        .save_all(&[
+            nft("t1", 1, Category::Art),
+            nft("t2", 2, Category::Music),
+            nft("t3", 1, Category::Art),
+        ])
+        .unwrap();
+    destination
+        .save_all(&[nft("t1", 9, Category::Collectible)])
+        .unwrap();
+    (source, destination)
+}
+
+#[test]
+fn test_copy_conflict_strategies() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    let filter = NftFilter {
+        category: Some(CategoryPath::new(Category::Art, Vec::new())),
+        ..NftFilter::default()
+    };
+
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Skip,
+        false,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+    assert_eq!(report.copied, vec!["t3"]);
+    assert_eq!(report.skipped, vec!["t1"]);
+    assert_eq!(destination.load_all().unwrap()[0].owner_id, 9);
+
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Rename,
+        false,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        report.renamed,
+        vec![
+            ("t1".to_string(), "t1_2".to_string()),
+            ("t3".to_string(), "t3_2".to_string())
+        ]
+    );
+
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &NftFilter::default(),
+        ConflictStrategy::Overwrite,
+        false,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+    assert_eq!(report.overwritten, vec!["t1", "t3"]);
+    assert_eq!(report.copied, vec!["t2"]);
+
+    let stored = destination.load_all().unwrap();
+    assert_eq!(stored.len(), 5);
+    assert_eq!(
+        stored.iter().find(|n| n.token_id == "t1").unwrap().owner_id,
+        1
+    );
+    assert_eq!(source.load_all().unwrap().len(), 3);
+}
+
+#[test]
+fn test_move_keeps_skipped_in_source() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    let filter = NftFilter {
+        owner_id: Some(1),
+        ..NftFilter::default()
+    };
+
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Skip,
+        true,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+    assert_eq!(report.transferred(), 1);
+
+    let remaining: Vec<String> = source
+        .load_all()
+        .unwrap()
+        .into_iter()
+        .map(|n| n.token_id)
+        .collect();
+    assert_eq!(remaining, vec!["t1", "t2"]);
+    assert_eq!(destination.load_all().unwrap().len(), 2);
+
+    // As duas pontas continuam com o histórico íntegro
+    let nfts = source.load_all().unwrap();
+    assert!(source.ledger().verify(&nfts).unwrap().is_valid());
+    let nfts = destination.load_all().unwrap();
+    assert!(destination.ledger().verify(&nfts).unwrap().is_valid());
+}
+
+#[test]
+fn test_move_failure_after_destination_write_keeps_nfts() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    // O histórico da origem não pode ser gravado: a origem é regravada, mas
+    // `save_all` falha depois da gravação do destino
+    let ledger = dir.path().join("a.db.ledger");
+    std::fs::remove_file(&ledger).unwrap();
+    std::fs::create_dir(&ledger).unwrap();
+
+    let filter = NftFilter {
+        token_ids: vec!["t2".to_string()],
+        ..NftFilter::default()
+    };
+    let err = process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Skip,
+        true,
+        &ValidationRules::default(),
+    )
+    .unwrap_err();
+    assert!(err.contains("estão no destino"), "{}", err);
+
+    // O NFT não se perde: está no destino e não está mais na origem
+    let in_destination = destination.load_all().unwrap();
+    assert!(in_destination.iter().any(|n| n.token_id == "t2"));
+    assert!(!source
+        .load_all()
+        .unwrap()
+        .iter()
+        .any(|n| n.token_id == "t2"));
+}
+
+#[test]
+fn test_copy_enforces_token_id_length() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    let rules = ValidationRules {
+        max_token_id_length: Some(2),
+        ..ValidationRules::default()
+    };
+
+    // t1_2 excede o limite: nada é gravado no destino
+    let err = process_copy(
+        &mut source,
+        &mut destination,
+        &NftFilter::default(),
+        ConflictStrategy::Rename,
+        false,
+        &rules,
+    )
+    .unwrap_err();
+    assert!(err.contains("no máximo 2 caracteres: 't1_2'"));
+    assert_eq!(destination.load_all().unwrap().len(), 1);
+
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &NftFilter::default(),
+        ConflictStrategy::Skip,
+        false,
+        &rules,
+    )
+    .unwrap();
+    assert_eq!(report.copied, vec!["t2", "t3"]);
+}
+
+#[test]
+fn test_move_carries_sales_and_valuations() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    let sale = |token_id: &str| Sale {
+        token_id: token_id.to_string(),
+        seller_id: 1,
+        buyer_id: 2,
+        price: Money::from_str("1 ETH").unwrap(),
+        date,
+        royalty: None,
+    };
+    let valuation = |token_id: &str, value: &str| Valuation {
+        token_id: token_id.to_string(),
+        value: Money::from_str(value).unwrap(),
+        date,
+        source: "Avaliador".to_string(),
+    };
+    source.save_sales(&[sale("t1"), sale("t2")]).unwrap();
+    source
+        .save_valuations(&[valuation("t1", "2 ETH"), valuation("t3", "3 ETH")])
+        .unwrap();
+    destination
+        .save_valuations(&[valuation("t1", "9 ETH")])
+        .unwrap();
+
+    let filter = NftFilter {
+        token_ids: vec!["t1".to_string(), "t3".to_string()],
+        ..NftFilter::default()
+    };
+    process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Rename,
+        true,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+
+    // No destino, "t1" virou "t1_2" e o "t1" original mantém a sua avaliação
+    assert_eq!(destination.load_sales().unwrap(), vec![sale("t1_2")]);
+    assert_eq!(
+        destination.load_valuations().unwrap(),
+        vec![
+            valuation("t1", "9 ETH"),
+            valuation("t1_2", "2 ETH"),
+            valuation("t3", "3 ETH")
+        ]
+    );
+    // Na origem ficam só as do NFT que não foi movido
+    assert_eq!(source.load_sales().unwrap(), vec![sale("t2")]);
+    assert!(source.load_valuations().unwrap().is_empty());
+}
+
+#[test]
+fn test_copy_filter_uses_source_categories() {
+    let dir = tempdir().unwrap();
+    let (mut source, mut destination) = storages(&dir);
+    let mut ingresso = CategoryDefinition::new("Ingresso");
+    ingresso.aliases.push("ticket".to_string());
+    let mut nfts = source.load_all().unwrap();
+    nfts.push(nft("t4", 1, Category::Other("Ingresso".to_string())));
+    source.save_all_with_categories(&nfts, &[ingresso]).unwrap();
+    destination
+        .save_categories(&[CategoryDefinition::new("Ticket")])
+        .unwrap();
+
+    // "ticket" é apelido de Ingresso na origem, mas outra categoria no destino
+    let raw = ["--category".to_string(), "ticket".to_string()];
+    let args = Args::parse(&raw, &[]).unwrap();
+    let filter = NftFilter::load(&args, &mut source).unwrap();
+    let report = process_copy(
+        &mut source,
+        &mut destination,
+        &filter,
+        ConflictStrategy::Skip,
+        false,
+        &ValidationRules::default(),
+    )
+    .unwrap();
+    assert_eq!(report.copied, vec!["t4"]);
+}
+
+#[test]
+fn test_same_database_resolves_path_aliases() {
+    let dir = tempdir().unwrap();
+    let (_source, _destination) = storages(&dir);
+    let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+    let alias = |name: &str| {
+        dir.path()
+            .join(".")
+            .join("sub")
+            .join("..")
+            .join(name)
+            .to_str()
+            .unwrap()
+            .to_string()
+    };
+    std::fs::create_dir(dir.path().join("sub")).unwrap();
+
+    assert!(same_database(&path("a.db"), &alias("a.db")));
+    assert!(!same_database(&path("a.db"), &path("b.db")));
+    // Destino que ainda não existe
+    assert!(same_database(&path("novo.db"), &alias("novo.db")));
+    assert!(!same_database(&path("a.db"), &alias("novo.db")));
+}
@@ -0,0 +1,173 @@
+use chrono::NaiveDate;
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::databases::NftFilter;
+use nft_manager::cli::erc721::{process_export_metadata, process_import_metadata};
+use nft_manager::models::category::{Category, CategoryPath};
+use nft_manager::models::metadata::AttributeValue;
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::erc721::{ImportOptions, DEFAULT_CATEGORY_ATTRIBUTE};
+use nft_manager::storage::file_storage::FileStorage;
+use std::fs;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn import_options() -> ImportOptions {
+    ImportOptions {
+        owner_id: 42,
+        creation_date: NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        category_attribute: "Type".to_string(),
+        default_category: None,
+    }
+}
+
+#[test]
+fn test_import_metadata_directory() {
+    let dir = tempdir().unwrap();
+    let metadata_dir = dir.path().join("metadata");
+    fs::create_dir(&metadata_dir).unwrap();
+    fs::write(
+        metadata_dir.join("1.json"),
+        r#"{
+            "name": "Punk #1",
+            "description": "Primeiro punk",
+            "image": "ipfs://QmHash/1.png",
+            "attributes": [
+                {"trait_type": "Type", "value": "Art"},
+                {"trait_type": "Level", "value": 5, "display_type": "number"}
+            ]
+        }"#,
+    )
+    .unwrap();
+    fs::write(metadata_dir.join("2.json"), r#"{"name": "Sem categoria"}"#).unwrap();
+    fs::write(metadata_dir.join("notes.txt"), "ignorado").unwrap();
+
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+
+    let rules = ValidationRules::default();
+    let report =
+        process_import_metadata(&metadata_dir, &import_options(), &rules, &mut storage).unwrap();
+    assert_eq!(report.imported, vec!["1".to_string()]);
+    assert_eq!(report.failed.len(), 1);
+
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(nfts.len(), 1);
+    assert_eq!(nfts[0].token_id, "1");
+    assert_eq!(nfts[0].owner_id, 42);
+    assert_eq!(nfts[0].category, Category::Art);
+    assert_eq!(nfts[0].metadata.name.as_deref(), Some("Punk #1"));
+    assert_eq!(nfts[0].metadata.attributes.len(), 1);
+    assert_eq!(nfts[0].metadata.attributes[0].value, AttributeValue::Number(5.0));
+
+    // Reimportar não duplica tokens existentes
+    let report =
+        process_import_metadata(&metadata_dir, &import_options(), &rules, &mut storage).unwrap();
+    assert_eq!(report.skipped, vec!["1".to_string()]);
+    assert_eq!(storage.load_all().unwrap().len(), 1);
+}
+
+#[test]
+fn test_export_metadata_round_trip() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+
+    let mut nft = NFT::new(
+        "token_export".to_string(),
+        42,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Music,
+    );
+    nft.metadata.name = Some("Faixa".to_string());
+    storage.save(&nft).unwrap();
+
+    let export_dir = dir.path().join("export");
+    let report = process_export_metadata(
+        &export_dir,
+        DEFAULT_CATEGORY_ATTRIBUTE,
+        &NftFilter::default(),
+        &mut storage,
+    )
+    .unwrap();
+    assert_eq!(report.exported, vec!["token_export".to_string()]);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(export_dir.join("token_export.json")).unwrap())
+            .unwrap();
+    assert_eq!(json["name"], "Faixa");
+    assert_eq!(json["attributes"][0]["trait_type"], "Category");
+    assert_eq!(json["attributes"][0]["value"], "Music");
+
+    let other_path = dir.path().join("other.db");
+    let mut other = FileStorage::new(other_path.to_str().unwrap());
+    let options = ImportOptions {
+        category_attribute: DEFAULT_CATEGORY_ATTRIBUTE.to_string(),
+        ..import_options()
+    };
+    process_import_metadata(&export_dir, &options, &ValidationRules::default(), &mut other)
+        .unwrap();
+    assert_eq!(other.load_all().unwrap(), vec![nft]);
+}
+
+#[test]
+fn test_export_metadata_round_trip_with_subcategories() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+
+    let mut nft = NFT::new(
+        "token_path".to_string(),
+        42,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+    nft.set_category_path(CategoryPath::from_str("Art/Generative/Fractal").unwrap());
+    storage.save(&nft).unwrap();
+
+    let export_dir = dir.path().join("export");
+    process_export_metadata(
+        &export_dir,
+        DEFAULT_CATEGORY_ATTRIBUTE,
+        &NftFilter::default(),
+        &mut storage,
+    )
+    .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(export_dir.join("token_path.json")).unwrap())
+            .unwrap();
+    assert_eq!(json["attributes"][0]["value"], "Art/Generative/Fractal");
+
+    let other_path = dir.path().join("other.db");
+    let mut other = FileStorage::new(other_path.to_str().unwrap());
+    let options = ImportOptions {
+        category_attribute: DEFAULT_CATEGORY_ATTRIBUTE.to_string(),
+        ..import_options()
+    };
+    process_import_metadata(&export_dir, &options, &ValidationRules::default(), &mut other)
+        .unwrap();
+    let imported = other.load_all().unwrap();
+    assert_eq!(imported, vec![nft]);
+    assert_eq!(imported[0].subcategories, vec!["Generative", "Fractal"]);
+}
+
+#[test]
+fn test_import_metadata_enforces_token_id_length() {
+    let dir = tempdir().unwrap();
+    let metadata_dir = dir.path().join("metadata");
+    fs::create_dir(&metadata_dir).unwrap();
+    let document = r#"{"attributes": [{"trait_type": "Type", "value": "Art"}]}"#;
+    fs::write(metadata_dir.join("1.json"), document).unwrap();
+    fs::write(metadata_dir.join("token_longo.json"), document).unwrap();
+
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    let rules = ValidationRules {
+        max_token_id_length: Some(4),
+        ..ValidationRules::default()
+    };
+    let report =
+        process_import_metadata(&metadata_dir, &import_options(), &rules, &mut storage).unwrap();
+    assert_eq!(report.imported, vec!["1".to_string()]);
+    assert_eq!(report.failed.len(), 1);
+    assert!(report.failed[0].1.contains("no máximo 4 caracteres"));
+}
@@ -0,0 +1,172 @@
+use chrono::NaiveDate;
+use nft_manager::cli::commands::{
+    process_create_nft, process_delete_nft, process_update_nft, process_verify_ledger,
+};
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::{FileStorage, StorageError};
+use nft_manager::storage::ledger::{LedgerAction, LedgerIssue, GENESIS_HASH};
+use std::fs::File;
+use tempfile::tempdir;
+
+fn sample_nft(token_id: &str) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    )
+}
+
+fn populated_storage(dir: &tempfile::TempDir) -> FileStorage {
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+
+    process_create_nft(&sample_nft("token_a"), &mut storage).unwrap();
+    process_create_nft(&sample_nft("token_b"), &mut storage).unwrap();
+    process_update_nft("token_a", 456u64, &ValidationRules::default(), &mut storage).unwrap();
+    process_delete_nft("token_b", &mut storage).unwrap();
+    storage
+}
+
+#[test]
+fn test_ledger_records_chained_mutations() {
+    let dir = tempdir().unwrap();
+    let storage = populated_storage(&dir);
+
+    let entries = storage.ledger().load_all().unwrap();
+    let actions: Vec<LedgerAction> = entries.iter().map(|e| e.action.clone()).collect();
+    assert_eq!(
+        actions,
+        vec![
+            LedgerAction::Create,
+            LedgerAction::Create,
+            LedgerAction::Update,
+            LedgerAction::Delete
+        ]
+    );
+    assert_eq!(entries[0].prev_hash, GENESIS_HASH);
+    for pair in entries.windows(2) {
+        assert_eq!(pair[1].prev_hash, pair[0].hash);
+    }
+}
+
+#[test]
+fn test_verify_intact_ledger() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.entries_checked, 4);
+}
+
+#[test]
+fn test_verify_detects_tampered_entry() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    let ledger = storage.ledger();
+    let mut entries = ledger.load_all().unwrap();
+    entries[1].nft.as_mut().unwrap().owner_id = 999;
+    ledger.save_all(&entries).unwrap();
+
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(report.issue, Some(LedgerIssue::Tampered { sequence: 2 }));
+}
+
+#[test]
+fn test_verify_detects_rehashed_entry() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    let ledger = storage.ledger();
+    let mut entries = ledger.load_all().unwrap();
+    entries[1].nft.as_mut().unwrap().owner_id = 999;
+    entries[1].hash = entries[1].compute_hash();
+    ledger.save_all(&entries).unwrap();
+
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(report.issue, Some(LedgerIssue::BrokenLink { sequence: 3 }));
+}
+
+#[test]
+fn test_verify_detects_missing_entry() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    let ledger = storage.ledger();
+    let mut entries = ledger.load_all().unwrap();
+    entries.remove(2);
+    ledger.save_all(&entries).unwrap();
+
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(
+        report.issue,
+        Some(LedgerIssue::Missing {
+            expected: 3,
+            found: 4
+        })
+    );
+}
+
+#[test]
+fn test_verify_detects_database_edited_outside_ledger() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    // Grava o banco diretamente, sem passar pelo histórico
+    let mut nft = sample_nft("token_a");
+    nft.owner_id = 777;
+    let file = File::create(&storage.file_path).unwrap();
+    serde_cbor::to_writer(file, &vec![nft]).unwrap();
+
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(
+        report.issue,
+        Some(LedgerIssue::StateMismatch {
+            token_id: "token_a".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_verify_requires_migrate_when_history_is_missing() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    // Sem o histórico, novas gravações não o recriam a partir do estado atual
+    std::fs::remove_file(&storage.ledger().file_path).unwrap();
+    process_create_nft(&sample_nft("token_c"), &mut storage).unwrap();
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(report.issue, Some(LedgerIssue::MissingHistory));
+    assert!(storage.ledger().load_all().unwrap().is_empty());
+
+    storage.migrate().unwrap();
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.entries_checked, 2);
+}
+
+#[test]
+fn test_duplicate_token_ids_are_detected() {
+    let dir = tempdir().unwrap();
+    let mut storage = populated_storage(&dir);
+
+    let mut nft = sample_nft("token_a");
+    nft.owner_id = 456;
+    let result = storage.save_all(&[nft.clone(), nft.clone()]);
+    assert!(matches!(result, Err(StorageError::DuplicateTokenId(ref t)) if t == "token_a"));
+
+    // Gravado por fora, o NFT repetido não se esconde atrás do último
+    let file = File::create(&storage.file_path).unwrap();
+    serde_cbor::to_writer(file, &vec![nft.clone(), nft]).unwrap();
+    let report = process_verify_ledger(&mut storage).unwrap();
+    assert_eq!(
+        report.issue,
+        Some(LedgerIssue::DuplicateTokenId {
+            token_id: "token_a".to_string()
+        })
+    );
+}
@@ -0,0 +1,172 @@
+use chrono::NaiveDate;
+use nft_manager::cli::merge::{diff_nfts, merge_nfts, process_merge, ConflictPolicy};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, owner_id: u64) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(2023, 6, 1).expect("Data inválida"),
+        Category::Art,
+    )
+}
+
+fn named(mut nft: NFT, name: &str) -> NFT {
+    nft.metadata.name = Some(name.to_string());
+    nft
+}
+
+#[test]
+fn test_diff_reports_fields() {
+    let a = vec![nft("kept", 1), nft("gone", 1), nft("edited", 1)];
+    let mut edited = named(nft("edited", 2), "Novo nome");
+    edited.category = Category::Other("Ingresso".to_string());
+    let b = vec![nft("kept", 1), edited, nft("new", 3)];
+
+    let diff = diff_nfts(&a, &b);
+    assert_eq!(diff.added, vec!["new"]);
+    assert_eq!(diff.removed, vec!["gone"]);
+    assert_eq!(diff.changed.len(), 1);
+
+    let changes = &diff.changed[0].changes;
+    let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(fields, vec!["category", "metadata.name", "owner_id"]);
+    assert_eq!(changes[0].after.as_deref(), Some("Other: Ingresso"));
+    assert_eq!(changes[1].before, None);
+    assert_eq!(changes[2].before.as_deref(), Some("1"));
+
+    assert!(diff_nfts(&a, &a).is_empty());
+}
+
+#[test]
+fn test_three_way_merge() {
+    let base = vec![nft("a", 1), nft("b", 1), nft("c", 1)];
+    // Nós: transferimos "a" e removemos "c"
+    let ours = vec![nft("a", 2), nft("b", 1)];
+    // Eles: nomeiam "a", alteram "b" e criam "d"
+    let theirs = vec![
+        named(nft("a", 1), "Alfa"),
+        nft("b", 5),
+        nft("c", 1),
+        nft("d", 4),
+    ];
+
+    let result = merge_nfts(&base, &ours, &theirs, ConflictPolicy::Fail).unwrap();
+    assert!(result.conflicts.is_empty());
+    assert_eq!(
+        result.nfts,
+        vec![named(nft("a", 2), "Alfa"), nft("b", 5), nft("d", 4)]
+    );
+}
+
+#[test]
+fn test_merge_conflicts_and_policies() {
+    let base = vec![nft("a", 1), nft("b", 1)];
+    let ours = vec![nft("a", 2)];
+    let theirs = vec![nft("a", 3), nft("b", 9)];
+
+    let result = merge_nfts(&base, &ours, &theirs, ConflictPolicy::Fail).unwrap();
+    assert_eq!(result.conflicts.len(), 2);
+    assert_eq!(result.conflicts[0].field.as_deref(), Some("owner_id"));
+    assert_eq!(result.conflicts[0].ours.as_deref(), Some("2"));
+    assert_eq!(result.conflicts[0].theirs.as_deref(), Some("3"));
+    assert_eq!(result.conflicts[1].token_id, "b");
+    assert_eq!(result.conflicts[1].field, None);
+
+    let result = merge_nfts(&base, &ours, &theirs, ConflictPolicy::Theirs).unwrap();
+    assert_eq!(result.nfts, vec![nft("a", 3), nft("b", 9)]);
+    let result = merge_nfts(&base, &ours, &theirs, ConflictPolicy::Ours).unwrap();
+    assert_eq!(result.nfts, vec![nft("a", 2)]);
+
+    // Com a política padrão, nada é gravado enquanto houver conflitos
+    let dir = tempdir().unwrap();
+    let open = |name: &str| FileStorage::new(dir.path().join(name).to_str().unwrap());
+    open("base.db").save_all(&base).unwrap();
+    open("ours.db").save_all(&ours).unwrap();
+    open("theirs.db").save_all(&theirs).unwrap();
+
+    let result = process_merge(
+        &mut open("base.db"),
+        &mut open("ours.db"),
+        &mut open("theirs.db"),
+        &mut open("out.db"),
+        ConflictPolicy::Fail,
+    )
+    .unwrap();
+    assert_eq!(result.conflicts.len(), 2);
+    assert!(!dir.path().join("out.db").exists());
+
+    process_merge(
+        &mut open("base.db"),
+        &mut open("ours.db"),
+        &mut open("theirs.db"),
+        &mut open("out.db"),
+        ConflictPolicy::Theirs,
+    )
+    .unwrap();
+    assert_eq!(open("out.db").load_all().unwrap().len(), 2);
+}
+
+#[test]
+fn test_merge_rejects_invalid_result_and_writes_once() {
+    use nft_manager::models::money::Money;
+    use nft_manager::models::sale::Sale;
+    use nft_manager::models::shares::Shares;
+    use std::str::FromStr;
+
+    // Nós transferimos "a"; eles dividiram "a" em cotas do proprietário anterior
+    let base = vec![nft("a", 1), nft("b", 1)];
+    let ours = vec![nft("a", 2), nft("b", 1)];
+    let mut shared = nft("a", 1);
+    shared.shares = Some(Shares::whole(1, 100));
+    let theirs = vec![shared, nft("b", 1)];
+
+    let result = merge_nfts(&base, &ours, &theirs, ConflictPolicy::Fail).unwrap();
+    assert!(result.conflicts.is_empty());
+
+    let dir = tempdir().unwrap();
+    let open = |name: &str| FileStorage::new(dir.path().join(name).to_str().unwrap());
+    open("base.db").save_all(&base).unwrap();
+    open("ours.db").save_all(&ours).unwrap();
+    open("theirs.db").save_all(&theirs).unwrap();
+
+    let error = process_merge(
+        &mut open("base.db"),
+        &mut open("ours.db"),
+        &mut open("theirs.db"),
+        &mut open("out.db"),
+        ConflictPolicy::Fail,
+    )
+    .unwrap_err();
+    assert!(
+        error.starts_with("NFT 'a' mesclado é inválido:"),
+        "{}",
+        error
+    );
+    assert!(!dir.path().join("out.db").exists());
+
+    // Sem o conflito de cotas, NFTs e vendas são gravados juntos
+    open("theirs.db").save_all(&base).unwrap();
+    let sale = Sale {
+        token_id: "b".to_string(),
+        seller_id: 1,
+        buyer_id: 3,
+        price: Money::from_str("10 ETH").unwrap(),
+        date: NaiveDate::from_ymd_opt(2023, 7, 1).expect("Data inválida"),
+        royalty: None,
+    };
+    open("theirs.db").save_sales(std::slice::from_ref(&sale)).unwrap();
+    process_merge(
+        &mut open("base.db"),
+        &mut open("ours.db"),
+        &mut open("theirs.db"),
+        &mut open("out.db"),
+        ConflictPolicy::Fail,
+    )
+    .unwrap();
+    assert_eq!(open("out.db").load_all().unwrap(), ours);
+    assert_eq!(open("out.db").load_sales().unwrap(), vec![sale]);
+}
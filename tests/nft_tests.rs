@@ -2,6 +2,7 @@ use chrono::NaiveDate;
 use nft_manager::cli::commands::{
     collect_nft_data, process_create_nft, process_delete_nft, process_update_nft,
 };
+use nft_manager::cli::config::ValidationRules;
 use nft_manager::models::category::Category;
 use nft_manager::models::nft::NFT;
 use nft_manager::storage::file_storage::FileStorage;
@@ -44,6 +45,7 @@ fn test_collect_nft_data_valid() {
         123u64,
         NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
         Category::Other("Arte".to_string()), // Ajustado para usar Category
+        &ValidationRules::default(),
     );
 
     assert!(nft.is_ok());
@@ -56,6 +58,7 @@ fn test_collect_nft_data_invalid() {
         0u64,           // owner_id inválido
         NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
         Category::Other("".to_string()), // Ajustado para usar Category
+        &ValidationRules::default(),
     );
 
     assert!(nft.is_err());
@@ -100,13 +103,50 @@ fn test_process_update_nft() {
     let mut storage = FileStorage::new(file_path_str);
     storage.save(&nft).unwrap();
 
-    let result = process_update_nft("token_update_test", 456u64, &mut storage);
+    let result = process_update_nft(
+        "token_update_test",
+        456u64,
+        &ValidationRules::default(),
+        &mut storage,
+    );
     assert!(result.is_ok());
 
     let nfts = storage.load_all().unwrap();
     assert_eq!(nfts[0].owner_id, 456u64);
 }
 
+#[test]
+fn test_transfer_clears_previous_owner_address() {
+    use nft_manager::cli::commands::process_find_nft;
+    use nft_manager::models::wallet::WalletAddress;
+    use std::str::FromStr;
+
+    let mut nft = NFT::new(
+        "token_address".to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+    nft.owner_address =
+        Some(WalletAddress::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap());
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage.save(&nft).unwrap();
+
+    // Manter o mesmo proprietário preserva o endereço
+    process_update_nft("token_address", 1, &ValidationRules::default(), &mut storage).unwrap();
+    assert!(process_find_nft("token_address", &mut storage)
+        .unwrap()
+        .owner_address
+        .is_some());
+
+    process_update_nft("token_address", 2, &ValidationRules::default(), &mut storage).unwrap();
+    let shown = process_find_nft("token_address", &mut storage).unwrap();
+    assert_eq!(shown.owner_id, 2);
+    assert_eq!(shown.owner_address, None);
+    assert!(shown.validate().is_ok());
+}
+
 #[test]
 fn test_process_delete_nft() {
     let nft = NFT::new(
@@ -150,7 +190,12 @@ fn test_update_nonexistent_nft() {
 
     let mut storage = FileStorage::new(file_path_str);
 
-    let result = process_update_nft("nonexistent_token", 456u64, &mut storage);
+    let result = process_update_nft(
+        "nonexistent_token",
+        456u64,
+        &ValidationRules::default(),
+        &mut storage,
+    );
     assert!(result.is_err());
 }
 
@@ -165,3 +210,127 @@ fn test_delete_nonexistent_nft() {
     let result = process_delete_nft("nonexistent_token", &mut storage);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_nft_metadata_validation() {
+    use nft_manager::models::metadata::{Attribute, AttributeValue, NftMetadata};
+
+    let base = NFT::new(
+        "token_metadata".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+
+    let mut metadata = NftMetadata {
+        name: Some("Punk #1".to_string()),
+        image: Some("ipfs://QmHash/1.png".to_string()),
+        ..NftMetadata::default()
+    };
+    metadata.set_attribute(Attribute::new("Nível", AttributeValue::Number(5.0)));
+    assert!(base.clone().with_metadata(metadata.clone()).validate().is_ok());
+
+    let mut invalid_uri = metadata.clone();
+    invalid_uri.external_url = Some("ftp://example.com".to_string());
+    assert!(base.clone().with_metadata(invalid_uri).validate().is_err());
+
+    let mut invalid_display = metadata;
+    invalid_display.set_attribute(Attribute {
+        trait_type: "Cor".to_string(),
+        value: AttributeValue::Text("Azul".to_string()),
+        display_type: Some("number".to_string()),
+    });
+    assert!(base.with_metadata(invalid_display).validate().is_err());
+}
+
+#[test]
+fn test_process_update_metadata() {
+    use nft_manager::cli::args::Args;
+    use nft_manager::cli::commands::{apply_metadata_args, process_update_metadata};
+    use nft_manager::models::metadata::{AttributeValue, NftMetadata};
+
+    let nft = NFT::new(
+        "token_metadata_test".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    storage.save(&nft).unwrap();
+
+    let raw: Vec<String> = ["--name", "Punk #1", "--attribute", "Nível=5", "--attribute", "Cor=Azul"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let args = Args::parse(&raw, &[]).unwrap();
+    let mut metadata = NftMetadata::default();
+    apply_metadata_args(&mut metadata, &args).unwrap();
+
+    let result = process_update_metadata("token_metadata_test", metadata, &mut storage);
+    assert!(result.is_ok());
+
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(nfts[0].metadata.name.as_deref(), Some("Punk #1"));
+    assert_eq!(nfts[0].metadata.attributes.len(), 2);
+    assert_eq!(nfts[0].metadata.attributes[0].value, AttributeValue::Number(5.0));
+}
+
+#[test]
+fn test_attribute_value_keeps_leading_zeros_as_text() {
+    use nft_manager::models::metadata::AttributeValue;
+
+    assert_eq!(AttributeValue::parse("007"), AttributeValue::Text("007".to_string()));
+    assert_eq!(AttributeValue::parse("-01"), AttributeValue::Text("-01".to_string()));
+    assert_eq!(AttributeValue::parse(" 5 "), AttributeValue::Number(5.0));
+    assert_eq!(AttributeValue::parse("0"), AttributeValue::Number(0.0));
+    assert_eq!(AttributeValue::parse("0.5"), AttributeValue::Number(0.5));
+    assert_eq!(AttributeValue::parse("Azul"), AttributeValue::Text("Azul".to_string()));
+}
+
+#[test]
+fn test_process_edit_nft_writes_once() {
+    use nft_manager::cli::args::Args;
+    use nft_manager::cli::commands::process_edit_nft;
+    use nft_manager::models::category::CategoryPath;
+
+    let nft = NFT::new(
+        "token_edit_test".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    storage.save(&nft).unwrap();
+    let args = |raw: &[&str]| {
+        let raw: Vec<String> = raw.iter().map(|s| s.to_string()).collect();
+        Args::parse(&raw, &[]).unwrap()
+    };
+
+    // Um atributo inexistente invalida a edição inteira
+    let invalid = args(&["--category", "Music/Jazz", "--remove-attribute", "Cor"]);
+    assert!(process_edit_nft("token_edit_test", &invalid, &mut storage).is_err());
+    assert_eq!(storage.load_all().unwrap()[0], nft);
+
+    let valid = args(&[
+        "--category",
+        "Music/Jazz",
+        "--royalty-bps",
+        "250",
+        "--royalty-recipient",
+        "7",
+        "--name",
+        "Faixa",
+    ]);
+    let entries = storage.ledger().load_all().unwrap().len();
+    let edited = process_edit_nft("token_edit_test", &valid, &mut storage).unwrap();
+    assert_eq!(edited.category_path(), "Music/Jazz".parse::<CategoryPath>().unwrap());
+    assert_eq!(edited.royalty.as_ref().map(|r| r.basis_points), Some(250));
+    assert_eq!(edited.metadata.name.as_deref(), Some("Faixa"));
+    assert_eq!(storage.load_all().unwrap()[0], edited);
+    assert_eq!(storage.ledger().load_all().unwrap().len(), entries + 1);
+}
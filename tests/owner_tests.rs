@@ -0,0 +1,88 @@
+use chrono::NaiveDate;
+use nft_manager::cli::commands::process_update_nft;
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::owners::{
+    process_create_owner, process_delete_owner, process_set_owner_policy, process_update_owner,
+};
+use nft_manager::cli::shares::{process_split_shares, process_transfer_shares};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::owner::Owner;
+use nft_manager::storage::file_storage::FileStorage;
+use tempfile::tempdir;
+
+fn storage_with_nft(dir: &tempfile::TempDir) -> FileStorage {
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    let nft = NFT::new(
+        "token_owner_test".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+    storage.save(&nft).unwrap();
+    storage
+}
+
+#[test]
+fn test_owner_crud() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage_with_nft(&dir);
+    let rules = ValidationRules::default();
+
+    let mut owner = Owner::new(123, "Alice".to_string());
+    owner.wallets
+        .push("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string());
+    process_create_owner(&owner, &mut storage).unwrap();
+    assert!(process_create_owner(&owner, &mut storage).is_err());
+    assert!(process_create_owner(&Owner::new(7, " ".to_string()), &mut storage).is_err());
+
+    let mut invalid_wallet = Owner::new(8, "Carol".to_string());
+    invalid_wallet.wallets.push("0xabc".to_string());
+    assert!(process_create_owner(&invalid_wallet, &mut storage).is_err());
+
+    owner.display_name = "Alice Silva".to_string();
+    process_update_owner(&owner, &mut storage).unwrap();
+    assert_eq!(storage.load_owners().unwrap()[0].display_name, "Alice Silva");
+
+    // Ainda possui o NFT
+    assert!(process_delete_owner(123, &mut storage).is_err());
+    process_update_nft("token_owner_test", 456, &rules, &mut storage).unwrap();
+    process_delete_owner(123, &mut storage).unwrap();
+    assert!(storage.load_owners().unwrap().is_empty());
+}
+
+#[test]
+fn test_delete_owner_holding_shares_is_refused() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage_with_nft(&dir);
+    let rules = ValidationRules::default();
+    process_create_owner(&Owner::new(456, "Bob".to_string()), &mut storage).unwrap();
+
+    process_split_shares("token_owner_test", 100, &mut storage).unwrap();
+    process_transfer_shares("token_owner_test", 123, 456, 10, &rules, &mut storage).unwrap();
+
+    // Bob não é o proprietário principal, mas tem cotas
+    let error = process_delete_owner(456, &mut storage).unwrap_err();
+    assert!(error.contains("cotas"), "{}", error);
+
+    process_transfer_shares("token_owner_test", 456, 123, 10, &rules, &mut storage).unwrap();
+    process_delete_owner(456, &mut storage).unwrap();
+    assert!(storage.load_owners().unwrap().is_empty());
+}
+
+#[test]
+fn test_transfer_requires_known_owner_when_strict() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage_with_nft(&dir);
+    let rules = ValidationRules::default();
+    process_create_owner(&Owner::new(456, "Bob".to_string()), &mut storage).unwrap();
+
+    process_set_owner_policy(true, &mut storage).unwrap();
+    assert!(process_update_nft("token_owner_test", 789, &rules, &mut storage).is_err());
+    process_update_nft("token_owner_test", 456, &rules, &mut storage).unwrap();
+
+    process_set_owner_policy(false, &mut storage).unwrap();
+    process_update_nft("token_owner_test", 789, &rules, &mut storage).unwrap();
+    assert_eq!(storage.load_all().unwrap()[0].owner_id, 789);
+}
@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+use nft_manager::cli::commands::{process_create_nft, process_update_nft};
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::owners::process_create_owner;
+use nft_manager::cli::portfolio::{compute_portfolio, process_portfolio, resolve_owner};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::owner::Owner;
+use nft_manager::storage::file_storage::FileStorage;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, owner_id: u64, day: u32, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(2023, 11, day).expect("Data inválida"),
+        category,
+    )
+}
+
+#[test]
+fn test_compute_portfolio_groups_by_category() {
+    let nfts = vec![
+        nft("art_new", 42, 20, Category::Art),
+        nft("art_old", 42, 1, Category::Art),
+        nft("music", 42, 10, Category::Music),
+        nft("other_owner", 7, 5, Category::Art),
+    ];
+
+    let portfolio = compute_portfolio(42, &nfts, &[], &[]);
+    assert_eq!(portfolio.total, 3);
+    assert_eq!(portfolio.owner_name, None);
+    assert_eq!(portfolio.categories.len(), 2);
+
+    let art = &portfolio.categories[0];
+    assert_eq!(art.category, "Art");
+    assert_eq!(art.count, 2);
+    assert_eq!(art.oldest, NaiveDate::from_ymd_opt(2023, 11, 1).unwrap());
+    assert_eq!(art.newest, NaiveDate::from_ymd_opt(2023, 11, 20).unwrap());
+    assert_eq!(art.nfts[0].token_id, "art_old");
+    assert_eq!(art.nfts[0].acquired_on, None);
+}
+
+#[test]
+fn test_process_portfolio_uses_history_for_acquisitions() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("nfts_test.db");
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+
+    process_create_owner(&Owner::new(42, "Alice".to_string()), &mut storage).unwrap();
+    process_create_nft(&nft("token_a", 7, 5, Category::Art), &mut storage).unwrap();
+    process_create_nft(&nft("token_b", 42, 6, Category::Music), &mut storage).unwrap();
+    process_update_nft("token_a", 42, &ValidationRules::default(), &mut storage).unwrap();
+
+    let owners = storage.load_owners().unwrap();
+    assert_eq!(resolve_owner("alice", &owners), Ok(42));
+    assert!(resolve_owner("bob", &owners).is_err());
+
+    let portfolio = process_portfolio("Alice", &mut storage).unwrap();
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(portfolio.owner_name.as_deref(), Some("Alice"));
+    assert_eq!(portfolio.total, 2);
+    assert!(portfolio
+        .categories
+        .iter()
+        .flat_map(|c| &c.nfts)
+        .all(|h| h.acquired_on == Some(today)));
+}
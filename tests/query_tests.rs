@@ -0,0 +1,146 @@
+use chrono::NaiveDate;
+use nft_manager::models::category::{Category, CategoryPath};
+use nft_manager::models::nft::NFT;
+use nft_manager::models::query::Query;
+use std::str::FromStr;
+
+fn nft(token_id: &str, owner_id: u64, year: i32, category: &str) -> NFT {
+    let mut nft = NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(year, 6, 1).expect("Data inválida"),
+        Category::Art,
+    );
+    nft.set_category_path(CategoryPath::from_str(category).unwrap());
+    nft
+}
+
+fn selected(expression: &str, nfts: &[NFT]) -> Vec<String> {
+    let query = Query::from_str(expression).unwrap();
+    nfts.iter()
+        .filter(|nft| query.matches(nft))
+        .map(|nft| nft.token_id.clone())
+        .collect()
+}
+
+fn sample() -> Vec<NFT> {
+    let mut tagged = nft("ART-0003", 3, 2024, "Art/Generative");
+    tagged.add_tag("Rare");
+    tagged.collection_id = Some("punks".to_string());
+    vec![
+        nft("ART-0001", 1, 2021, "Art"),
+        nft("MUS-0001", 5, 2022, "Music"),
+        tagged,
+        nft("TCK-0001", 2, 2020, "Ticket"),
+    ]
+}
+
+#[test]
+fn test_query_combines_conditions() {
+    let nfts = sample();
+    assert_eq!(
+        selected(
+            "category in (art, music) and created < 2023-01-01 and owner not in (1, 2)",
+            &nfts
+        ),
+        vec!["MUS-0001"]
+    );
+    // `and` tem precedência sobre `or`; parênteses mudam o agrupamento
+    assert_eq!(
+        selected("owner = 2 or owner = 1 and created > 2021-12-31", &nfts),
+        vec!["TCK-0001"]
+    );
+    assert_eq!(
+        selected("(owner = 2 or owner = 1) and created > 2019-12-31", &nfts),
+        vec!["ART-0001", "TCK-0001"]
+    );
+    assert_eq!(
+        selected("not category = Art and OWNER >= 2", &nfts),
+        vec!["MUS-0001", "TCK-0001"]
+    );
+}
+
+#[test]
+fn test_query_text_operators() {
+    let nfts = sample();
+    assert_eq!(
+        selected("category = art", &nfts),
+        vec!["ART-0001", "ART-0003"]
+    );
+    assert_eq!(
+        selected("category contains GENERATIVE", &nfts),
+        vec!["ART-0003"]
+    );
+    assert_eq!(
+        selected("token_id matches '^ART-\\d{3}[13]$'", &nfts),
+        vec!["ART-0001", "ART-0003"]
+    );
+    assert_eq!(selected("token ~ 'CK'", &nfts), vec!["TCK-0001"]);
+    assert_eq!(
+        selected("tag = rare and collection = punks", &nfts),
+        vec!["ART-0003"]
+    );
+    assert_eq!(selected("collection != punks", &nfts).len(), 3);
+    assert_eq!(
+        selected(
+            "category = 'Art/Generative' or token_id = \"TCK-0001\"",
+            &nfts
+        ),
+        vec!["ART-0003", "TCK-0001"]
+    );
+}
+
+#[test]
+fn test_query_errors_report_position() {
+    let cases = [
+        ("", 1, "consulta vazia"),
+        ("owner = 1 and", 14, "esperado um campo"),
+        ("price = 2", 1, "campo 'price' desconhecido"),
+        ("owner = abc", 9, "não é um Owner ID válido"),
+        ("created < 2023-13-01", 11, "data inválida"),
+        ("category < art", 10, "não se aplica"),
+        ("owner contains 1", 7, "não se aplica"),
+        ("token_id matches '('", 18, "expressão regular inválida"),
+        ("owner in (1, 2", 15, "esperado ',' ou ')'"),
+        ("(owner = 1", 11, "esperado ')'"),
+        ("owner = 1 owner = 2", 11, "'owner' inesperado"),
+        ("tag = 'rare", 7, "aspas sem fechamento"),
+        ("owner ! 1", 7, "esperado '!='"),
+    ];
+    for (expression, position, message) in cases {
+        let error = Query::from_str(expression).unwrap_err();
+        assert_eq!(error.position, position, "{}", expression);
+        assert!(
+            error.message.contains(message),
+            "{}: {}",
+            expression,
+            error.message
+        );
+    }
+
+    let rendered = Query::from_str("owner = abc")
+        .unwrap_err()
+        .render("owner = abc");
+    assert!(rendered.starts_with("Consulta inválida na posição 9:"));
+    assert!(rendered.ends_with("\n  owner = abc\n          ^"));
+}
+
+#[test]
+fn test_query_depth_is_limited() {
+    // 64 níveis são aceitos; o 65º é apontado na mensagem
+    let nested = |prefix: &str, levels: usize| {
+        format!("{}owner = 1{}", prefix.repeat(levels), ")".repeat(levels))
+    };
+    assert!(Query::from_str(&nested("(", 64)).is_ok());
+    let error = Query::from_str(&nested("(", 65)).unwrap_err();
+    assert_eq!(error.position, 65);
+    assert!(error.message.contains("complexa demais"));
+
+    let error = Query::from_str(&format!("{}owner = 1", "not ".repeat(100_000))).unwrap_err();
+    assert_eq!(error.position, 64 * 4 + 1);
+
+    let chain = |terms: usize| vec!["owner = 1"; terms].join(" or ");
+    assert!(Query::from_str(&chain(65)).is_ok());
+    let error = Query::from_str(&chain(66)).unwrap_err();
+    assert_eq!(error.position, 65 * "owner = 1 or ".len() - 2);
+}
@@ -0,0 +1,262 @@
+use chrono::NaiveDate;
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::sales::{process_record_sale, process_royalty_report};
+use nft_manager::models::category::Category;
+use nft_manager::models::collection::{Chain, Collection, Royalty};
+use nft_manager::models::money::{accumulate, Money};
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn date(month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(2024, month, day)
+}
+
+fn money(text: &str) -> Money {
+    Money::from_str(text).unwrap()
+}
+
+fn storage(dir: &tempfile::TempDir) -> FileStorage {
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    let mut collection = Collection::new(
+        "punks".to_string(),
+        "Punks".to_string(),
+        "PNK".to_string(),
+        Chain::Ethereum,
+    );
+    collection.royalty = Some(Royalty {
+        basis_points: 500,
+        recipient_id: 7,
+    });
+    storage.save_collections(&[collection]).unwrap();
+
+    let nft = |token_id: &str| {
+        let mut nft = NFT::new(
+            token_id.to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            Category::Art,
+        );
+        nft.collection_id = Some("punks".to_string());
+        nft
+    };
+    let mut own_royalty = nft("b");
+    own_royalty.royalty = Some(Royalty {
+        basis_points: 250,
+        recipient_id: 8,
+    });
+    let mut plain = nft("c");
+    plain.collection_id = None;
+    storage.save_all(&[nft("a"), own_royalty, plain]).unwrap();
+    storage
+}
+
+#[test]
+fn test_money_arithmetic_is_exact() {
+    assert_eq!(
+        money("0.3 eth").basis_points(333).unwrap().to_string(),
+        "0.00999 ETH"
+    );
+    assert_eq!(Money::parse("1,5", "usd").unwrap().to_string(), "1.5 USD");
+    assert!(Money::parse("-1", "ETH").is_err());
+    assert!(Money::parse("abc", "ETH").is_err());
+    assert!(Money::parse("1", "E").is_err());
+    assert!(Money::from_str("1").is_err());
+}
+
+#[test]
+fn test_record_sale_transfers_and_computes_royalty() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+
+    let sale = process_record_sale(
+        "a",
+        2,
+        money("2.5 ETH"),
+        date(3, 1),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    assert_eq!((sale.seller_id, sale.buyer_id), (1, 2));
+    assert_eq!(sale.royalty_due().unwrap(), Some(money("0.125 ETH")));
+    let sale = process_record_sale(
+        "b",
+        3,
+        money("10 ETH"),
+        date(3, 2),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    assert_eq!(sale.royalty_due().unwrap(), Some(money("0.25 ETH")));
+    let sale = process_record_sale(
+        "c",
+        3,
+        money("10 ETH"),
+        date(3, 3),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    assert_eq!(sale.royalty_due().unwrap(), None);
+
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(
+        nfts.iter().map(|n| n.owner_id).collect::<Vec<u64>>(),
+        [2, 3, 3]
+    );
+    assert_eq!(storage.load_sales().unwrap().len(), 3);
+
+    // O royalty vigente fica gravado na venda
+    let mut collections = storage.load_collections().unwrap();
+    collections[0].royalty = None;
+    storage.save_collections(&collections).unwrap();
+    process_record_sale(
+        "a",
+        4,
+        money("1000 USD"),
+        date(4, 1),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    assert_eq!(
+        storage.load_sales().unwrap()[0].royalty_due().unwrap(),
+        Some(money("0.125 ETH"))
+    );
+
+    // Comprador já proprietário, NFT inexistente e venda antes da criação
+    let invalid = [
+        ("a", 4, date(4, 2)),
+        ("zz", 5, date(4, 2)),
+        ("a", 5, NaiveDate::from_ymd_opt(2022, 12, 31)),
+    ];
+    for (token_id, buyer_id, sale_date) in invalid {
+        assert!(process_record_sale(
+            token_id,
+            buyer_id,
+            money("1 ETH"),
+            sale_date,
+            &ValidationRules::default(),
+            &mut storage
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn test_royalty_report_by_recipient_and_period() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    process_record_sale(
+        "a",
+        2,
+        money("2.5 ETH"),
+        date(1, 10),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    process_record_sale(
+        "a",
+        3,
+        money("1.5 ETH"),
+        date(2, 10),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    process_record_sale(
+        "b",
+        3,
+        money("100 USD"),
+        date(2, 15),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    process_record_sale(
+        "a",
+        4,
+        money("0.1 ETH"),
+        date(3, 10),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+
+    let totals = process_royalty_report(None, None, &mut storage).unwrap();
+    let summary: Vec<(u64, String, usize)> = totals
+        .iter()
+        .map(|t| (t.recipient_id, t.total.to_string(), t.sales))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (7, "0.205 ETH".to_string(), 3),
+            (8, "2.5 USD".to_string(), 1)
+        ]
+    );
+
+    let february = process_royalty_report(date(2, 1), date(2, 29), &mut storage).unwrap();
+    assert_eq!(february.len(), 2);
+    assert_eq!(february[0].total, money("0.075 ETH"));
+}
+
+#[test]
+fn test_out_of_range_amounts_are_errors() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let max = money("79228162514264337593543950335 ETH");
+    assert!(max.basis_points(500).is_err());
+
+    // O royalty não pode ser calculado: nada é gravado
+    let err = process_record_sale(
+        "a",
+        2,
+        max.clone(),
+        date(3, 1),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap_err();
+    assert!(err.contains("fora do intervalo suportado"));
+    assert!(storage.load_sales().unwrap().is_empty());
+    assert_eq!(storage.load_all().unwrap()[0].owner_id, 1);
+
+    // Totais que estouram viram erro no relatório
+    let half = money("40000000000000000000000000000 ETH");
+    let sale = process_record_sale(
+        "c",
+        2,
+        half.clone(),
+        date(3, 1),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    let mut totals = Vec::new();
+    accumulate(&mut totals, &sale.price).unwrap();
+    assert!(accumulate(&mut totals, &half).is_err());
+}
+
+#[test]
+fn test_record_sale_rejects_backdated_sales() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let rules = ValidationRules::default();
+    process_record_sale("a", 2, money("1 ETH"), date(3, 10), &rules, &mut storage).unwrap();
+
+    let err =
+        process_record_sale("a", 3, money("1 ETH"), date(3, 9), &rules, &mut storage).unwrap_err();
+    assert!(err.contains("anterior à última venda registrada do NFT (2024-03-10)"));
+    assert_eq!(storage.load_sales().unwrap().len(), 1);
+    assert_eq!(storage.load_all().unwrap()[0].owner_id, 2);
+
+    // Outra venda na mesma data e vendas de outros NFTs continuam aceitas
+    process_record_sale("a", 3, money("1 ETH"), date(3, 10), &rules, &mut storage).unwrap();
+    process_record_sale("b", 3, money("1 ETH"), date(3, 1), &rules, &mut storage).unwrap();
+    assert_eq!(storage.load_sales().unwrap().len(), 3);
+}
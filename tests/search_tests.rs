@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use nft_manager::cli::search::{highlight, process_search, HighlightStyle};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use nft_manager::storage::search_index::SearchIndex;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        category,
+    )
+}
+
+fn token_ids(query: &str, storage: &mut FileStorage) -> Vec<String> {
+    process_search(query, 10, storage)
+        .unwrap()
+        .into_iter()
+        .map(|hit| hit.token_id)
+        .collect()
+}
+
+#[test]
+fn test_search_ranks_fuzzy_matches() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    let mut named = nft("MUS-0042", Category::Music);
+    named.metadata.name = Some("Sunset Artwork".to_string());
+    storage
+        .save_all(&[
+            nft("ART-0003", Category::Art),
+            nft("ART-0013", Category::Art),
+            named,
+            nft("TCK-0001", Category::Other("Concert ticket".to_string())),
+        ])
+        .unwrap();
+
+    // Identidade antes de prefixo, prefixo antes de trecho
+    assert_eq!(token_ids("art-0003", &mut storage)[0], "ART-0003");
+    assert_eq!(
+        token_ids("art", &mut storage),
+        vec!["ART-0003", "ART-0013", "MUS-0042"]
+    );
+    // Caracteres em ordem e erros de digitação
+    assert_eq!(
+        token_ids("art3", &mut storage)[..2],
+        ["ART-0003", "ART-0013"]
+    );
+    assert_eq!(token_ids("ATR-0003", &mut storage)[0], "ART-0003");
+    assert_eq!(token_ids("cocnert", &mut storage), vec!["TCK-0001"]);
+
+    let hits = process_search("ticket", 10, &mut storage).unwrap();
+    assert_eq!(hits[0].label, "Categoria");
+    assert_eq!(hits[0].text, "Other: Concert ticket");
+    assert_eq!(hits[0].positions, (15..21).collect::<Vec<usize>>());
+    assert!(process_search("zzzz", 10, &mut storage).unwrap().is_empty());
+}
+
+#[test]
+fn test_search_index_updates_incrementally() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts.db");
+    let mut storage = FileStorage::new(db_path.to_str().unwrap());
+    storage.save(&nft("alpha", Category::Art)).unwrap();
+    storage.save(&nft("beta", Category::Art)).unwrap();
+    assert!(dir.path().join("nfts.db.index").exists());
+
+    let mut nfts = storage.load_all().unwrap();
+    nfts[0].add_tag("genesis");
+    nfts.retain(|n| n.token_id != "beta");
+    nfts.push(nft("gamma", Category::Music));
+    storage.save_all(&nfts).unwrap();
+
+    assert_eq!(token_ids("genesis", &mut storage), vec!["alpha"]);
+    assert!(token_ids("beta", &mut storage).is_empty());
+    assert_eq!(token_ids("gamma", &mut storage), vec!["gamma"]);
+
+    // Um índice que não corresponde ao banco é reconstruído na busca
+    SearchIndex::for_database(db_path.to_str().unwrap())
+        .rebuild(&[])
+        .unwrap();
+    assert_eq!(token_ids("gamma", &mut storage), vec!["gamma"]);
+
+    // Assim como um índice com os mesmos Token IDs e conteúdo antigo
+    let mut nfts = storage.load_all().unwrap();
+    nfts[0].metadata.name = Some("Zephyr".to_string());
+    let file = std::fs::File::create(&db_path).unwrap();
+    serde_cbor::to_writer(file, &nfts).unwrap();
+    assert_eq!(token_ids("zephyr", &mut storage), vec!["alpha"]);
+}
+
+#[test]
+fn test_highlight() {
+    assert_eq!(
+        highlight("ART-0003", &[0, 1, 2, 7], HighlightStyle::Brackets),
+        "[ART]-000[3]"
+    );
+    assert_eq!(
+        highlight("Arte", &[0], HighlightStyle::Ansi),
+        "\x1b[1;33mA\x1b[0mrte"
+    );
+}
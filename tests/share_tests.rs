@@ -0,0 +1,210 @@
+use chrono::NaiveDate;
+use nft_manager::cli::bulk::{process_bulk_update, BulkChange};
+use nft_manager::cli::commands::process_update_nft;
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::databases::NftFilter;
+use nft_manager::cli::portfolio::process_portfolio;
+use nft_manager::cli::sales::process_record_sale;
+use nft_manager::cli::shares::{
+    process_join_shares, process_split_shares, process_transfer_shares,
+};
+use nft_manager::cli::valuations::{process_record_valuation, process_valuation_report};
+use nft_manager::models::category::Category;
+use nft_manager::models::money::Money;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::shares::{ShareHolding, Shares};
+use nft_manager::models::valuation::Valuation;
+use nft_manager::storage::file_storage::FileStorage;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn storage(dir: &tempfile::TempDir) -> FileStorage {
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    let nft = |token_id: &str, category: Category| {
+        NFT::new(
+            token_id.to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            category,
+        )
+    };
+    storage
+        .save_all(&[nft("a", Category::Art), nft("b", Category::Music)])
+        .unwrap();
+    storage
+}
+
+fn holders(nft: &NFT) -> Vec<(u64, u64)> {
+    nft.shares
+        .as_ref()
+        .map(|s| s.holders.iter().map(|h| (h.owner_id, h.units)).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_shares_validation() {
+    let shares = |total: u64, holders: &[(u64, u64)]| Shares {
+        total,
+        holders: holders
+            .iter()
+            .map(|&(owner_id, units)| ShareHolding { owner_id, units })
+            .collect(),
+    };
+    assert!(shares(100, &[(1, 60), (2, 40)]).validate().is_ok());
+    assert!(shares(0, &[]).validate().is_err());
+    assert!(shares(100, &[(1, 60), (2, 50)]).validate().is_err());
+    assert!(shares(100, &[(1, 60), (2, 30)]).validate().is_err());
+    assert!(shares(100, &[(1, 100), (2, 0)]).validate().is_err());
+    assert!(shares(100, &[(1, 50), (1, 50)]).validate().is_err());
+    assert!(shares(2, &[(1, u64::MAX), (2, 3)]).validate().is_err());
+
+    let mut nft = NFT::new(
+        "a".to_string(),
+        3,
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        Category::Art,
+    );
+    nft.shares = Some(shares(100, &[(1, 60), (2, 40)]));
+    assert!(nft.validate().is_err(), "proprietário sem cotas");
+    nft.owner_id = 2;
+    assert!(nft.validate().is_err(), "proprietário minoritário");
+    nft.owner_id = 1;
+    assert!(nft.validate().is_ok());
+    assert_eq!(nft.share_of(2), Some(Decimal::from_str("0.4").unwrap()));
+    assert_eq!(nft.share_of(3), None);
+}
+
+#[test]
+fn test_split_transfer_and_join() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+
+    let nft = process_split_shares("a", 100, &mut storage).unwrap();
+    assert_eq!(holders(&nft), vec![(1, 100)]);
+    assert!(process_split_shares("a", 10, &mut storage).is_err());
+    assert!(process_split_shares("b", 0, &mut storage).is_err());
+
+    process_transfer_shares("a", 1, 2, 30, &ValidationRules::default(), &mut storage).unwrap();
+    let nft =
+        process_transfer_shares("a", 1, 3, 45, &ValidationRules::default(), &mut storage).unwrap();
+    assert_eq!(holders(&nft), vec![(1, 25), (2, 30), (3, 45)]);
+    assert_eq!(nft.owner_id, 3, "maior cotista");
+
+    // Saldo insuficiente, quantidade zero, NFT sem cotas e transferência integral
+    assert!(
+        process_transfer_shares("a", 1, 2, 26, &ValidationRules::default(), &mut storage).is_err()
+    );
+    assert!(
+        process_transfer_shares("a", 2, 3, 0, &ValidationRules::default(), &mut storage).is_err()
+    );
+    assert!(
+        process_transfer_shares("b", 1, 2, 1, &ValidationRules::default(), &mut storage).is_err()
+    );
+    assert!(process_update_nft("a", 9, &ValidationRules::default(), &mut storage).is_err());
+    assert!(process_join_shares("a", &mut storage).is_err());
+
+    process_transfer_shares("a", 1, 3, 25, &ValidationRules::default(), &mut storage).unwrap();
+    let nft =
+        process_transfer_shares("a", 2, 3, 30, &ValidationRules::default(), &mut storage).unwrap();
+    assert_eq!(holders(&nft), vec![(3, 100)]);
+    let nft = process_join_shares("a", &mut storage).unwrap();
+    assert_eq!((nft.owner_id, nft.shares), (3, None));
+}
+
+#[test]
+fn test_reports_account_for_fractional_holdings() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    process_split_shares("a", 3, &mut storage).unwrap();
+    process_transfer_shares("a", 1, 2, 1, &ValidationRules::default(), &mut storage).unwrap();
+
+    let portfolio = process_portfolio("2", &mut storage).unwrap();
+    assert_eq!(portfolio.total, 1);
+    assert_eq!(portfolio.categories[0].nfts[0].token_id, "a");
+    let portfolio = process_portfolio("1", &mut storage).unwrap();
+    assert_eq!(portfolio.total, 2);
+    assert_eq!(
+        portfolio.equivalent.round_dp(4),
+        Decimal::from_str("1.6667").unwrap()
+    );
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    for (token_id, value) in [("a", "9 ETH"), ("b", "1 ETH")] {
+        let valuation = Valuation {
+            token_id: token_id.to_string(),
+            value: Money::from_str(value).unwrap(),
+            date,
+            source: "Avaliador".to_string(),
+        };
+        process_record_valuation(&valuation, &mut storage).unwrap();
+    }
+    let owners = |as_of: NaiveDate, storage: &mut FileStorage| {
+        let report = process_valuation_report(as_of, storage).unwrap();
+        assert_eq!(report.totals[0].to_string(), "10 ETH");
+        report
+            .owners
+            .iter()
+            .map(|o| (o.owner_id, o.nfts, o.totals[0].to_string()))
+            .collect::<Vec<(u64, usize, String)>>()
+    };
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(
+        owners(today, &mut storage),
+        vec![(1, 2, "7 ETH".to_string()), (2, 1, "3 ETH".to_string())]
+    );
+    // As cotas foram transferidas hoje, segundo o histórico
+    assert_eq!(owners(date, &mut storage), vec![(1, 2, "10 ETH".to_string())]);
+}
+
+#[test]
+fn test_shared_nfts_cannot_be_transferred_whole() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let rules = ValidationRules::default();
+    process_split_shares("a", 10, &mut storage).unwrap();
+    process_transfer_shares("a", 1, 2, 1, &rules, &mut storage).unwrap();
+
+    let err = process_update_nft("a", 2, &rules, &mut storage).unwrap_err();
+    assert!(err.contains("share transfer"));
+
+    let price = Money::from_str("1 ETH").unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 2, 1);
+    let err = process_record_sale("a", 2, price, date, &rules, &mut storage).unwrap_err();
+    assert!(err.contains("share transfer"));
+    assert!(storage.load_sales().unwrap().is_empty());
+
+    let change = BulkChange {
+        owner_id: Some(2),
+        ..BulkChange::default()
+    };
+    let err =
+        process_bulk_update(&NftFilter::default(), &change, &rules, &mut storage).unwrap_err();
+    assert!(err.contains("a: NFT 'a' está dividido em cotas"));
+
+    // Nada mudou: o maior cotista continua sendo o proprietário
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(
+        (nfts[0].owner_id, holders(&nfts[0])),
+        (1, vec![(1, 9), (2, 1)])
+    );
+    assert_eq!(nfts[1].owner_id, 1);
+}
+
+#[test]
+fn test_money_share_does_not_overflow() {
+    let money = |text: &str| Money::from_str(text).unwrap();
+    assert_eq!(
+        money("10 ETH").share(1, 3).unwrap().amount.round_dp(4),
+        Decimal::from_str("3.3333").unwrap()
+    );
+    assert_eq!(money("9 ETH").share(1, 3).unwrap(), money("3 ETH"));
+
+    // O produto não cabe em um Decimal: divide antes de multiplicar
+    let max = money("79228162514264337593543950335 ETH");
+    assert_eq!(
+        max.share(u64::MAX, u64::MAX).unwrap().amount.round(),
+        max.amount
+    );
+    assert!(max.share(1, 0).is_err());
+}
@@ -0,0 +1,38 @@
+use nft_manager::cli::shell::{completions, LineSource};
+use nft_manager::models::category::CategoryDefinition;
+use std::io::Cursor;
+
+#[test]
+fn test_completions_include_tokens_and_categories() {
+    let token_ids = vec![
+        "token_1".to_string(),
+        "token_2".to_string(),
+        "art_01".to_string(),
+    ];
+
+    assert_eq!(
+        completions("tok", &token_ids, &[]),
+        vec!["token_1", "token_2"]
+    );
+    assert_eq!(completions("ar", &token_ids, &[]), vec!["Art", "art_01"]);
+    assert_eq!(
+        completions("VIRTUAL", &token_ids, &[]),
+        vec!["Virtual Real Estate"]
+    );
+    assert!(completions("xyz", &token_ids, &[]).is_empty());
+
+    let definitions = vec![CategoryDefinition::new("Ingresso")];
+    assert_eq!(
+        completions("ing", &token_ids, &definitions),
+        vec!["Ingresso"]
+    );
+}
+
+#[test]
+fn test_buffered_line_source_signals_end_of_input() {
+    let mut reader = Cursor::new("primeira\r\nsegunda\n");
+
+    assert_eq!(reader.read_line("> ").unwrap().as_deref(), Some("primeira"));
+    assert_eq!(reader.read_line("> ").unwrap().as_deref(), Some("segunda"));
+    assert_eq!(reader.read_line("> ").unwrap(), None);
+}
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use nft_manager::cli::stats::{compute_stats, gini};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::owner::Owner;
+
+fn nft(token_id: &str, owner_id: u64, month: u32, category: Category) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        owner_id,
+        NaiveDate::from_ymd_opt(2023, month, 5).expect("Data inválida"),
+        category,
+    )
+}
+
+#[test]
+fn test_gini_coefficient() {
+    assert_eq!(gini(&[]), 0.0);
+    assert!(gini(&[3, 3, 3]).abs() < 1e-9);
+    assert!((gini(&[0, 0, 0, 4]) - 0.75).abs() < 1e-9);
+}
+
+#[test]
+fn test_compute_stats() {
+    let nfts = vec![
+        nft("a", 1, 1, Category::Art),
+        nft("b", 1, 1, Category::Art),
+        nft("c", 1, 2, Category::Other("Ingresso".to_string())),
+        nft("d", 2, 3, Category::Other("Domínio".to_string())),
+    ];
+    let owners = vec![Owner::new(1, "Alice".to_string())];
+
+    let stats = compute_stats(&nfts, &owners, 1);
+    assert_eq!(stats.total, 4);
+    assert_eq!(stats.unique_owners, 2);
+
+    let categories: Vec<(&str, usize)> = stats
+        .categories
+        .iter()
+        .map(|c| (c.category.as_str(), c.count))
+        .collect();
+    assert_eq!(
+        categories,
+        vec![("Art", 2), ("Other: Domínio", 1), ("Other: Ingresso", 1)]
+    );
+
+    let months: Vec<(&str, usize)> = stats
+        .minted_per_month
+        .iter()
+        .map(|m| (m.month.as_str(), m.count))
+        .collect();
+    assert_eq!(months, vec![("2023-01", 2), ("2023-02", 1), ("2023-03", 1)]);
+
+    assert_eq!(stats.top_holders.len(), 1);
+    assert_eq!(stats.top_holders[0].owner_id, 1);
+    assert_eq!(stats.top_holders[0].owner_name.as_deref(), Some("Alice"));
+    assert_eq!(stats.top_holders[0].count, 3);
+    assert!((stats.gini - 0.25).abs() < 1e-9);
+}
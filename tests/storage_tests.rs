@@ -9,3 +9,64 @@ fn test_save_all_io_error() {
     let result = mock_file_storage.save_all(&[]);
     assert!(matches!(result, Err(StorageError::Io(_))));
 }
+
+#[test]
+fn test_load_legacy_schema_and_migrate() {
+    use chrono::NaiveDate;
+    use nft_manager::models::category::Category;
+    use nft_manager::models::nft::NFT;
+    use nft_manager::storage::file_storage::SCHEMA_VERSION;
+    use std::fs::File;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("legacy_nfts.db");
+    let nft = NFT::new(
+        "token_legacy".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+
+    // Versão 0: lista de NFTs gravada diretamente, sem envelope
+    let file = File::create(&file_path).unwrap();
+    serde_cbor::to_writer(file, &vec![nft.clone()]).unwrap();
+
+    let mut storage = FileStorage::new(file_path.to_str().unwrap());
+    assert_eq!(storage.schema_version().unwrap(), Some(0));
+    assert_eq!(storage.load_all().unwrap(), vec![nft.clone()]);
+
+    assert_eq!(storage.migrate().unwrap(), Some(0));
+    assert_eq!(storage.schema_version().unwrap(), Some(SCHEMA_VERSION));
+    assert_eq!(storage.load_all().unwrap(), vec![nft]);
+}
+
+#[test]
+fn test_migrate_normalizes_other_descriptions() {
+    use chrono::NaiveDate;
+    use nft_manager::models::category::Category;
+    use nft_manager::models::nft::NFT;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    let mut nft = NFT::new(
+        "t1".to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Other(" Ingresso/Show ".to_string()),
+    );
+    nft.subcategories = vec!["Pista".to_string()];
+    assert!(nft.validate().is_err());
+    storage.save_all(&[nft]).unwrap();
+
+    storage.migrate().unwrap();
+    let migrated = &storage.load_all().unwrap()[0];
+    assert_eq!(migrated.category, Category::Other("Ingresso".to_string()));
+    assert_eq!(migrated.subcategories, vec!["Show", "Pista"]);
+    assert!(migrated.validate().is_ok());
+
+    // A correção fica registrada no histórico
+    let current = storage.load_all().unwrap();
+    assert!(storage.ledger().verify(&current).unwrap().is_valid());
+}
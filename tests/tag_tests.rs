@@ -0,0 +1,109 @@
+use chrono::NaiveDate;
+use nft_manager::cli::databases::NftFilter;
+use nft_manager::cli::tags::{process_add_tags, process_list_tags, process_rename_tag};
+use nft_manager::models::category::{Category, CategoryPath};
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn nft(token_id: &str, path: &str, tags: &[&str]) -> NFT {
+    let mut nft = NFT::new(
+        token_id.to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        Category::Art,
+    );
+    nft.set_category_path(CategoryPath::from_str(path).expect("Categoria válida"));
+    for tag in tags {
+        nft.add_tag(tag);
+    }
+    nft
+}
+
+#[test]
+fn test_category_path_parse_and_subtree() {
+    let path = CategoryPath::from_str(" game item / Weapon/Sword ").unwrap();
+    assert_eq!(path.category, Category::GameItem);
+    assert_eq!(path.subcategories, vec!["Weapon", "Sword"]);
+    assert_eq!(path.to_string(), "Game Item/Weapon/Sword");
+    assert!(CategoryPath::from_str("Art//Generative").is_err());
+    assert!(CategoryPath::from_str("Art/").is_err());
+
+    let weapon = CategoryPath::from_str("GameItem/weapon").unwrap();
+    let root = CategoryPath::from_str("Game Item").unwrap();
+    assert!(root.contains(&path));
+    assert!(!CategoryPath::from_str("Game Item/weapon")
+        .unwrap()
+        .contains(&root));
+    assert!(CategoryPath::from_str("Game Item/weapon")
+        .unwrap()
+        .contains(&path));
+    assert!(weapon.contains(&path));
+    assert!(!weapon.contains(&CategoryPath::from_str("Other: GameItem/Weapon").unwrap()));
+}
+
+#[test]
+fn test_filter_by_subtree_and_tags() {
+    let nfts = [
+        nft("a", "Art/Generative", &["raro", "1/1"]),
+        nft("b", "Art/Generative/Glitch", &["Raro"]),
+        nft("c", "Art/Photography", &["série"]),
+        nft("d", "Music", &["raro", "série"]),
+    ];
+    let select = |filter: &NftFilter| -> Vec<&str> {
+        nfts.iter()
+            .filter(|n| filter.matches(n))
+            .map(|n| n.token_id.as_str())
+            .collect()
+    };
+
+    let filter = NftFilter {
+        category: Some(CategoryPath::from_str("art/generative").unwrap()),
+        ..NftFilter::default()
+    };
+    assert_eq!(select(&filter), ["a", "b"]);
+
+    let filter = NftFilter {
+        all_tags: vec!["RARO".to_string(), "série".to_string()],
+        ..NftFilter::default()
+    };
+    assert_eq!(select(&filter), ["d"]);
+
+    let filter = NftFilter {
+        category: Some(CategoryPath::from_str("Art").unwrap()),
+        any_tags: vec!["1/1".to_string(), "série".to_string()],
+        ..NftFilter::default()
+    };
+    assert_eq!(select(&filter), ["a", "c"]);
+}
+
+#[test]
+fn test_tag_management() {
+    let dir = tempdir().unwrap();
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    storage
+        .save_all(&[
+            nft("a", "Art", &["raro"]),
+            nft("b", "Art", &["Raro", "rare"]),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        process_add_tags("a", &["RARO", "série"], &mut storage),
+        Ok(vec!["série".to_string()])
+    );
+    assert!(process_add_tags("x", &["série"], &mut storage).is_err());
+    assert!(process_add_tags("a", &[" "], &mut storage).is_err());
+
+    // "b" já tem "rare": perde apenas "Raro"
+    assert_eq!(process_rename_tag("raro", "rare", &mut storage), Ok(2));
+    let nfts = storage.load_all().unwrap();
+    assert_eq!(nfts[0].tags, ["rare", "série"]);
+    assert_eq!(nfts[1].tags, ["rare"]);
+
+    assert_eq!(
+        process_list_tags(&mut storage),
+        Ok(vec![("rare".to_string(), 2), ("série".to_string(), 1)])
+    );
+}
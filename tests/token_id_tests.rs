@@ -0,0 +1,93 @@
+use chrono::NaiveDate;
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::token_id::TokenIdStrategy;
+
+fn nft(token_id: &str) -> NFT {
+    NFT::new(
+        token_id.to_string(),
+        1,
+        NaiveDate::from_ymd_opt(2023, 4, 1).expect("Data inválida"),
+        Category::Art,
+    )
+}
+
+#[test]
+fn test_token_id_strategy_parse() {
+    for text in ["uuid4", "uuid7", "sequential", "template:ART-{n:04}-X"] {
+        let strategy: TokenIdStrategy = text.parse().expect("Estratégia válida");
+        assert_eq!(strategy.to_string(), text);
+    }
+    assert_eq!(
+        "template:ART-{n:04}".parse::<TokenIdStrategy>(),
+        Ok(TokenIdStrategy::Template("ART-{n:04}".to_string()))
+    );
+    for text in [
+        "uuid5",
+        "template:ART",
+        "template:ART-{n:x}",
+        "template:{n}-{n}",
+    ] {
+        assert!(
+            text.parse::<TokenIdStrategy>().is_err(),
+            "{} deveria ser inválida",
+            text
+        );
+    }
+}
+
+#[test]
+fn test_generate_counters_skip_existing() {
+    let existing = vec![nft("ART-0001"), nft("ART-0007"), nft("ART-x"), nft("c1-2")];
+
+    let template = TokenIdStrategy::Template("ART-{n:04}".to_string());
+    assert_eq!(template.generate(&existing, None).unwrap(), "ART-0008");
+    assert_eq!(template.generate(&[], None).unwrap(), "ART-0001");
+
+    // Sequencial: um contador por coleção
+    let sequential = TokenIdStrategy::Sequential;
+    assert_eq!(sequential.generate(&existing, Some("c1")).unwrap(), "c1-3");
+    assert_eq!(sequential.generate(&existing, Some("c2")).unwrap(), "c2-1");
+    assert_eq!(sequential.generate(&existing, None).unwrap(), "1");
+    // O contador continua após um Token ID informado manualmente
+    assert_eq!(sequential.generate(&[nft("5")], None).unwrap(), "6");
+}
+
+#[test]
+fn test_generate_uuids() {
+    let existing = vec![nft("a")];
+    let v4 = TokenIdStrategy::UuidV4.generate(&existing, None).unwrap();
+    assert_eq!(uuid::Uuid::parse_str(&v4).unwrap().get_version_num(), 4);
+
+    let first = TokenIdStrategy::UuidV7.generate(&existing, None).unwrap();
+    let second = TokenIdStrategy::UuidV7
+        .generate(&[nft(&first)], None)
+        .unwrap();
+    assert_eq!(uuid::Uuid::parse_str(&first).unwrap().get_version_num(), 7);
+    // UUIDv7 é ordenado pelo horário de criação
+    assert!(first < second);
+}
+
+#[test]
+fn test_generate_with_unusual_collection_ids() {
+    // Ids de coleção com chaves ou `{n` não são interpretados como modelo
+    let existing = vec![nft("c{n}-1"), nft("c{n-2")];
+    let sequential = TokenIdStrategy::Sequential;
+    assert_eq!(
+        sequential.generate(&existing, Some("c{n}")).unwrap(),
+        "c{n}-2"
+    );
+    assert_eq!(
+        sequential.generate(&existing, Some("c{n")).unwrap(),
+        "c{n-3"
+    );
+    assert_eq!(sequential.generate(&existing, Some("}")).unwrap(), "}-1");
+
+    // Modelo inválido vira erro em vez de pânico
+    let template = TokenIdStrategy::Template("ART".to_string());
+    assert!(template.generate(&existing, None).is_err());
+    let template = TokenIdStrategy::Template("{n}".to_string());
+    assert!(template
+        .generate(&[nft(&u64::MAX.to_string())], None)
+        .is_err());
+}
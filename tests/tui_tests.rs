@@ -0,0 +1,148 @@
+use chrono::NaiveDate;
+use nft_manager::cli::tui::{draw, App, SortColumn};
+use nft_manager::models::category::Category;
+use nft_manager::models::nft::NFT;
+use nft_manager::storage::file_storage::FileStorage;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Terminal;
+use tempfile::tempdir;
+
+fn seed(db_path: &str) {
+    let mut storage = FileStorage::new(db_path);
+    let nfts = vec![
+        NFT::new(
+            "token_b".to_string(),
+            2,
+            NaiveDate::from_ymd_opt(2023, 3, 1).expect("Data inválida"),
+            Category::Music,
+        ),
+        NFT::new(
+            "token_a".to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2023, 5, 1).expect("Data inválida"),
+            Category::Art,
+        ),
+    ];
+    storage.save_all(&nfts).expect("Falha ao salvar NFTs");
+}
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+/// Desenha a interface em um buffer de memória e devolve as linhas da tela.
+fn render(app: &App) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(120, 20)).expect("Falha ao criar terminal");
+    terminal
+        .draw(|frame| draw(frame, app))
+        .expect("Falha ao desenhar");
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[test]
+fn test_tui_renders_table_and_details() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path = db_path.to_str().unwrap();
+    seed(db_path);
+
+    let mut app = App::new(db_path).expect("Falha ao abrir a interface");
+    let screen = render(&app).join("\n");
+    assert!(screen.contains("NFTs (2)"));
+    assert!(screen.contains("Token ID ▲"));
+    assert!(screen.contains("Token ID: token_a"));
+
+    // Ordena por Owner ID e depois inverte
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.sort(), (SortColumn::Owner, false));
+    press(&mut app, KeyCode::Char('r'));
+    assert_eq!(app.selected().unwrap().token_id, "token_b");
+
+    // Filtra pela categoria
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "art");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.filter(), "art");
+    assert_eq!(app.visible().len(), 1);
+    let screen = render(&app).join("\n");
+    assert!(screen.contains("NFTs (1 de 2) — filtro: art"));
+    assert!(!screen.contains("token_b"));
+
+    press(&mut app, KeyCode::Char('q'));
+    assert!(app.should_quit());
+}
+
+#[test]
+fn test_tui_create_transfer_and_delete() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("nfts_test.db");
+    let db_path = db_path.to_str().unwrap();
+    seed(db_path);
+
+    let mut app = App::new(db_path).expect("Falha ao abrir a interface");
+
+    // Criação: Token ID, Owner ID, data (pré-preenchida) e categoria
+    press(&mut app, KeyCode::Char('n'));
+    assert!(render(&app).join("\n").contains("Criar NFT"));
+    type_text(&mut app, "token_c");
+    press(&mut app, KeyCode::Tab);
+    type_text(&mut app, "0");
+    press(&mut app, KeyCode::Enter);
+    assert!(app.status().unwrap().contains("Owner ID inválido"));
+    press(&mut app, KeyCode::Backspace);
+    type_text(&mut app, "7");
+    press(&mut app, KeyCode::Tab);
+    for _ in 0..10 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_text(&mut app, "2023-01-15");
+    press(&mut app, KeyCode::Tab);
+    type_text(&mut app, "Collectible");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.status(), Some("NFT salvo com sucesso!"));
+    assert_eq!(app.selected().unwrap().token_id, "token_c");
+
+    // Transferência do NFT selecionado
+    press(&mut app, KeyCode::Char('t'));
+    type_text(&mut app, "9");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.status(), Some("NFT atualizado com sucesso!"));
+
+    // Edição dos metadados
+    press(&mut app, KeyCode::Char('e'));
+    type_text(&mut app, "Moeda rara");
+    press(&mut app, KeyCode::Enter);
+    assert!(render(&app).join("\n").contains("Nome: Moeda rara"));
+
+    let mut storage = FileStorage::new(db_path);
+    let stored = storage.load_all().expect("Falha ao carregar NFTs");
+    let token_c = stored.iter().find(|n| n.token_id == "token_c").unwrap();
+    assert_eq!(token_c.owner_id, 9);
+    assert_eq!(token_c.metadata.name.as_deref(), Some("Moeda rara"));
+
+    // Exclusão exige confirmação
+    press(&mut app, KeyCode::Char('d'));
+    press(&mut app, KeyCode::Char('n'));
+    assert_eq!(app.visible().len(), 3);
+    press(&mut app, KeyCode::Char('d'));
+    assert!(render(&app)
+        .join("\n")
+        .contains("Deletar o NFT 'token_c'? (s/N)"));
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.visible().len(), 2);
+    assert_eq!(storage.load_all().unwrap().len(), 2);
+}
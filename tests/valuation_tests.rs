@@ -0,0 +1,252 @@
+use chrono::NaiveDate;
+use nft_manager::cli::commands::{process_delete_nft, process_update_nft};
+use nft_manager::cli::config::ValidationRules;
+use nft_manager::cli::sales::process_record_sale;
+use nft_manager::cli::valuations::{
+    process_price_history, process_record_valuation, process_valuation_report,
+};
+use nft_manager::models::category::Category;
+use nft_manager::models::money::Money;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::valuation::{PriceKind, Valuation};
+use nft_manager::storage::file_storage::FileStorage;
+use nft_manager::storage::ledger::GENESIS_HASH;
+use std::str::FromStr;
+use tempfile::tempdir;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+}
+
+fn money(text: &str) -> Money {
+    Money::from_str(text).unwrap()
+}
+
+fn valuation(token_id: &str, value: &str, date: NaiveDate) -> Valuation {
+    Valuation {
+        token_id: token_id.to_string(),
+        value: money(value),
+        date,
+        source: "Avaliador".to_string(),
+    }
+}
+
+fn storage(dir: &tempfile::TempDir) -> FileStorage {
+    let mut storage = FileStorage::new(dir.path().join("nfts.db").to_str().unwrap());
+    let nft = |token_id: &str, category: Category, created: NaiveDate| {
+        NFT::new(token_id.to_string(), 1, created, category)
+    };
+    storage
+        .save_all(&[
+            nft("a", Category::Art, date(1, 1)),
+            nft("b", Category::Art, date(1, 1)),
+            nft("c", Category::Music, date(5, 1)),
+        ])
+        .unwrap();
+    storage
+}
+
+#[test]
+fn test_record_valuation_validates() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    process_record_valuation(&valuation("a", "1 ETH", date(1, 5)), &mut storage).unwrap();
+
+    let mut empty_source = valuation("a", "1 ETH", date(1, 5));
+    empty_source.source = " ".to_string();
+    let future = chrono::Local::now().date_naive() + chrono::Duration::days(1);
+    let invalid = [
+        valuation("zz", "1 ETH", date(1, 5)),
+        valuation("c", "1 ETH", date(4, 30)),
+        valuation("a", "1 ETH", future),
+        empty_source,
+    ];
+    for valuation in &invalid {
+        assert!(process_record_valuation(valuation, &mut storage).is_err());
+    }
+    assert_eq!(storage.load_valuations().unwrap().len(), 1);
+}
+
+#[test]
+fn test_price_history_merges_appraisals_and_sales() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    process_record_valuation(&valuation("a", "2 ETH", date(3, 1)), &mut storage).unwrap();
+    process_record_sale(
+        "a",
+        2,
+        money("2.5 ETH"),
+        Some(date(3, 1)),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    process_record_valuation(&valuation("a", "1.5 ETH", date(2, 1)), &mut storage).unwrap();
+
+    let history = process_price_history("a", &mut storage).unwrap();
+    let summary: Vec<(NaiveDate, PriceKind, String)> = history
+        .iter()
+        .map(|p| (p.date, p.kind, p.value.to_string()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (date(2, 1), PriceKind::Appraisal, "1.5 ETH".to_string()),
+            (date(3, 1), PriceKind::Appraisal, "2 ETH".to_string()),
+            (date(3, 1), PriceKind::Sale, "2.5 ETH".to_string()),
+        ]
+    );
+    assert!(process_price_history("b", &mut storage).unwrap().is_empty());
+}
+
+#[test]
+fn test_valuation_report_as_of_date() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    process_record_valuation(&valuation("a", "0.1 ETH", date(2, 1)), &mut storage).unwrap();
+    process_record_valuation(&valuation("b", "0.2 ETH", date(2, 1)), &mut storage).unwrap();
+    process_record_valuation(&valuation("b", "100 USD", date(2, 2)), &mut storage).unwrap();
+    process_record_sale(
+        "a",
+        2,
+        money("0.3 ETH"),
+        Some(date(4, 1)),
+        &ValidationRules::default(),
+        &mut storage,
+    )
+    .unwrap();
+    process_record_valuation(&valuation("c", "5 USD", date(6, 1)), &mut storage).unwrap();
+
+    // Antes da venda: tudo com o Owner ID 1; 0.1 + 0.2 sem erro de ponto flutuante
+    let report = process_valuation_report(date(3, 1), &mut storage).unwrap();
+    assert_eq!(report.owners.len(), 1);
+    assert_eq!(report.owners[0].nfts, 2);
+    assert_eq!(
+        report.owners[0].totals,
+        vec![money("0.1 ETH"), money("100 USD")]
+    );
+    assert!(report.unvalued.is_empty());
+
+    let report = process_valuation_report(date(6, 1), &mut storage).unwrap();
+    let owners: Vec<(u64, usize, Vec<Money>)> = report
+        .owners
+        .iter()
+        .map(|o| (o.owner_id, o.nfts, o.totals.clone()))
+        .collect();
+    assert_eq!(
+        owners,
+        vec![
+            (1, 2, vec![money("105 USD")]),
+            (2, 1, vec![money("0.3 ETH")]),
+        ]
+    );
+    let categories: Vec<(String, Vec<Money>)> = report
+        .categories
+        .iter()
+        .map(|c| (c.category.clone(), c.totals.clone()))
+        .collect();
+    assert_eq!(
+        categories,
+        vec![
+            ("Art".to_string(), vec![money("0.3 ETH"), money("100 USD")]),
+            ("Music".to_string(), vec![money("5 USD")]),
+        ]
+    );
+    assert_eq!(report.totals, vec![money("0.3 ETH"), money("105 USD")]);
+
+    let report = process_valuation_report(date(5, 15), &mut storage).unwrap();
+    assert_eq!(report.unvalued, vec!["c".to_string()]);
+}
+
+/// Regrava o histórico com as datas de registro informadas, refazendo a
+/// cadeia de hashes.
+fn backdate_history(storage: &FileStorage, dates: &[NaiveDate]) {
+    let ledger = storage.ledger();
+    let mut entries = ledger.load_all().unwrap();
+    let mut prev_hash = GENESIS_HASH.to_string();
+    for (entry, date) in entries.iter_mut().zip(dates) {
+        entry.timestamp = date.and_hms_opt(12, 0, 0).unwrap();
+        entry.prev_hash = prev_hash;
+        entry.hash = entry.compute_hash();
+        prev_hash = entry.hash.clone();
+    }
+    ledger.save_all(&entries).unwrap();
+}
+
+#[test]
+fn test_valuation_report_follows_history() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let rules = ValidationRules::default();
+    process_record_valuation(&valuation("a", "1 ETH", date(1, 10)), &mut storage).unwrap();
+    process_record_valuation(&valuation("b", "2 ETH", date(1, 10)), &mut storage).unwrap();
+    process_update_nft("a", 2, &rules, &mut storage).unwrap();
+    process_delete_nft("b", &mut storage).unwrap();
+    // Criações em 1/1, transferência de "a" em 2/1 e remoção de "b" em 3/1
+    backdate_history(
+        &storage,
+        &[date(1, 1), date(1, 1), date(1, 1), date(2, 1), date(3, 1)],
+    );
+    assert!(storage
+        .ledger()
+        .verify(&storage.load_all().unwrap())
+        .unwrap()
+        .is_valid());
+
+    let owners = |as_of: NaiveDate, storage: &mut FileStorage| {
+        let report = process_valuation_report(as_of, storage).unwrap();
+        let owners: Vec<(u64, usize, Vec<Money>)> = report
+            .owners
+            .iter()
+            .map(|o| (o.owner_id, o.nfts, o.totals.clone()))
+            .collect();
+        (owners, report.unvalued)
+    };
+    // "b" ainda existia e entra na contagem, mas sem avaliação: as
+    // avaliações dele foram apagadas junto com ele
+    assert_eq!(
+        owners(date(1, 15), &mut storage),
+        (vec![(1, 2, vec![money("1 ETH")])], vec!["b".to_string()])
+    );
+    assert_eq!(
+        owners(date(2, 15), &mut storage),
+        (
+            vec![(1, 1, Vec::new()), (2, 1, vec![money("1 ETH")])],
+            vec!["b".to_string()]
+        )
+    );
+    assert_eq!(
+        owners(date(3, 15), &mut storage),
+        (vec![(2, 1, vec![money("1 ETH")])], Vec::new())
+    );
+}
+
+#[test]
+fn test_delete_removes_sales_and_valuations() {
+    let dir = tempdir().unwrap();
+    let mut storage = storage(&dir);
+    let rules = ValidationRules::default();
+    process_record_valuation(&valuation("a", "1 ETH", date(1, 10)), &mut storage).unwrap();
+    process_record_valuation(&valuation("b", "2 ETH", date(1, 10)), &mut storage).unwrap();
+    process_record_sale(
+        "a",
+        2,
+        money("1 ETH"),
+        Some(date(2, 1)),
+        &rules,
+        &mut storage,
+    )
+    .unwrap();
+
+    process_delete_nft("a", &mut storage).unwrap();
+    assert!(storage.load_sales().unwrap().is_empty());
+    assert_eq!(
+        storage.load_valuations().unwrap(),
+        vec![valuation("b", "2 ETH", date(1, 10))]
+    );
+
+    // Um novo NFT com o mesmo Token ID começa sem histórico de preços
+    let nft = NFT::new("a".to_string(), 3, date(6, 1), Category::Art);
+    storage.save(&nft).unwrap();
+    assert!(process_price_history("a", &mut storage).unwrap().is_empty());
+}
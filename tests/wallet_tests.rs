@@ -0,0 +1,78 @@
+use chrono::NaiveDate;
+use nft_manager::models::category::Category;
+use nft_manager::models::collection::Chain;
+use nft_manager::models::nft::NFT;
+use nft_manager::models::wallet::WalletAddress;
+use std::str::FromStr;
+
+#[test]
+fn test_ethereum_address_checksum() {
+    let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    let wallet = WalletAddress::from_str(checksummed).unwrap();
+    assert_eq!(wallet.chain, Chain::Ethereum);
+    assert_eq!(wallet.address, checksummed);
+
+    // Entrada em minúsculas é normalizada para o checksum EIP-55
+    let wallet = WalletAddress::from_str(&format!("eth:{}", checksummed.to_lowercase())).unwrap();
+    assert_eq!(wallet.address, checksummed);
+    assert_eq!(wallet.to_string(), format!("ethereum:{}", checksummed));
+
+    let wrong_case = "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    let error = WalletAddress::from_str(wrong_case).unwrap_err();
+    assert!(error.contains("checksum EIP-55"));
+
+    let error = WalletAddress::from_str("0x1234").unwrap_err();
+    assert!(error.contains("40 dígitos hexadecimais"));
+}
+
+#[test]
+fn test_solana_address() {
+    let wallet = WalletAddress::from_str("So11111111111111111111111111111111111111112").unwrap();
+    assert_eq!(wallet.chain, Chain::Solana);
+
+    let error = WalletAddress::from_str("sol:So1111111111111111111111111111111111111111O").unwrap_err();
+    assert!(error.contains("base58"));
+
+    let error = WalletAddress::from_str("sol:abc").unwrap_err();
+    assert!(error.contains("32 bytes"));
+}
+
+#[test]
+fn test_bitcoin_bech32_address() {
+    let wallet = WalletAddress::from_str("BC1QAR0SRRR7XFKVY5L643LYDNW9RE59GTZZWF5MDQ").unwrap();
+    assert_eq!(wallet.chain, Chain::Bitcoin);
+    assert_eq!(wallet.address, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+
+    // Taproot usa bech32m
+    assert!(WalletAddress::from_str(
+        "btc:bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+    )
+    .is_ok());
+
+    let error = WalletAddress::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdp").unwrap_err();
+    assert!(error.contains("checksum bech32"));
+
+    let error = WalletAddress::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mDQ").unwrap_err();
+    assert!(error.contains("misturadas"));
+}
+
+#[test]
+fn test_nft_validate_reports_wallet_check() {
+    let mut nft = NFT::new(
+        "token_wallet".to_string(),
+        123u64,
+        NaiveDate::from_ymd_opt(2023, 11, 5).expect("Data inválida"),
+        Category::Art,
+    );
+    nft.owner_address = Some(WalletAddress {
+        chain: Chain::Ethereum,
+        address: "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+    });
+
+    let error = nft.validate().unwrap_err();
+    assert_eq!(
+        error,
+        "Endereço Ethereum inválido: checksum EIP-55 não confere"
+    );
+}